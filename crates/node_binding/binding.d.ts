@@ -1607,6 +1607,8 @@ export interface RawExperiments {
   topLevelAwait: boolean
 incremental?: false | { [key: string]: boolean }
 parallelCodeSplitting: boolean
+lazyBarrel?: boolean
+inlineConst?: boolean
 rspackFuture?: RawRspackFuture
 cache: boolean | { type: "persistent" } & RawExperimentCacheOptionsPersistent | { type: "memory" }
 }
@@ -1615,6 +1617,7 @@ export interface RawExperimentSnapshotOptions {
   immutablePaths: Array<string|RegExp>
   unmanagedPaths: Array<string|RegExp>
   managedPaths: Array<string|RegExp>
+  fileStrategy?: "compile-time" | "content-hash" | "compile-time-and-content-hash"
 }
 
 export interface RawExposeOptions {
@@ -1998,6 +2001,8 @@ export interface RawOptions {
   profile: boolean
   amd?: string
   bail: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
   __references: Record<string, any>
 }
 
@@ -2250,6 +2255,13 @@ export interface RawStatsOptions {
 export interface RawStorageOptions {
   type: "filesystem"
   directory: string
+  bucketSize?: number
+  adaptiveBucket?: boolean
+  packSize?: number
+  expire?: number
+  maxSize?: number
+  freshGeneration?: number
+  releaseGeneration?: number
 }
 
 export interface RawSubresourceIntegrityPluginOptions {
@@ -18,6 +18,8 @@ pub struct RawExperiments {
   #[napi(ts_type = "false | { [key: string]: boolean }")]
   pub incremental: Option<WithFalse<RawIncremental>>,
   pub parallel_code_splitting: bool,
+  pub lazy_barrel: Option<bool>,
+  pub inline_const: Option<bool>,
   pub rspack_future: Option<RawRspackFuture>,
   #[napi(
     ts_type = r#"boolean | { type: "persistent" } & RawExperimentCacheOptionsPersistent | { type: "memory" }"#
@@ -25,9 +27,11 @@ pub struct RawExperiments {
   pub cache: RawExperimentCacheOptions,
 }
 
-impl From<RawExperiments> for Experiments {
-  fn from(value: RawExperiments) -> Self {
-    Self {
+impl TryFrom<RawExperiments> for Experiments {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExperiments) -> rspack_error::Result<Self> {
+    Ok(Self {
       incremental: match value.incremental {
         Some(value) => match value {
           WithFalse::True(value) => value.into(),
@@ -38,8 +42,10 @@ impl From<RawExperiments> for Experiments {
       parallel_code_splitting: value.parallel_code_splitting,
       layers: value.layers,
       top_level_await: value.top_level_await,
+      lazy_barrel: value.lazy_barrel.unwrap_or_default(),
+      inline_const: value.inline_const.unwrap_or_default(),
       rspack_future: value.rspack_future.unwrap_or_default().into(),
-      cache: normalize_raw_experiment_cache_options(value.cache),
-    }
+      cache: normalize_raw_experiment_cache_options(value.cache)?,
+    })
   }
 }
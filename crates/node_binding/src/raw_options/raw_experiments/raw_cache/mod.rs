@@ -23,9 +23,11 @@ pub struct RawExperimentCacheOptionsPersistent {
   pub storage: Option<RawStorageOptions>,
 }
 
-impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
-  fn from(value: RawExperimentCacheOptionsPersistent) -> Self {
-    Self {
+impl TryFrom<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExperimentCacheOptionsPersistent) -> rspack_error::Result<Self> {
+    Ok(Self {
       build_dependencies: value
         .build_dependencies
         .unwrap_or_default()
@@ -33,9 +35,9 @@ impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
         .map(Into::into)
         .collect(),
       version: value.version.unwrap_or_default(),
-      snapshot: value.snapshot.unwrap_or_default().into(),
-      storage: value.storage.unwrap_or_default().into(),
-    }
+      snapshot: value.snapshot.unwrap_or_default().try_into()?,
+      storage: value.storage.unwrap_or_default().try_into()?,
+    })
   }
 }
 
@@ -43,7 +45,7 @@ impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
 pub enum RawExperimentCache {
   #[default]
   Memory,
-  Persistent(RawExperimentCacheOptionsPersistent),
+  Persistent(Box<RawExperimentCacheOptionsPersistent>),
 }
 
 impl TypeName for RawExperimentCache {
@@ -69,7 +71,7 @@ impl FromNapiValue for RawExperimentCache {
     let v = match &*t {
       "persistent" => {
         let o = RawExperimentCacheOptionsPersistent::from_napi_value(env, napi_val)?;
-        Self::Persistent(o)
+        Self::Persistent(Box::new(o))
       }
       "memory" => Self::Memory,
       _ => panic!("Unexpected cache type: {t}, expected 'persistent' or 'memory'"),
@@ -81,8 +83,8 @@ impl FromNapiValue for RawExperimentCache {
 
 pub fn normalize_raw_experiment_cache_options(
   options: RawExperimentCacheOptions,
-) -> ExperimentCacheOptions {
-  match options {
+) -> rspack_error::Result<ExperimentCacheOptions> {
+  Ok(match options {
     Either::A(options) => {
       if options {
         ExperimentCacheOptions::Memory
@@ -91,8 +93,10 @@ pub fn normalize_raw_experiment_cache_options(
       }
     }
     Either::B(options) => match options {
-      RawExperimentCache::Persistent(options) => ExperimentCacheOptions::Persistent(options.into()),
+      RawExperimentCache::Persistent(options) => {
+        ExperimentCacheOptions::Persistent(Box::new((*options).try_into()?))
+      }
       RawExperimentCache::Memory => ExperimentCacheOptions::Memory,
     },
-  }
+  })
 }
@@ -1,6 +1,7 @@
 use napi::Either;
 use napi_derive::napi;
-use rspack_core::cache::persistent::snapshot::{PathMatcher, SnapshotOptions};
+use rspack_core::cache::persistent::snapshot::{FileStrategy, PathMatcher, SnapshotOptions};
+use rspack_error::error;
 use rspack_regex::RspackRegex;
 
 #[derive(Debug, Default)]
@@ -12,32 +13,54 @@ pub struct RawExperimentSnapshotOptions {
   pub unmanaged_paths: Vec<RawPathMatcher>,
   #[napi(ts_type = r#"Array<string|RegExp>"#)]
   pub managed_paths: Vec<RawPathMatcher>,
+  #[napi(ts_type = r#""compile-time" | "content-hash" | "compile-time-and-content-hash""#)]
+  pub file_strategy: Option<String>,
 }
 
 type RawPathMatcher = Either<String, RspackRegex>;
 
-impl From<RawExperimentSnapshotOptions> for SnapshotOptions {
-  fn from(value: RawExperimentSnapshotOptions) -> Self {
-    SnapshotOptions::new(
-      value
-        .immutable_paths
-        .into_iter()
-        .map(normalize_raw_path_matcher)
-        .collect(),
-      value
-        .unmanaged_paths
-        .into_iter()
-        .map(normalize_raw_path_matcher)
-        .collect(),
-      value
-        .managed_paths
-        .into_iter()
-        .map(normalize_raw_path_matcher)
-        .collect(),
+impl TryFrom<RawExperimentSnapshotOptions> for SnapshotOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExperimentSnapshotOptions) -> rspack_error::Result<Self> {
+    let file_strategy = value
+      .file_strategy
+      .as_deref()
+      .map(normalize_raw_file_strategy)
+      .transpose()?
+      .unwrap_or_default();
+    Ok(
+      SnapshotOptions::new(
+        value
+          .immutable_paths
+          .into_iter()
+          .map(normalize_raw_path_matcher)
+          .collect(),
+        value
+          .unmanaged_paths
+          .into_iter()
+          .map(normalize_raw_path_matcher)
+          .collect(),
+        value
+          .managed_paths
+          .into_iter()
+          .map(normalize_raw_path_matcher)
+          .collect(),
+      )
+      .with_file_strategy(file_strategy),
     )
   }
 }
 
+fn normalize_raw_file_strategy(value: &str) -> rspack_error::Result<FileStrategy> {
+  match value {
+    "compile-time" => Ok(FileStrategy::CompileTime),
+    "content-hash" => Ok(FileStrategy::ContentHash),
+    "compile-time-and-content-hash" => Ok(FileStrategy::CompileTimeAndContentHash),
+    s => Err(error!("unsupported snapshot file strategy {s}")),
+  }
+}
+
 fn normalize_raw_path_matcher(value: RawPathMatcher) -> PathMatcher {
   match value {
     Either::A(s) => PathMatcher::String(s),
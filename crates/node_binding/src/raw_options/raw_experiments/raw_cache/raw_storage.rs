@@ -1,5 +1,6 @@
 use napi_derive::napi;
-use rspack_core::cache::persistent::storage::StorageOptions;
+use rspack_core::cache::persistent::storage::{StorageOptions, StoragePackOptions};
+use rspack_error::error;
 
 #[derive(Debug, Default)]
 #[napi(object)]
@@ -7,15 +8,62 @@ pub struct RawStorageOptions {
   #[napi(ts_type = r#""filesystem""#)]
   pub r#type: String,
   pub directory: String,
+  pub bucket_size: Option<u32>,
+  pub adaptive_bucket: Option<bool>,
+  pub pack_size: Option<u32>,
+  pub expire: Option<f64>,
+  pub max_size: Option<f64>,
+  pub fresh_generation: Option<u32>,
+  pub release_generation: Option<u32>,
 }
 
-impl From<RawStorageOptions> for StorageOptions {
-  fn from(value: RawStorageOptions) -> Self {
+/// Convert a number from js to integer, js numbers may be negative, fractional or NaN
+fn to_integer(name: &str, value: f64) -> rspack_error::Result<u64> {
+  if !value.is_finite() || value < 0.0 || value.fract() != 0.0 || value > u64::MAX as f64 {
+    return Err(error!(
+      "invalid experiments.cache.storage.{name}: {value}, expect a non-negative integer"
+    ));
+  }
+  Ok(value as u64)
+}
+
+impl TryFrom<RawStorageOptions> for StorageOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStorageOptions) -> rspack_error::Result<Self> {
+    let default = StoragePackOptions::default();
+    let pack = StoragePackOptions {
+      bucket_size: value
+        .bucket_size
+        .map_or(default.bucket_size, |size| size as usize),
+      adaptive_bucket: value.adaptive_bucket.unwrap_or(default.adaptive_bucket),
+      pack_size: value
+        .pack_size
+        .map_or(default.pack_size, |size| size as usize),
+      expire: value
+        .expire
+        .map(|expire| to_integer("expire", expire))
+        .transpose()?
+        .unwrap_or(default.expire),
+      max_size: value
+        .max_size
+        .map(|size| to_integer("maxSize", size).map(|size| size as usize))
+        .transpose()?,
+      fresh_generation: value
+        .fresh_generation
+        .map(|generation| generation as usize)
+        .or(default.fresh_generation),
+      release_generation: value
+        .release_generation
+        .map(|generation| generation as usize)
+        .or(default.release_generation),
+    };
     match value.r#type.as_str() {
-      "filesystem" => StorageOptions::FileSystem {
+      "filesystem" => Ok(StorageOptions::FileSystem {
         directory: value.directory.into(),
-      },
-      s => panic!("unsupported storage type {s}"),
+        pack,
+      }),
+      s => Err(error!("unsupported storage type {s}")),
     }
   }
 }
@@ -984,6 +984,14 @@ impl From<JsStatsOptions> for ExtendedStatsOptions {
       source: value.source,
       used_exports: value.used_exports,
       warnings: value.warnings,
+      // the following fields are extracted by the stats factory in js
+      built_at: false,
+      errors_count: false,
+      output_path: false,
+      public_path: false,
+      timings: false,
+      version: false,
+      warnings_count: false,
     }
   }
 }
@@ -44,8 +44,13 @@ pub(super) enum BuiltinPluginOptions {
   // Entry and runtime plugins
   EntryPlugin((String /* entry request */, EntryOptions)),
   RuntimePlugin,
-  // TODO: add bundler info plugin
-  // BundlerInfoRspackPlugin,
+  BundlerInfoRspackPlugin(
+    (
+      String, /* version */
+      String, /* bundler */
+      rspack_plugin_runtime::BundlerInfoForceMode,
+    ),
+  ),
 
   // Core functionality plugins
   InferAsyncModulesPlugin,
@@ -63,13 +68,11 @@ pub(super) enum BuiltinPluginOptions {
   MangleExportsPlugin(bool),
 
   // Experiments
-  // TODO: support lazy compilation
-  // LazyCompilationPlugin,
+  LazyCompilationPlugin(super::LazyCompilationOptions),
 
   // Output plugins
   EnableLibraryPlugin(LibraryType),
-  // TODO: support split chunks
-  // SplitChunksPlugin,
+  SplitChunksPlugin(rspack_plugin_split_chunks::PluginOptions),
   RemoveEmptyChunksPlugin,
   RealContentHashPlugin,
 
@@ -77,6 +80,8 @@ pub(super) enum BuiltinPluginOptions {
   NamedModuleIdsPlugin,
   NaturalModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
+  HashedModuleIdsPlugin(rspack_ids::HashedModuleIdsPluginOptions),
+  OccurrenceModuleIdsPlugin(rspack_ids::OccurrenceModuleIdsPluginOptions),
   NaturalChunkIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
@@ -86,11 +91,11 @@ pub(super) enum BuiltinPluginOptions {
   DefinePlugin(rspack_plugin_javascript::define_plugin::DefineValue),
   AnyMinimizerRspackPlugin(BoxPlugin),
 
-  // TODO: support performance
-  // SizeLimitsPlugin,
+  SizeLimitsPlugin(rspack_plugin_size_limits::SizeLimitsPluginOptions),
 
   // Cache plugins
-  // MemoryCachePlugin,
+  // MemoryCachePlugin is not needed, the memory cache is created by the compiler
+  // from `experiments.cache` instead of a plugin
 
   // Worker plugins
   WorkerPlugin,
@@ -217,8 +222,9 @@ impl BuilderContext {
       BuiltinPluginOptions::RuntimePlugin => {
         plugins.push(rspack_plugin_runtime::RuntimePlugin::default().boxed())
       }
-      // TODO: add bundler info plugin
-      // BuiltinPluginOptions::BundlerInfoRspackPlugin => {}
+      BuiltinPluginOptions::BundlerInfoRspackPlugin((version, bundler, force)) => {
+        plugins.push(rspack_plugin_runtime::BundlerInfoPlugin::new(version, bundler, force).boxed())
+      }
 
       // Core functionality plugins
       BuiltinPluginOptions::InferAsyncModulesPlugin => {
@@ -262,19 +268,24 @@ impl BuilderContext {
       }
 
       // Experiments
-      // TODO: support lazy compilation
-      // BuiltinPluginOptions::LazyCompilationPlugin => {
-      // plugins
-      // .push(rspack_plugin_lazy_compilation::plugin::LazyCompilationPlugin::default().boxed());
-      // }
+      BuiltinPluginOptions::LazyCompilationPlugin(options) => plugins.push(
+        rspack_plugin_lazy_compilation::plugin::LazyCompilationPlugin::new(
+          options.cacheable,
+          options.backend,
+          options.test,
+          options.entries,
+          options.imports,
+        )
+        .boxed(),
+      ),
 
       // Output plugins
       BuiltinPluginOptions::EnableLibraryPlugin(library_type) => {
         rspack_plugin_library::enable_library_plugin(library_type, &mut plugins)
       }
-      // BuiltinPluginOptions::SplitChunksPlugin => {
-      // plugins.push(rspack_plugin_split_chunks::SplitChunksPlugin::default().boxed())
-      // }
+      BuiltinPluginOptions::SplitChunksPlugin(options) => {
+        plugins.push(rspack_plugin_split_chunks::SplitChunksPlugin::new(options).boxed())
+      }
       BuiltinPluginOptions::RemoveEmptyChunksPlugin => {
         plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin::default().boxed())
       }
@@ -292,6 +303,12 @@ impl BuilderContext {
       BuiltinPluginOptions::DeterministicModuleIdsPlugin => {
         plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed())
       }
+      BuiltinPluginOptions::HashedModuleIdsPlugin(options) => {
+        plugins.push(rspack_ids::HashedModuleIdsPlugin::new(options).boxed())
+      }
+      BuiltinPluginOptions::OccurrenceModuleIdsPlugin(options) => {
+        plugins.push(rspack_ids::OccurrenceModuleIdsPlugin::new(options).boxed())
+      }
       BuiltinPluginOptions::NaturalChunkIdsPlugin => {
         plugins.push(rspack_ids::NaturalChunkIdsPlugin::default().boxed())
       }
@@ -311,13 +328,9 @@ impl BuilderContext {
       }
       BuiltinPluginOptions::AnyMinimizerRspackPlugin(plugin) => plugins.push(plugin),
 
-      // TODO: support performance
-      // BuiltinPluginOptions::SizeLimitsPlugin => {
-      // plugins.push(rspack_plugin_size_limits::SizeLimitsPlugin::default().boxed())
-      // }
-
-      // Cache plugins
-      // BuiltinPluginOptions::MemoryCachePlugin => MemoryCachePlugin::default().boxed(),
+      BuiltinPluginOptions::SizeLimitsPlugin(options) => {
+        plugins.push(rspack_plugin_size_limits::SizeLimitsPlugin::new(options).boxed())
+      }
 
       // Worker plugins
       BuiltinPluginOptions::WorkerPlugin => {
@@ -44,22 +44,28 @@ use indexmap::IndexMap;
 use rspack_core::{incremental::IncrementalPasses, ModuleType};
 use rspack_core::{
   AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions, BoxPlugin, ByDependency,
-  CacheOptions, ChunkLoading, ChunkLoadingType, CleanOptions, Compiler, CompilerOptions, Context,
-  CrossOriginLoading, CssAutoGeneratorOptions, CssAutoParserOptions, CssExportsConvention,
-  CssGeneratorOptions, CssModuleGeneratorOptions, CssModuleParserOptions, CssParserOptions,
-  DynamicImportMode, EntryDescription, EntryOptions, EntryRuntime, Environment,
+  CacheOptions, ChunkLoading, ChunkLoadingType, CleanOptions, CompilationId, Compiler, CompilerId,
+  CompilerOptions, Context, CrossOriginLoading, CssAutoGeneratorOptions, CssAutoParserOptions,
+  CssExportsConvention, CssGeneratorOptions, CssModuleGeneratorOptions, CssModuleParserOptions,
+  CssParserOptions, DynamicImportMode, EntryDescription, EntryOptions, EntryRuntime, Environment,
   ExperimentCacheOptions, Experiments, ExternalItem, ExternalType, Filename, FilenameTemplate,
   GeneratorOptions, GeneratorOptionsMap, JavascriptParserOptions, JavascriptParserOrder,
   JavascriptParserUrl, JsonParserOptions, LibraryName, LibraryNonUmdObject, LibraryOptions,
-  LibraryType, MangleExportsOption, Mode, ModuleNoParseRules, ModuleOptions, ModuleRule,
+  LibraryType, MangleExportsOption, Mode, Module, ModuleNoParseRules, ModuleOptions, ModuleRule,
   ModuleRuleEffect, NodeDirnameOption, NodeFilenameOption, NodeGlobalOption, NodeOption,
   Optimization, OutputOptions, ParseOption, ParserOptions, ParserOptionsMap, PathInfo, PublicPath,
-  Resolve, RspackFuture, RuleSetCondition, RuleSetLogicalConditions, SideEffectOption,
+  Resolve, RspackFuture, RuleSetCondition, RuleSetLogicalConditions, SideEffectOption, SourceType,
   StatsOptions, TrustedTypes, UsedExportsOption, WasmLoading, WasmLoadingType,
 };
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem, WritableFileSystem};
 use rspack_hash::{HashDigest, HashFunction, HashSalt};
+use rspack_macros::rspack_version;
 use rspack_paths::{AssertUtf8, Utf8PathBuf};
+use rspack_plugin_lazy_compilation::{
+  backend::Backend,
+  plugin::{LazyCompilationTest, LazyCompilationTestCheck},
+};
+use rspack_plugin_split_chunks::{CacheGroup, CacheGroupTest, ChunkNameGetter, SplitChunkSizes};
 use rspack_regex::RspackRegex;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use serde_json::json;
@@ -230,6 +236,41 @@ impl CompilerBuilder {
     self
   }
 
+  /// Set the file to read and write the records of ids.
+  ///
+  /// See [`CompilerOptionsBuilder::records_path`] for more details.
+  pub fn records_path<V>(&mut self, records_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.options_builder.records_path(records_path);
+    self
+  }
+
+  /// Set the file to read the records of ids from.
+  ///
+  /// See [`CompilerOptionsBuilder::records_input_path`] for more details.
+  pub fn records_input_path<V>(&mut self, records_input_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.options_builder.records_input_path(records_input_path);
+    self
+  }
+
+  /// Set the file to write the records of ids to.
+  ///
+  /// See [`CompilerOptionsBuilder::records_output_path`] for more details.
+  pub fn records_output_path<V>(&mut self, records_output_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self
+      .options_builder
+      .records_output_path(records_output_path);
+    self
+  }
+
   /// Set options for module configuration.
   ///
   /// Both are accepted:
@@ -363,6 +404,25 @@ impl CompilerBuilder {
     self
   }
 
+  /// Set options for performance hints.
+  ///
+  /// See [`CompilerOptionsBuilder::performance`] for more details.
+  pub fn performance<V>(&mut self, performance: V) -> &mut Self
+  where
+    V: Into<PerformanceOptionsBuilder>,
+  {
+    self.options_builder.performance(performance);
+    self
+  }
+
+  /// Disable performance hints.
+  ///
+  /// See [`CompilerOptionsBuilder::disable_performance`] for more details.
+  pub fn disable_performance(&mut self) -> &mut Self {
+    self.options_builder.disable_performance();
+    self
+  }
+
   /// Set options for experiments.
   ///
   /// Both are accepted:
@@ -573,6 +633,12 @@ pub struct CompilerOptionsBuilder {
   profile: Option<bool>,
   /// Whether to fail on the first error.
   bail: Option<bool>,
+  /// The file to read and write the records of ids.
+  records_path: Option<Utf8PathBuf>,
+  /// The file to read the records of ids from.
+  records_input_path: Option<Utf8PathBuf>,
+  /// The file to write the records of ids to.
+  records_output_path: Option<Utf8PathBuf>,
   /// Performance optimization options.
   experiments: Option<ExperimentsBuilder>,
   /// Options for module configuration.
@@ -587,6 +653,8 @@ pub struct CompilerOptionsBuilder {
   node: Option<NodeOptionBuilder>,
   /// The value of `require.amd` or `define.amd`.
   amd: Option<String>,
+  /// Performance hints, `Some(None)` means performance hints are disabled.
+  performance: Option<Option<PerformanceOptionsBuilder>>,
 }
 
 impl From<&mut CompilerOptionsBuilder> for CompilerOptionsBuilder {
@@ -606,6 +674,9 @@ impl From<&mut CompilerOptionsBuilder> for CompilerOptionsBuilder {
       devtool: value.devtool.take(),
       profile: value.profile.take(),
       bail: value.bail.take(),
+      records_path: value.records_path.take(),
+      records_input_path: value.records_input_path.take(),
+      records_output_path: value.records_output_path.take(),
       experiments: value.experiments.take(),
       module: value.module.take(),
       output: value.output.take(),
@@ -613,6 +684,7 @@ impl From<&mut CompilerOptionsBuilder> for CompilerOptionsBuilder {
       optimization: value.optimization.take(),
       node: value.node.take(),
       amd: value.amd.take(),
+      performance: value.performance.take(),
     }
   }
 }
@@ -714,6 +786,36 @@ impl CompilerOptionsBuilder {
     self
   }
 
+  /// Set the file to read and write the records of ids.
+  ///
+  /// Records keep the module and chunk ids stable across builds, this is the default
+  /// of both `records_input_path` and `records_output_path`.
+  pub fn records_path<V>(&mut self, records_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.records_path = Some(records_path.into());
+    self
+  }
+
+  /// Set the file to read the records of ids from.
+  pub fn records_input_path<V>(&mut self, records_input_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.records_input_path = Some(records_input_path.into());
+    self
+  }
+
+  /// Set the file to write the records of ids to.
+  pub fn records_output_path<V>(&mut self, records_output_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.records_output_path = Some(records_output_path.into());
+    self
+  }
+
   /// Set options for module configuration.
   ///
   /// Both are accepted:
@@ -836,6 +938,23 @@ impl CompilerOptionsBuilder {
     self
   }
 
+  /// Set options for performance hints.
+  ///
+  /// Performance hints are enabled by default in production mode for browser targets.
+  pub fn performance<V>(&mut self, performance: V) -> &mut Self
+  where
+    V: Into<PerformanceOptionsBuilder>,
+  {
+    self.performance = Some(Some(performance.into()));
+    self
+  }
+
+  /// Disable performance hints.
+  pub fn disable_performance(&mut self) -> &mut Self {
+    self.performance = Some(None);
+    self
+  }
+
   /// Set options for experiments.
   ///
   /// Both are accepted:
@@ -914,6 +1033,9 @@ impl CompilerOptionsBuilder {
 
     let profile = d!(self.profile.take(), false);
     let bail = d!(self.bail.take(), false);
+    let records_path = self.records_path.take();
+    let records_input_path = self.records_input_path.take().or(records_path.clone());
+    let records_output_path = self.records_output_path.take().or(records_path);
     let cache = d!(self.cache.take(), {
       if development {
         CacheOptions::Memory
@@ -1025,7 +1147,18 @@ impl CompilerOptionsBuilder {
         .push(BuiltinPluginOptions::EvalDevToolModulePlugin(options));
     }
 
-    // TODO: bundler info
+    // apply bundler info defaults, which depend on the output defaults
+    if let Some(mut bundler_info) = experiments_builder
+      .rspack_future
+      .as_mut()
+      .and_then(|rspack_future| rspack_future.bundler_info.take())
+    {
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::BundlerInfoRspackPlugin(
+          bundler_info.build(output.library.is_some()),
+        ));
+    }
 
     // apply externals presets defaults
     let externals_presets = self.externals_presets.get_or_insert_default();
@@ -1221,6 +1354,18 @@ impl CompilerOptionsBuilder {
       .plugins
       .push(BuiltinPluginOptions::WorkerPlugin);
 
+    // apply performance defaults
+    let performance = f!(self.performance.take(), || {
+      (production && target_properties.browser.unwrap_or(true)).then(Default::default)
+    });
+    if let Some(mut performance) = performance {
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::SizeLimitsPlugin(
+          performance.build(production),
+        ));
+    }
+
     // TODO: stats plugins
     let stats = d!(self.stats.take(), StatsOptions { colors: true });
 
@@ -1242,6 +1387,8 @@ impl CompilerOptionsBuilder {
       profile,
       amd,
       bail,
+      records_input_path,
+      records_output_path,
       __references: Default::default(),
     }
   }
@@ -3127,6 +3274,10 @@ pub struct OptimizationOptionsBuilder {
   merge_duplicate_chunks: Option<bool>,
   /// Which algorithm to use when choosing module ids.
   module_ids: Option<String>,
+  /// Options of the hashed module ids, used when `module_ids` is `"hashed"`.
+  hashed_module_ids: Option<rspack_ids::HashedModuleIdsPluginOptions>,
+  /// Options of the occurrence module ids, used when `module_ids` is `"size"`.
+  occurrence_module_ids: Option<rspack_ids::OccurrenceModuleIdsPluginOptions>,
   /// Which algorithm to use when choosing chunk ids.
   chunk_ids: Option<String>,
   /// Whether to enable minimize.
@@ -3155,7 +3306,8 @@ pub struct OptimizationOptionsBuilder {
   emit_on_errors: Option<bool>,
   /// Runtime chunk.
   runtime_chunk: Option<rspack_plugin_runtime_chunk::RuntimeChunkOptions>,
-  // TODO: split chunks
+  /// Split chunks, `Some(None)` means split chunks is disabled.
+  split_chunks: Option<Option<SplitChunksOptionsBuilder>>,
 }
 
 impl From<Optimization> for OptimizationOptionsBuilder {
@@ -3172,6 +3324,8 @@ impl From<Optimization> for OptimizationOptionsBuilder {
       remove_empty_chunks: None,
       merge_duplicate_chunks: None,
       module_ids: None,
+      hashed_module_ids: None,
+      occurrence_module_ids: None,
       chunk_ids: None,
       minimize: None,
       minimizer: None,
@@ -3179,6 +3333,7 @@ impl From<Optimization> for OptimizationOptionsBuilder {
       node_env: None,
       emit_on_errors: None,
       runtime_chunk: None,
+      split_chunks: None,
     }
   }
 }
@@ -3190,6 +3345,8 @@ impl From<&mut OptimizationOptionsBuilder> for OptimizationOptionsBuilder {
       remove_empty_chunks: value.remove_empty_chunks.take(),
       merge_duplicate_chunks: value.merge_duplicate_chunks.take(),
       module_ids: value.module_ids.take(),
+      hashed_module_ids: value.hashed_module_ids.take(),
+      occurrence_module_ids: value.occurrence_module_ids.take(),
       chunk_ids: value.chunk_ids.take(),
       minimize: value.minimize.take(),
       minimizer: value.minimizer.take(),
@@ -3204,6 +3361,7 @@ impl From<&mut OptimizationOptionsBuilder> for OptimizationOptionsBuilder {
       node_env: value.node_env.take(),
       emit_on_errors: value.emit_on_errors.take(),
       runtime_chunk: value.runtime_chunk.take(),
+      split_chunks: value.split_chunks.take(),
     }
   }
 }
@@ -3233,6 +3391,28 @@ impl OptimizationOptionsBuilder {
     self
   }
 
+  /// Set the options of the hashed module ids.
+  ///
+  /// Only takes effect when `module_ids` is `"hashed"`.
+  pub fn hashed_module_ids(
+    &mut self,
+    value: rspack_ids::HashedModuleIdsPluginOptions,
+  ) -> &mut Self {
+    self.hashed_module_ids = Some(value);
+    self
+  }
+
+  /// Set the options of the occurrence module ids.
+  ///
+  /// Only takes effect when `module_ids` is `"size"`.
+  pub fn occurrence_module_ids(
+    &mut self,
+    value: rspack_ids::OccurrenceModuleIdsPluginOptions,
+  ) -> &mut Self {
+    self.occurrence_module_ids = Some(value);
+    self
+  }
+
   /// Set which algorithm to use when choosing chunk ids.
   pub fn chunk_ids(&mut self, value: String) -> &mut Self {
     self.chunk_ids = Some(value);
@@ -3358,6 +3538,23 @@ impl OptimizationOptionsBuilder {
     self
   }
 
+  /// Set the options of split chunks.
+  ///
+  /// Split chunks is enabled by default, only async chunks are selected.
+  pub fn split_chunks<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksOptionsBuilder>,
+  {
+    self.split_chunks = Some(Some(value.into()));
+    self
+  }
+
+  /// Disable split chunks.
+  pub fn disable_split_chunks(&mut self) -> &mut Self {
+    self.split_chunks = Some(None);
+    self
+  }
+
   /// Build [`Optimization`] from options.
   ///
   /// [`Optimization`]: rspack_core::options::Optimization
@@ -3367,7 +3564,7 @@ impl OptimizationOptionsBuilder {
     builder_context: &mut BuilderContext,
     development: bool,
     production: bool,
-    _css: bool,
+    css: bool,
   ) -> Optimization {
     let remove_available_modules = d!(self.remove_available_modules, false);
     let remove_empty_chunks = d!(self.remove_empty_chunks, true);
@@ -3414,6 +3611,24 @@ impl OptimizationOptionsBuilder {
           .plugins
           .push(BuiltinPluginOptions::NaturalModuleIdsPlugin);
       }
+      "hashed" => {
+        builder_context
+          .plugins
+          .push(BuiltinPluginOptions::HashedModuleIdsPlugin(
+            self.hashed_module_ids.take().unwrap_or_default(),
+          ));
+      }
+      "size" => {
+        builder_context
+          .plugins
+          .push(BuiltinPluginOptions::OccurrenceModuleIdsPlugin(
+            self.occurrence_module_ids.take().unwrap_or(
+              rspack_ids::OccurrenceModuleIdsPluginOptions {
+                prioritise_initial: true,
+              },
+            ),
+          ));
+      }
       _ => {
         panic!("moduleIds: {module_ids} is not implemented");
       }
@@ -3517,6 +3732,15 @@ impl OptimizationOptionsBuilder {
         ));
     }
     let inner_graph = d!(self.inner_graph, production);
+    if let Some(mut split_chunks) = f!(self.split_chunks.take(), || Some(Default::default())) {
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::SplitChunksPlugin(split_chunks.build(
+          production,
+          css,
+          used_exports == UsedExportsOption::True,
+        )));
+    }
     if !d!(self.emit_on_errors, !production) {
       builder_context
         .plugins
@@ -3595,157 +3819,1316 @@ impl OptimizationOptionsBuilder {
   }
 }
 
-/// Builder used to build [`Experiments`].
-///
-/// [`Experiments`]: rspack_core::options::Experiments
-#[derive(Debug, Default)]
-pub struct ExperimentsBuilder {
-  /// Whether to enable module layers feature.  
-  layers: Option<bool>,
-  /// Incremental passes.
-  incremental: Option<IncrementalPasses>,
-  /// Whether to enable top level await.
-  top_level_await: Option<bool>,
-  /// Rspack future.
-  rspack_future: Option<RspackFuture>,
-  /// Cache options.
-  cache: Option<ExperimentCacheOptions>,
-  /// Whether to enable output module.
-  output_module: Option<bool>,
-  /// Whether to enable future defaults.
-  future_defaults: Option<bool>,
-  /// Whether to enable css.
-  css: Option<bool>,
-  /// Whether to enable parallel code splitting.
-  parallel_code_splitting: Option<bool>,
-  /// Whether to enable async web assembly.
-  async_web_assembly: Option<bool>,
-  // TODO: lazy compilation
+/// Which chunks will be selected for split chunks.
+#[derive(Clone)]
+pub enum SplitChunksChunks {
+  /// Select async chunks only.
+  Async,
+  /// Select initial chunks only.
+  Initial,
+  /// Select all chunks.
+  All,
+  /// Select chunks whose name matches the regex.
+  Regex(RspackRegex),
+  /// Select chunks by a custom filter.
+  Fn(rspack_plugin_split_chunks::ChunkFilter),
 }
 
-impl From<Experiments> for ExperimentsBuilder {
-  fn from(value: Experiments) -> Self {
-    ExperimentsBuilder {
-      layers: Some(value.layers),
-      incremental: Some(value.incremental),
-      top_level_await: Some(value.top_level_await),
-      rspack_future: Some(value.rspack_future),
-      cache: Some(value.cache),
-      parallel_code_splitting: Some(value.parallel_code_splitting),
-      output_module: None,
-      future_defaults: None,
-      css: None,
-      async_web_assembly: None,
+impl std::fmt::Debug for SplitChunksChunks {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Async => write!(f, "Async"),
+      Self::Initial => write!(f, "Initial"),
+      Self::All => write!(f, "All"),
+      Self::Regex(regex) => f.debug_tuple("Regex").field(regex).finish(),
+      Self::Fn(_) => write!(f, "Fn(...)"),
     }
   }
 }
 
-impl From<&mut ExperimentsBuilder> for ExperimentsBuilder {
-  fn from(value: &mut ExperimentsBuilder) -> Self {
-    ExperimentsBuilder {
-      layers: value.layers.take(),
-      incremental: value.incremental.take(),
-      top_level_await: value.top_level_await.take(),
-      rspack_future: value.rspack_future.take(),
-      cache: value.cache.take(),
-      output_module: value.output_module.take(),
-      future_defaults: value.future_defaults.take(),
-      css: value.css.take(),
-      parallel_code_splitting: value.parallel_code_splitting.take(),
-      async_web_assembly: value.async_web_assembly.take(),
+impl From<SplitChunksChunks> for rspack_plugin_split_chunks::ChunkFilter {
+  fn from(value: SplitChunksChunks) -> Self {
+    match value {
+      SplitChunksChunks::Async => rspack_plugin_split_chunks::create_async_chunk_filter(),
+      SplitChunksChunks::Initial => rspack_plugin_split_chunks::create_initial_chunk_filter(),
+      SplitChunksChunks::All => rspack_plugin_split_chunks::create_all_chunk_filter(),
+      SplitChunksChunks::Regex(regex) => {
+        rspack_plugin_split_chunks::create_regex_chunk_filter_from_str(regex)
+      }
+      SplitChunksChunks::Fn(f) => f,
     }
   }
 }
 
-impl ExperimentsBuilder {
-  /// Set whether to enable layers.
-  pub fn layers(&mut self, layers: bool) -> &mut Self {
-    self.layers = Some(layers);
-    self
+/// Size limit used by split chunks.
+#[derive(Debug, Clone)]
+pub enum SplitChunksSize {
+  /// Size in bytes applied to all of the default size types.
+  Bytes(f64),
+  /// Size in bytes of each source type.
+  Sizes(HashMap<SourceType, f64>),
+}
+
+impl From<f64> for SplitChunksSize {
+  fn from(value: f64) -> Self {
+    Self::Bytes(value)
   }
+}
 
-  /// Set the incremental passes.
-  pub fn incremental(&mut self, incremental: IncrementalPasses) -> &mut Self {
-    self.incremental = Some(incremental);
-    self
+impl From<HashMap<SourceType, f64>> for SplitChunksSize {
+  fn from(value: HashMap<SourceType, f64>) -> Self {
+    Self::Sizes(value)
   }
+}
 
-  /// Set whether to enable top level await.
-  pub fn top_level_await(&mut self, top_level_await: bool) -> &mut Self {
-    self.top_level_await = Some(top_level_await);
+fn create_split_chunk_sizes(
+  size: Option<SplitChunksSize>,
+  default_size_types: &[SourceType],
+) -> SplitChunkSizes {
+  match size {
+    Some(SplitChunksSize::Bytes(size)) => {
+      SplitChunkSizes::with_initial_value(default_size_types, size)
+    }
+    Some(SplitChunksSize::Sizes(sizes)) => {
+      let mut split_chunk_sizes = SplitChunkSizes::empty();
+      split_chunk_sizes.extend(sizes);
+      split_chunk_sizes
+    }
+    None => SplitChunkSizes::empty(),
+  }
+}
+
+/// Builder used to build options of a cache group of split chunks.
+///
+/// See [`CacheGroup`] for more details.
+///
+/// [`CacheGroup`]: rspack_plugin_split_chunks::CacheGroup
+#[derive(Debug, Default)]
+pub struct CacheGroupOptionsBuilder {
+  /// Which chunks will be selected.
+  chunks: Option<SplitChunksChunks>,
+  /// Select modules whose resource matches the regex.
+  test: Option<RspackRegex>,
+  /// Select modules whose module type matches the regex.
+  r#type: Option<RspackRegex>,
+  /// Select modules whose layer starts with the layer.
+  layer: Option<String>,
+  /// The name of the split chunk.
+  name: Option<String>,
+  /// The priority of the cache group.
+  priority: Option<f64>,
+  /// Whether to ignore the size and request limits.
+  enforce: Option<bool>,
+  /// Whether to reuse the existing chunk which contains exactly the same modules.
+  reuse_existing_chunk: Option<bool>,
+  /// The minimum number of chunks that must share a module before splitting.
+  min_chunks: Option<u32>,
+  /// The minimum size of the split chunk.
+  min_size: Option<SplitChunksSize>,
+  /// The minimum size reduction of the main chunk.
+  min_size_reduction: Option<SplitChunksSize>,
+  /// The maximum size of the split chunk.
+  max_size: Option<SplitChunksSize>,
+  /// The maximum size of the split async chunk.
+  max_async_size: Option<SplitChunksSize>,
+  /// The maximum size of the split initial chunk.
+  max_initial_size: Option<SplitChunksSize>,
+  /// The maximum number of parallel requests of async chunks.
+  max_async_requests: Option<f64>,
+  /// The maximum number of parallel requests of an entrypoint.
+  max_initial_requests: Option<f64>,
+  /// The hint of the chunk id.
+  id_hint: Option<String>,
+  /// The filename of the split chunk.
+  filename: Option<Filename>,
+  /// The delimiter of the generated name.
+  automatic_name_delimiter: Option<String>,
+  /// Whether to split chunks by the used exports of modules.
+  used_exports: Option<bool>,
+}
+
+impl From<&mut CacheGroupOptionsBuilder> for CacheGroupOptionsBuilder {
+  fn from(value: &mut CacheGroupOptionsBuilder) -> Self {
+    CacheGroupOptionsBuilder {
+      chunks: value.chunks.take(),
+      test: value.test.take(),
+      r#type: value.r#type.take(),
+      layer: value.layer.take(),
+      name: value.name.take(),
+      priority: value.priority.take(),
+      enforce: value.enforce.take(),
+      reuse_existing_chunk: value.reuse_existing_chunk.take(),
+      min_chunks: value.min_chunks.take(),
+      min_size: value.min_size.take(),
+      min_size_reduction: value.min_size_reduction.take(),
+      max_size: value.max_size.take(),
+      max_async_size: value.max_async_size.take(),
+      max_initial_size: value.max_initial_size.take(),
+      max_async_requests: value.max_async_requests.take(),
+      max_initial_requests: value.max_initial_requests.take(),
+      id_hint: value.id_hint.take(),
+      filename: value.filename.take(),
+      automatic_name_delimiter: value.automatic_name_delimiter.take(),
+      used_exports: value.used_exports.take(),
+    }
+  }
+}
+
+impl CacheGroupOptionsBuilder {
+  /// Set which chunks will be selected.
+  pub fn chunks(&mut self, value: SplitChunksChunks) -> &mut Self {
+    self.chunks = Some(value);
     self
   }
 
-  /// Set the cache options.
-  pub fn cache(&mut self, cache: ExperimentCacheOptions) -> &mut Self {
-    self.cache = Some(cache);
+  /// Set the regex to select modules by their resource or name.
+  pub fn test(&mut self, value: RspackRegex) -> &mut Self {
+    self.test = Some(value);
     self
   }
 
-  /// Set whether to enable future defaults.
-  pub fn future_defaults(&mut self, future_defaults: bool) -> &mut Self {
-    self.future_defaults = Some(future_defaults);
+  /// Set the regex to select modules by their module type.
+  pub fn r#type(&mut self, value: RspackRegex) -> &mut Self {
+    self.r#type = Some(value);
     self
   }
 
-  /// Set whether to enable css.
-  pub fn css(&mut self, css: bool) -> &mut Self {
-    self.css = Some(css);
+  /// Set the layer to select modules, modules whose layer starts with the value are selected.
+  pub fn layer<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.layer = Some(value.into());
     self
   }
 
-  /// Set whether to enable async web assembly.
-  pub fn async_web_assembly(&mut self, async_web_assembly: bool) -> &mut Self {
-    self.async_web_assembly = Some(async_web_assembly);
+  /// Set the name of the split chunk.
+  pub fn name<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.name = Some(value.into());
     self
   }
 
-  /// Set whether to enable parallel code splitting.
-  pub fn parallel_code_splitting(&mut self, parallel_code_splitting: bool) -> &mut Self {
-    self.parallel_code_splitting = Some(parallel_code_splitting);
+  /// Set the priority of the cache group.
+  ///
+  /// Default set to `0`.
+  pub fn priority(&mut self, value: f64) -> &mut Self {
+    self.priority = Some(value);
     self
   }
 
-  /// Build [`Experiments`] from options.
+  /// Set whether to ignore `min_size`, `min_chunks` and the size limits of split chunks.
   ///
-  /// [`Experiments`]: rspack_core::options::Experiments
-  #[must_use]
-  fn build(
-    &mut self,
-    _builder_context: &mut BuilderContext,
-    development: bool,
-    production: bool,
-  ) -> Experiments {
-    let layers = d!(self.layers, false);
-    let incremental = f!(self.incremental.take(), || {
-      if !production {
-        IncrementalPasses::MAKE | IncrementalPasses::EMIT_ASSETS
-      } else {
-        IncrementalPasses::empty()
-      }
-    });
-    let top_level_await = d!(self.top_level_await, true);
-    let cache = f!(self.cache.take(), || {
-      if development {
-        ExperimentCacheOptions::Memory
-      } else {
-        ExperimentCacheOptions::Disabled
-      }
-    });
-    let rspack_future = d!(self.rspack_future.take(), RspackFuture {});
+  /// Default set to `false`.
+  pub fn enforce(&mut self, value: bool) -> &mut Self {
+    self.enforce = Some(value);
+    self
+  }
 
-    // Builder specific
-    let future_defaults = w!(self.future_defaults, false);
-    w!(self.css, *future_defaults);
-    w!(self.async_web_assembly, *future_defaults);
-    w!(self.output_module, false);
+  /// Set whether to reuse the existing chunk which contains exactly the same modules.
+  ///
+  /// Default set to `false`.
+  pub fn reuse_existing_chunk(&mut self, value: bool) -> &mut Self {
+    self.reuse_existing_chunk = Some(value);
+    self
+  }
+
+  /// Set the minimum number of chunks that must share a module before splitting.
+  pub fn min_chunks(&mut self, value: u32) -> &mut Self {
+    self.min_chunks = Some(value);
+    self
+  }
+
+  /// Set the minimum size of the split chunk.
+  pub fn min_size<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.min_size = Some(value.into());
+    self
+  }
+
+  /// Set the minimum size reduction of the main chunk.
+  pub fn min_size_reduction<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.min_size_reduction = Some(value.into());
+    self
+  }
+
+  /// Set the maximum size of the split chunk.
+  pub fn max_size<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.max_size = Some(value.into());
+    self
+  }
+
+  /// Set the maximum size of the split async chunk.
+  pub fn max_async_size<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.max_async_size = Some(value.into());
+    self
+  }
+
+  /// Set the maximum size of the split initial chunk.
+  pub fn max_initial_size<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.max_initial_size = Some(value.into());
+    self
+  }
+
+  /// Set the maximum number of parallel requests of async chunks.
+  pub fn max_async_requests(&mut self, value: f64) -> &mut Self {
+    self.max_async_requests = Some(value);
+    self
+  }
+
+  /// Set the maximum number of parallel requests of an entrypoint.
+  pub fn max_initial_requests(&mut self, value: f64) -> &mut Self {
+    self.max_initial_requests = Some(value);
+    self
+  }
+
+  /// Set the hint of the chunk id.
+  ///
+  /// Default set to the key of the cache group.
+  pub fn id_hint<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.id_hint = Some(value.into());
+    self
+  }
+
+  /// Set the filename of the split chunk.
+  pub fn filename(&mut self, value: Filename) -> &mut Self {
+    self.filename = Some(value);
+    self
+  }
+
+  /// Set the delimiter of the generated name.
+  pub fn automatic_name_delimiter<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.automatic_name_delimiter = Some(value.into());
+    self
+  }
+
+  /// Set whether to split chunks by the used exports of modules.
+  pub fn used_exports(&mut self, value: bool) -> &mut Self {
+    self.used_exports = Some(value);
+    self
+  }
+
+  /// Build [`CacheGroup`] from options, the unset options are inherited from `split_chunks`.
+  ///
+  /// [`CacheGroup`]: rspack_plugin_split_chunks::CacheGroup
+  fn build(&mut self, key: String, overall: &SplitChunksOverall) -> CacheGroup {
+    let enforce = d!(self.enforce, false);
+    let empty_sizes = SplitChunkSizes::empty();
+    let create_sizes =
+      |size: Option<SplitChunksSize>| create_split_chunk_sizes(size, &overall.default_size_types);
+
+    let min_size = create_sizes(self.min_size.take()).merge(if enforce {
+      &empty_sizes
+    } else {
+      &overall.min_size
+    });
+    let min_size_reduction = create_sizes(self.min_size_reduction.take()).merge(if enforce {
+      &empty_sizes
+    } else {
+      &overall.min_size_reduction
+    });
+    let max_size = create_sizes(self.max_size.take());
+    let max_async_size = create_sizes(self.max_async_size.take())
+      .merge(&max_size)
+      .merge(if enforce {
+        &empty_sizes
+      } else {
+        &overall.max_async_size
+      });
+    let max_initial_size = create_sizes(self.max_initial_size.take())
+      .merge(&max_size)
+      .merge(if enforce {
+        &empty_sizes
+      } else {
+        &overall.max_initial_size
+      });
+
+    let min_chunks = if enforce {
+      1
+    } else {
+      d!(self.min_chunks, overall.min_chunks)
+    };
+    let (max_async_requests, max_initial_requests) = if enforce {
+      (f64::INFINITY, f64::INFINITY)
+    } else {
+      (
+        d!(self.max_async_requests, overall.max_async_requests),
+        d!(self.max_initial_requests, overall.max_initial_requests),
+      )
+    };
+
+    let test = self
+      .test
+      .take()
+      .map_or(CacheGroupTest::Enabled, CacheGroupTest::RegExp);
+    let r#type = self.r#type.take().map_or_else(
+      rspack_plugin_split_chunks::create_default_module_type_filter,
+      |regex| -> rspack_plugin_split_chunks::ModuleTypeFilter {
+        Arc::new(move |module| regex.test(module.module_type().as_str()))
+      },
+    );
+    let layer = self.layer.take().map_or_else(
+      rspack_plugin_split_chunks::create_default_module_layer_filter,
+      |test| -> rspack_plugin_split_chunks::ModuleLayerFilter {
+        Arc::new(move |layer| {
+          Ok(match layer {
+            Some(layer) => layer.starts_with(&test),
+            None => test.is_empty(),
+          })
+        })
+      },
+    );
+    let name = self
+      .name
+      .take()
+      .map_or_else(|| overall.name.clone(), ChunkNameGetter::String);
+
+    CacheGroup {
+      id_hint: f!(self.id_hint.take(), || key.clone()),
+      key,
+      chunk_filter: self
+        .chunks
+        .take()
+        .map_or_else(|| overall.chunks.clone(), Into::into),
+      test,
+      r#type,
+      layer,
+      name,
+      priority: d!(self.priority, 0.0),
+      min_size,
+      min_size_reduction,
+      reuse_existing_chunk: d!(self.reuse_existing_chunk, false),
+      min_chunks,
+      max_async_requests,
+      max_initial_requests,
+      max_async_size,
+      max_initial_size,
+      filename: self.filename.take().or_else(|| overall.filename.clone()),
+      automatic_name_delimiter: f!(self.automatic_name_delimiter.take(), || overall
+        .automatic_name_delimiter
+        .clone()),
+      used_exports: d!(self.used_exports, overall.used_exports),
+    }
+  }
+}
+
+/// Options of split chunks inherited by cache groups.
+struct SplitChunksOverall {
+  chunks: rspack_plugin_split_chunks::ChunkFilter,
+  name: ChunkNameGetter,
+  default_size_types: Vec<SourceType>,
+  min_chunks: u32,
+  min_size: SplitChunkSizes,
+  min_size_reduction: SplitChunkSizes,
+  max_async_size: SplitChunkSizes,
+  max_initial_size: SplitChunkSizes,
+  max_async_requests: f64,
+  max_initial_requests: f64,
+  filename: Option<Filename>,
+  automatic_name_delimiter: String,
+  used_exports: bool,
+}
+
+/// Builder used to build options for split chunks plugin.
+///
+/// See [`PluginOptions`] for more details.
+///
+/// [`PluginOptions`]: rspack_plugin_split_chunks::PluginOptions
+#[derive(Debug, Default)]
+pub struct SplitChunksOptionsBuilder {
+  /// Which chunks will be selected.
+  chunks: Option<SplitChunksChunks>,
+  /// The name of the split chunks.
+  name: Option<String>,
+  /// The source types used when a size is set by a number.
+  default_size_types: Option<Vec<SourceType>>,
+  /// The minimum number of chunks that must share a module before splitting.
+  min_chunks: Option<u32>,
+  /// The minimum size of the split chunk.
+  min_size: Option<SplitChunksSize>,
+  /// The minimum size reduction of the main chunk.
+  min_size_reduction: Option<SplitChunksSize>,
+  /// The maximum size of the split chunk.
+  max_size: Option<SplitChunksSize>,
+  /// The maximum size of the split async chunk.
+  max_async_size: Option<SplitChunksSize>,
+  /// The maximum size of the split initial chunk.
+  max_initial_size: Option<SplitChunksSize>,
+  /// The maximum number of parallel requests of async chunks.
+  max_async_requests: Option<f64>,
+  /// The maximum number of parallel requests of an entrypoint.
+  max_initial_requests: Option<f64>,
+  /// The filename of the split chunks.
+  filename: Option<Filename>,
+  /// The delimiter of the generated name.
+  automatic_name_delimiter: Option<String>,
+  /// Whether to hide the path info of the generated name.
+  hide_path_info: Option<bool>,
+  /// Whether to split chunks by the used exports of modules.
+  used_exports: Option<bool>,
+  /// Cache groups, `None` means the cache group is disabled.
+  cache_groups: IndexMap<String, Option<CacheGroupOptionsBuilder>>,
+}
+
+impl From<&mut SplitChunksOptionsBuilder> for SplitChunksOptionsBuilder {
+  fn from(value: &mut SplitChunksOptionsBuilder) -> Self {
+    SplitChunksOptionsBuilder {
+      chunks: value.chunks.take(),
+      name: value.name.take(),
+      default_size_types: value.default_size_types.take(),
+      min_chunks: value.min_chunks.take(),
+      min_size: value.min_size.take(),
+      min_size_reduction: value.min_size_reduction.take(),
+      max_size: value.max_size.take(),
+      max_async_size: value.max_async_size.take(),
+      max_initial_size: value.max_initial_size.take(),
+      max_async_requests: value.max_async_requests.take(),
+      max_initial_requests: value.max_initial_requests.take(),
+      filename: value.filename.take(),
+      automatic_name_delimiter: value.automatic_name_delimiter.take(),
+      hide_path_info: value.hide_path_info.take(),
+      used_exports: value.used_exports.take(),
+      cache_groups: std::mem::take(&mut value.cache_groups),
+    }
+  }
+}
+
+impl SplitChunksOptionsBuilder {
+  /// Set which chunks will be selected.
+  ///
+  /// Default set to [`SplitChunksChunks::Async`].
+  pub fn chunks(&mut self, value: SplitChunksChunks) -> &mut Self {
+    self.chunks = Some(value);
+    self
+  }
+
+  /// Set the name of the split chunks.
+  pub fn name<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.name = Some(value.into());
+    self
+  }
+
+  /// Set the source types used when a size is set by a number.
+  ///
+  /// Default set to `["javascript", "unknown"]`, and `css` is added when css is enabled.
+  pub fn default_size_types(&mut self, value: Vec<SourceType>) -> &mut Self {
+    self.default_size_types = Some(value);
+    self
+  }
+
+  /// Set the minimum number of chunks that must share a module before splitting.
+  ///
+  /// Default set to `1`.
+  pub fn min_chunks(&mut self, value: u32) -> &mut Self {
+    self.min_chunks = Some(value);
+    self
+  }
+
+  /// Set the minimum size of the split chunk.
+  ///
+  /// Default set to `20000` in production mode.
+  /// Default set to `10000` in development mode.
+  pub fn min_size<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.min_size = Some(value.into());
+    self
+  }
+
+  /// Set the minimum size reduction of the main chunk.
+  pub fn min_size_reduction<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.min_size_reduction = Some(value.into());
+    self
+  }
+
+  /// Set the maximum size of the split chunk.
+  pub fn max_size<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.max_size = Some(value.into());
+    self
+  }
+
+  /// Set the maximum size of the split async chunk.
+  pub fn max_async_size<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.max_async_size = Some(value.into());
+    self
+  }
+
+  /// Set the maximum size of the split initial chunk.
+  pub fn max_initial_size<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksSize>,
+  {
+    self.max_initial_size = Some(value.into());
+    self
+  }
+
+  /// Set the maximum number of parallel requests of async chunks.
+  ///
+  /// Default set to `30` in production mode.
+  /// Default set to `Infinity` in development mode.
+  pub fn max_async_requests(&mut self, value: f64) -> &mut Self {
+    self.max_async_requests = Some(value);
+    self
+  }
+
+  /// Set the maximum number of parallel requests of an entrypoint.
+  ///
+  /// Default set to `30` in production mode.
+  /// Default set to `Infinity` in development mode.
+  pub fn max_initial_requests(&mut self, value: f64) -> &mut Self {
+    self.max_initial_requests = Some(value);
+    self
+  }
+
+  /// Set the filename of the split chunks.
+  pub fn filename(&mut self, value: Filename) -> &mut Self {
+    self.filename = Some(value);
+    self
+  }
+
+  /// Set the delimiter of the generated name.
+  ///
+  /// Default set to `-`.
+  pub fn automatic_name_delimiter<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.automatic_name_delimiter = Some(value.into());
+    self
+  }
+
+  /// Set whether to hide the path info of the generated name.
+  ///
+  /// Default set to `true` in production mode.
+  /// Default set to `false` in development mode.
+  pub fn hide_path_info(&mut self, value: bool) -> &mut Self {
+    self.hide_path_info = Some(value);
+    self
+  }
+
+  /// Set whether to split chunks by the used exports of modules.
+  ///
+  /// Default set to `true` when `optimization.used_exports` is `true`.
+  pub fn used_exports(&mut self, value: bool) -> &mut Self {
+    self.used_exports = Some(value);
+    self
+  }
+
+  /// Add a cache group, the cache group with the same key will be overridden.
+  ///
+  /// The `default` and `defaultVendors` cache groups are added by default.
+  pub fn cache_group<K, V>(&mut self, key: K, value: V) -> &mut Self
+  where
+    K: Into<String>,
+    V: Into<CacheGroupOptionsBuilder>,
+  {
+    self.cache_groups.insert(key.into(), Some(value.into()));
+    self
+  }
+
+  /// Disable a cache group, this is useful to disable the `default` and `defaultVendors` cache groups.
+  pub fn disable_cache_group<K>(&mut self, key: K) -> &mut Self
+  where
+    K: Into<String>,
+  {
+    self.cache_groups.insert(key.into(), None);
+    self
+  }
+
+  /// Build [`PluginOptions`] from options.
+  ///
+  /// [`PluginOptions`]: rspack_plugin_split_chunks::PluginOptions
+  fn build(
+    &mut self,
+    production: bool,
+    css: bool,
+    used_exports: bool,
+  ) -> rspack_plugin_split_chunks::PluginOptions {
+    let default_size_types = f!(self.default_size_types.take(), || {
+      if css {
+        vec![SourceType::Css, SourceType::JavaScript, SourceType::Unknown]
+      } else {
+        vec![SourceType::JavaScript, SourceType::Unknown]
+      }
+    });
+    let create_sizes =
+      |size: Option<SplitChunksSize>| create_split_chunk_sizes(size, &default_size_types);
+
+    let min_size = create_sizes(Some(f!(self.min_size.take(), || {
+      SplitChunksSize::Bytes(if production { 20000.0 } else { 10000.0 })
+    })));
+    let max_size = create_sizes(self.max_size.take());
+    let max_async_size = create_sizes(self.max_async_size.take()).merge(&max_size);
+    let max_initial_size = create_sizes(self.max_initial_size.take()).merge(&max_size);
+    let max_requests = if production { 30.0 } else { f64::INFINITY };
+
+    let overall = SplitChunksOverall {
+      chunks: self.chunks.take().map_or_else(
+        rspack_plugin_split_chunks::create_async_chunk_filter,
+        Into::into,
+      ),
+      name: self
+        .name
+        .take()
+        .map_or(ChunkNameGetter::Disabled, ChunkNameGetter::String),
+      min_chunks: d!(self.min_chunks, 1),
+      min_size_reduction: create_sizes(self.min_size_reduction.take()),
+      max_async_requests: d!(self.max_async_requests, max_requests),
+      max_initial_requests: d!(self.max_initial_requests, max_requests),
+      filename: self.filename.take(),
+      automatic_name_delimiter: f!(self.automatic_name_delimiter.take(), || "-".to_string()),
+      used_exports: d!(self.used_exports, used_exports),
+      min_size,
+      max_async_size,
+      max_initial_size,
+      default_size_types,
+    };
+
+    let mut cache_groups = std::mem::take(&mut self.cache_groups);
+    cache_groups
+      .entry("default".to_string())
+      .or_insert_with(|| {
+        let mut cache_group = CacheGroupOptionsBuilder::default();
+        cache_group
+          .id_hint("")
+          .reuse_existing_chunk(true)
+          .min_chunks(2)
+          .priority(-20.0);
+        Some(cache_group)
+      });
+    cache_groups
+      .entry("defaultVendors".to_string())
+      .or_insert_with(|| {
+        let mut cache_group = CacheGroupOptionsBuilder::default();
+        cache_group
+          .id_hint("vendors")
+          .reuse_existing_chunk(true)
+          .test(
+            RspackRegex::with_flags(r"[\\/]node_modules[\\/]", "i")
+              .expect("should be a valid regex"),
+          )
+          .priority(-10.0);
+        Some(cache_group)
+      });
+    let cache_groups = cache_groups
+      .into_iter()
+      .filter_map(|(key, cache_group)| Some(cache_group?.build(key, &overall)))
+      .collect();
+
+    rspack_plugin_split_chunks::PluginOptions {
+      cache_groups,
+      fallback_cache_group: rspack_plugin_split_chunks::FallbackCacheGroup {
+        chunks_filter: rspack_plugin_split_chunks::create_all_chunk_filter(),
+        min_size: overall.min_size.clone(),
+        max_async_size: overall.max_async_size.clone(),
+        max_initial_size: overall.max_initial_size.clone(),
+        automatic_name_delimiter: overall.automatic_name_delimiter.clone(),
+      },
+      hide_path_info: Some(d!(self.hide_path_info, production)),
+    }
+  }
+}
+
+/// The type of performance hints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceHints {
+  /// Emit warnings when the limits are exceeded.
+  Warning,
+  /// Emit errors when the limits are exceeded.
+  Error,
+  /// Do not emit any hints.
+  False,
+}
+
+/// Builder used to build options for performance hints.
+///
+/// See [`SizeLimitsPluginOptions`] for more details.
+///
+/// [`SizeLimitsPluginOptions`]: rspack_plugin_size_limits::SizeLimitsPluginOptions
+#[derive(Default)]
+pub struct PerformanceOptionsBuilder {
+  /// The type of hints.
+  hints: Option<PerformanceHints>,
+  /// The maximum size of an asset in bytes.
+  max_asset_size: Option<f64>,
+  /// The maximum size of an entrypoint in bytes.
+  max_entrypoint_size: Option<f64>,
+  /// The filter of the assets taken into account.
+  asset_filter: Option<rspack_plugin_size_limits::AssetFilterFn>,
+}
+
+impl std::fmt::Debug for PerformanceOptionsBuilder {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("PerformanceOptionsBuilder")
+      .field("hints", &self.hints)
+      .field("max_asset_size", &self.max_asset_size)
+      .field("max_entrypoint_size", &self.max_entrypoint_size)
+      .finish_non_exhaustive()
+  }
+}
+
+impl From<&mut PerformanceOptionsBuilder> for PerformanceOptionsBuilder {
+  fn from(value: &mut PerformanceOptionsBuilder) -> Self {
+    PerformanceOptionsBuilder {
+      hints: value.hints.take(),
+      max_asset_size: value.max_asset_size.take(),
+      max_entrypoint_size: value.max_entrypoint_size.take(),
+      asset_filter: value.asset_filter.take(),
+    }
+  }
+}
+
+impl PerformanceOptionsBuilder {
+  /// Set the type of hints.
+  ///
+  /// Default set to [`PerformanceHints::Warning`] in production mode.
+  /// Default set to [`PerformanceHints::False`] in other modes.
+  pub fn hints(&mut self, hints: PerformanceHints) -> &mut Self {
+    self.hints = Some(hints);
+    self
+  }
+
+  /// Set the maximum size of an asset in bytes.
+  ///
+  /// Default set to `250000`.
+  pub fn max_asset_size(&mut self, max_asset_size: f64) -> &mut Self {
+    self.max_asset_size = Some(max_asset_size);
+    self
+  }
+
+  /// Set the maximum size of an entrypoint in bytes.
+  ///
+  /// Default set to `250000`.
+  pub fn max_entrypoint_size(&mut self, max_entrypoint_size: f64) -> &mut Self {
+    self.max_entrypoint_size = Some(max_entrypoint_size);
+    self
+  }
+
+  /// Set the filter of the assets taken into account.
+  ///
+  /// Default to exclude the assets marked as `development`.
+  pub fn asset_filter(
+    &mut self,
+    asset_filter: rspack_plugin_size_limits::AssetFilterFn,
+  ) -> &mut Self {
+    self.asset_filter = Some(asset_filter);
+    self
+  }
+
+  /// Build [`SizeLimitsPluginOptions`] from options.
+  ///
+  /// [`SizeLimitsPluginOptions`]: rspack_plugin_size_limits::SizeLimitsPluginOptions
+  fn build(&mut self, production: bool) -> rspack_plugin_size_limits::SizeLimitsPluginOptions {
+    let hints = d!(
+      self.hints.take(),
+      if production {
+        PerformanceHints::Warning
+      } else {
+        PerformanceHints::False
+      }
+    );
+    rspack_plugin_size_limits::SizeLimitsPluginOptions {
+      asset_filter: self.asset_filter.take(),
+      hints: match hints {
+        PerformanceHints::Warning => Some("warning".to_string()),
+        PerformanceHints::Error => Some("error".to_string()),
+        PerformanceHints::False => None,
+      },
+      max_asset_size: Some(d!(self.max_asset_size, 250_000.0)),
+      max_entrypoint_size: Some(d!(self.max_entrypoint_size, 250_000.0)),
+    }
+  }
+}
+
+/// Builder used to build options for [`RspackFuture`], same as `experiments.rspackFuture` in JS.
+///
+/// [`RspackFuture`]: rspack_core::options::RspackFuture
+#[derive(Debug, Default)]
+pub struct RspackFutureOptionsBuilder {
+  /// The bundler info injected into the runtime.
+  bundler_info: Option<BundlerInfoOptionsBuilder>,
+}
+
+impl From<RspackFuture> for RspackFutureOptionsBuilder {
+  fn from(_value: RspackFuture) -> Self {
+    RspackFutureOptionsBuilder::default()
+  }
+}
+
+impl From<&mut RspackFutureOptionsBuilder> for RspackFutureOptionsBuilder {
+  fn from(value: &mut RspackFutureOptionsBuilder) -> Self {
+    RspackFutureOptionsBuilder {
+      bundler_info: value.bundler_info.take(),
+    }
+  }
+}
+
+impl RspackFutureOptionsBuilder {
+  /// Set the bundler info injected into the runtime.
+  ///
+  /// Bundler info is disabled by default.
+  pub fn bundler_info<V>(&mut self, bundler_info: V) -> &mut Self
+  where
+    V: Into<BundlerInfoOptionsBuilder>,
+  {
+    self.bundler_info = Some(bundler_info.into());
+    self
+  }
+}
+
+/// Builder used to build options for bundler info injected into the runtime.
+#[derive(Debug, Default)]
+pub struct BundlerInfoOptionsBuilder {
+  /// The version of the bundler.
+  version: Option<String>,
+  /// The name of the bundler.
+  bundler: Option<String>,
+  /// Which bundler info will be injected regardless of whether it is used.
+  force: Option<rspack_plugin_runtime::BundlerInfoForceMode>,
+}
+
+impl From<&mut BundlerInfoOptionsBuilder> for BundlerInfoOptionsBuilder {
+  fn from(value: &mut BundlerInfoOptionsBuilder) -> Self {
+    BundlerInfoOptionsBuilder {
+      version: value.version.take(),
+      bundler: value.bundler.take(),
+      force: value.force.take(),
+    }
+  }
+}
+
+impl BundlerInfoOptionsBuilder {
+  /// Set the version of the bundler.
+  ///
+  /// Default set to the version of `@rspack/core`.
+  pub fn version<V>(&mut self, version: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.version = Some(version.into());
+    self
+  }
+
+  /// Set the name of the bundler.
+  ///
+  /// Default set to `rspack`.
+  pub fn bundler<V>(&mut self, bundler: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.bundler = Some(bundler.into());
+    self
+  }
+
+  /// Set which bundler info will be injected regardless of whether it is used.
+  ///
+  /// Default set to `All` when the output is not a library, otherwise `Auto`.
+  pub fn force(&mut self, force: rspack_plugin_runtime::BundlerInfoForceMode) -> &mut Self {
+    self.force = Some(force);
+    self
+  }
+
+  /// Build the options of bundler info plugin.
+  fn build(
+    &mut self,
+    library: bool,
+  ) -> (String, String, rspack_plugin_runtime::BundlerInfoForceMode) {
+    let version = f!(self.version.take(), || rspack_version!().to_string());
+    let bundler = f!(self.bundler.take(), || "rspack".to_string());
+    let force = f!(self.force.take(), || {
+      if library {
+        rspack_plugin_runtime::BundlerInfoForceMode::Auto
+      } else {
+        rspack_plugin_runtime::BundlerInfoForceMode::All
+      }
+    });
+    (version, bundler, force)
+  }
+}
+
+type LazyCompilationTestFnInner = Arc<dyn Fn(&dyn Module) -> bool + Send + Sync>;
+
+/// Custom function to test whether a module should be compiled lazily.
+#[derive(Clone)]
+pub struct LazyCompilationTestFn(LazyCompilationTestFnInner);
+
+impl LazyCompilationTestFn {
+  /// Create a test function for lazy compilation.
+  pub fn new<F>(f: F) -> Self
+  where
+    F: Fn(&dyn Module) -> bool + Send + Sync + 'static,
+  {
+    Self(Arc::new(f))
+  }
+}
+
+impl std::fmt::Debug for LazyCompilationTestFn {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "LazyCompilationTestFn(...)")
+  }
+}
+
+impl LazyCompilationTestCheck for LazyCompilationTestFn {
+  fn test(
+    &self,
+    _compiler_id: CompilerId,
+    _compilation_id: CompilationId,
+    module: &dyn Module,
+  ) -> bool {
+    (self.0)(module)
+  }
+}
+
+/// Options of lazy compilation plugin.
+#[derive(Debug)]
+pub(super) struct LazyCompilationOptions {
+  pub(super) cacheable: bool,
+  pub(super) backend: Box<dyn Backend>,
+  pub(super) test: Option<LazyCompilationTest<LazyCompilationTestFn>>,
+  pub(super) entries: bool,
+  pub(super) imports: bool,
+}
+
+/// Builder used to build options for lazy compilation.
+///
+/// There is no default builder, as the backend is required.
+#[derive(Debug)]
+pub struct LazyCompilationOptionsBuilder {
+  /// The backend which serves the lazily compiled modules, taken when the builder is moved.
+  backend: Option<Box<dyn Backend>>,
+  /// Whether to compile entries lazily.
+  entries: Option<bool>,
+  /// Whether to compile dynamic imports lazily.
+  imports: Option<bool>,
+  /// Which modules should be compiled lazily.
+  test: Option<LazyCompilationTest<LazyCompilationTestFn>>,
+  /// Whether the proxy modules are cacheable.
+  cacheable: Option<bool>,
+}
+
+impl From<&mut LazyCompilationOptionsBuilder> for LazyCompilationOptionsBuilder {
+  fn from(value: &mut LazyCompilationOptionsBuilder) -> Self {
+    LazyCompilationOptionsBuilder {
+      backend: value.backend.take(),
+      entries: value.entries.take(),
+      imports: value.imports.take(),
+      test: value.test.take(),
+      cacheable: value.cacheable.take(),
+    }
+  }
+}
+
+impl LazyCompilationOptionsBuilder {
+  /// Create a lazy compilation builder with the backend serving the lazily compiled modules.
+  pub fn new<B>(backend: B) -> Self
+  where
+    B: Backend + 'static,
+  {
+    Self {
+      backend: Some(Box::new(backend)),
+      entries: None,
+      imports: None,
+      test: None,
+      cacheable: None,
+    }
+  }
+
+  /// Set whether to compile entries lazily.
+  ///
+  /// Default set to `true`.
+  pub fn entries(&mut self, entries: bool) -> &mut Self {
+    self.entries = Some(entries);
+    self
+  }
+
+  /// Set whether to compile dynamic imports lazily.
+  ///
+  /// Default set to `true`.
+  pub fn imports(&mut self, imports: bool) -> &mut Self {
+    self.imports = Some(imports);
+    self
+  }
+
+  /// Only compile the modules whose name matches the regex lazily.
+  pub fn test(&mut self, test: RspackRegex) -> &mut Self {
+    self.test = Some(LazyCompilationTest::Regex(test));
+    self
+  }
+
+  /// Only compile the modules passing the test function lazily.
+  pub fn test_fn(&mut self, test: LazyCompilationTestFn) -> &mut Self {
+    self.test = Some(LazyCompilationTest::Fn(test));
+    self
+  }
+
+  /// Set whether the proxy modules are cacheable.
+  ///
+  /// Default set to `true`.
+  pub fn cacheable(&mut self, cacheable: bool) -> &mut Self {
+    self.cacheable = Some(cacheable);
+    self
+  }
+
+  /// Build the options of lazy compilation plugin.
+  fn build(&mut self) -> LazyCompilationOptions {
+    LazyCompilationOptions {
+      cacheable: d!(self.cacheable, true),
+      backend: expect!(self.backend.take()),
+      test: self.test.take(),
+      entries: d!(self.entries, true),
+      imports: d!(self.imports, true),
+    }
+  }
+}
+
+/// Builder used to build [`Experiments`].
+///
+/// [`Experiments`]: rspack_core::options::Experiments
+#[derive(Debug, Default)]
+pub struct ExperimentsBuilder {
+  /// Whether to enable module layers feature.  
+  layers: Option<bool>,
+  /// Incremental passes.
+  incremental: Option<IncrementalPasses>,
+  /// Whether to enable top level await.
+  top_level_await: Option<bool>,
+  /// Rspack future.
+  rspack_future: Option<RspackFutureOptionsBuilder>,
+  /// Cache options.
+  cache: Option<ExperimentCacheOptions>,
+  /// Whether to enable output module.
+  output_module: Option<bool>,
+  /// Whether to enable future defaults.
+  future_defaults: Option<bool>,
+  /// Whether to enable css.
+  css: Option<bool>,
+  /// Whether to enable parallel code splitting.
+  parallel_code_splitting: Option<bool>,
+  /// Whether to enable async web assembly.
+  async_web_assembly: Option<bool>,
+  /// Whether to enable lazy barrel.
+  lazy_barrel: Option<bool>,
+  /// Whether to enable inline const.
+  inline_const: Option<bool>,
+  /// Lazy compilation options.
+  lazy_compilation: Option<LazyCompilationOptionsBuilder>,
+}
+
+impl From<Experiments> for ExperimentsBuilder {
+  fn from(value: Experiments) -> Self {
+    ExperimentsBuilder {
+      layers: Some(value.layers),
+      incremental: Some(value.incremental),
+      top_level_await: Some(value.top_level_await),
+      rspack_future: Some(value.rspack_future.into()),
+      cache: Some(value.cache),
+      parallel_code_splitting: Some(value.parallel_code_splitting),
+      output_module: None,
+      future_defaults: None,
+      css: None,
+      async_web_assembly: None,
+      lazy_barrel: Some(value.lazy_barrel),
+      inline_const: Some(value.inline_const),
+      lazy_compilation: None,
+    }
+  }
+}
+
+impl From<&mut ExperimentsBuilder> for ExperimentsBuilder {
+  fn from(value: &mut ExperimentsBuilder) -> Self {
+    ExperimentsBuilder {
+      layers: value.layers.take(),
+      incremental: value.incremental.take(),
+      top_level_await: value.top_level_await.take(),
+      rspack_future: value.rspack_future.take(),
+      cache: value.cache.take(),
+      output_module: value.output_module.take(),
+      future_defaults: value.future_defaults.take(),
+      css: value.css.take(),
+      parallel_code_splitting: value.parallel_code_splitting.take(),
+      async_web_assembly: value.async_web_assembly.take(),
+      lazy_barrel: value.lazy_barrel.take(),
+      inline_const: value.inline_const.take(),
+      lazy_compilation: value.lazy_compilation.take(),
+    }
+  }
+}
+
+impl ExperimentsBuilder {
+  /// Set whether to enable layers.
+  pub fn layers(&mut self, layers: bool) -> &mut Self {
+    self.layers = Some(layers);
+    self
+  }
+
+  /// Set the incremental passes.
+  pub fn incremental(&mut self, incremental: IncrementalPasses) -> &mut Self {
+    self.incremental = Some(incremental);
+    self
+  }
+
+  /// Set whether to enable top level await.
+  pub fn top_level_await(&mut self, top_level_await: bool) -> &mut Self {
+    self.top_level_await = Some(top_level_await);
+    self
+  }
+
+  /// Set the cache options.
+  pub fn cache(&mut self, cache: ExperimentCacheOptions) -> &mut Self {
+    self.cache = Some(cache);
+    self
+  }
+
+  /// Set the rspack future options.
+  pub fn rspack_future<V>(&mut self, rspack_future: V) -> &mut Self
+  where
+    V: Into<RspackFutureOptionsBuilder>,
+  {
+    self.rspack_future = Some(rspack_future.into());
+    self
+  }
+
+  /// Set whether to enable future defaults.
+  pub fn future_defaults(&mut self, future_defaults: bool) -> &mut Self {
+    self.future_defaults = Some(future_defaults);
+    self
+  }
+
+  /// Set whether to enable css.
+  pub fn css(&mut self, css: bool) -> &mut Self {
+    self.css = Some(css);
+    self
+  }
+
+  /// Set whether to enable async web assembly.
+  pub fn async_web_assembly(&mut self, async_web_assembly: bool) -> &mut Self {
+    self.async_web_assembly = Some(async_web_assembly);
+    self
+  }
+
+  /// Set whether to enable lazy barrel.
+  ///
+  /// When enabled, named re-exports of side-effect-free modules are only built
+  /// once an import references them.
+  pub fn lazy_barrel(&mut self, lazy_barrel: bool) -> &mut Self {
+    self.lazy_barrel = Some(lazy_barrel);
+    self
+  }
+
+  /// Set whether to enable inline const.
+  ///
+  /// When enabled, imports of `export const` declared with a primitive literal
+  /// are replaced by the value itself, so minifiers can eliminate dead branches.
+  pub fn inline_const(&mut self, inline_const: bool) -> &mut Self {
+    self.inline_const = Some(inline_const);
+    self
+  }
+
+  /// Set whether to enable parallel code splitting.
+  pub fn parallel_code_splitting(&mut self, parallel_code_splitting: bool) -> &mut Self {
+    self.parallel_code_splitting = Some(parallel_code_splitting);
+    self
+  }
+
+  /// Set the lazy compilation options.
+  ///
+  /// Lazy compilation is disabled by default.
+  pub fn lazy_compilation<V>(&mut self, lazy_compilation: V) -> &mut Self
+  where
+    V: Into<LazyCompilationOptionsBuilder>,
+  {
+    self.lazy_compilation = Some(lazy_compilation.into());
+    self
+  }
+
+  /// Build [`Experiments`] from options.
+  ///
+  /// [`Experiments`]: rspack_core::options::Experiments
+  #[must_use]
+  fn build(
+    &mut self,
+    builder_context: &mut BuilderContext,
+    development: bool,
+    production: bool,
+  ) -> Experiments {
+    let layers = d!(self.layers, false);
+    let incremental = f!(self.incremental.take(), || {
+      if !production {
+        IncrementalPasses::MAKE | IncrementalPasses::EMIT_ASSETS
+      } else {
+        IncrementalPasses::empty()
+      }
+    });
+    let top_level_await = d!(self.top_level_await, true);
+    let cache = f!(self.cache.take(), || {
+      if development {
+        ExperimentCacheOptions::Memory
+      } else {
+        ExperimentCacheOptions::Disabled
+      }
+    });
+    // the bundler info is applied after the output defaults
+    let rspack_future = RspackFuture {};
+
+    // Builder specific
+    let future_defaults = w!(self.future_defaults, false);
+    w!(self.css, *future_defaults);
+    w!(self.async_web_assembly, *future_defaults);
+    w!(self.output_module, false);
 
     let parallel_code_splitting = d!(self.parallel_code_splitting, false);
+    let lazy_barrel = d!(self.lazy_barrel, false);
+    let inline_const = d!(self.inline_const, false);
+
+    if let Some(mut lazy_compilation) = self.lazy_compilation.take() {
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::LazyCompilationPlugin(
+          lazy_compilation.build(),
+        ));
+    }
 
     Experiments {
       layers,
       incremental,
       top_level_await,
+      lazy_barrel,
+      inline_const,
       rspack_future,
       parallel_code_splitting,
       cache,
@@ -3798,6 +5181,73 @@ mod test {
     assert!(!plugins.is_empty());
   }
 
+  #[test]
+  fn performance_and_lazy_compilation() {
+    #[derive(Debug)]
+    struct NoopBackend;
+
+    #[async_trait::async_trait]
+    impl Backend for NoopBackend {
+      async fn module(
+        &mut self,
+        _original_module: rspack_core::ModuleIdentifier,
+        _path: String,
+      ) -> rspack_error::Result<rspack_plugin_lazy_compilation::backend::ModuleInfo> {
+        unreachable!()
+      }
+    }
+
+    type BuiltinPluginOptionsTag = <BuiltinPluginOptions as EnumTag>::Tag;
+    let has_plugin = |context: &BuilderContext, tag: BuiltinPluginOptionsTag| {
+      context.plugins.iter().any(|p| p.tag() == tag)
+    };
+
+    let mut context: BuilderContext = Default::default();
+    let _ = CompilerOptions::builder()
+      .mode(Mode::Production)
+      .target(vec!["web".to_string()])
+      .build(&mut context);
+    assert!(has_plugin(
+      &context,
+      BuiltinPluginOptionsTag::SizeLimitsPlugin
+    ));
+    assert!(!has_plugin(
+      &context,
+      BuiltinPluginOptionsTag::BundlerInfoRspackPlugin
+    ));
+    assert!(!has_plugin(
+      &context,
+      BuiltinPluginOptionsTag::LazyCompilationPlugin
+    ));
+
+    let mut context: BuilderContext = Default::default();
+    let _ = CompilerOptions::builder()
+      .mode(Mode::Development)
+      .target(vec!["web".to_string()])
+      .disable_performance()
+      .experiments(
+        Experiments::builder()
+          .lazy_compilation(LazyCompilationOptionsBuilder::new(NoopBackend))
+          .rspack_future(
+            RspackFutureOptionsBuilder::default()
+              .bundler_info(BundlerInfoOptionsBuilder::default()),
+          ),
+      )
+      .build(&mut context);
+    assert!(!has_plugin(
+      &context,
+      BuiltinPluginOptionsTag::SizeLimitsPlugin
+    ));
+    assert!(has_plugin(
+      &context,
+      BuiltinPluginOptionsTag::LazyCompilationPlugin
+    ));
+    assert!(has_plugin(
+      &context,
+      BuiltinPluginOptionsTag::BundlerInfoRspackPlugin
+    ));
+  }
+
   #[test]
   fn mutable_builder_into_owned_builder() {
     let _ = CompilerOptions::builder()
@@ -0,0 +1,1364 @@
+//! Declarative compiler configuration.
+//!
+//! A [`Config`] is loaded from a JSON or TOML document and converted into a
+//! [`CompilerOptionsBuilder`] or a [`CompilerBuilder`]. Keys are written in
+//! camelCase, the same way as in `rspack.config.js`:
+//!
+//! ```toml
+//! mode = "production"
+//! entry = "./src/index.js"
+//!
+//! [output]
+//! filename = "[name].[contenthash].js"
+//!
+//! [[module.rules]]
+//! test = { regex = "\\.jsx?$" }
+//! loader = "builtin:swc-loader"
+//! options = { jsc = { parser = { syntax = "ecmascript", jsx = true } } }
+//! ```
+//!
+//! Regular expressions are written as `{ regex = "...", flags = "..." }`, plain
+//! strings in rule conditions match path prefixes like they do in webpack.
+//!
+//! Every validation error points to the offending key, e.g.
+//! `Invalid configuration at "module.rules[0].use[1].loader": ...`.
+
+use std::str::FromStr;
+
+use rspack_core::{
+  AliasMap, CleanOptions, CrossOriginLoading, EntryDescription, LibraryName, LibraryNonUmdObject,
+  LibraryOptions, MangleExportsOption, Mode, ModuleOptions, ModuleRule, ModuleRuleEffect,
+  ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, OutputOptions, PublicPath,
+  Resolve, RuleSetCondition, RuleSetLogicalConditions, SideEffectOption, UsedExportsOption,
+};
+use rspack_error::{error, Error, Result};
+use rspack_hash::{HashDigest, HashFunction, HashSalt};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_plugin_runtime::BundlerInfoForceMode;
+use rspack_plugin_runtime_chunk::{RuntimeChunkName, RuntimeChunkOptions};
+use rspack_regex::RspackRegex;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::builder::{
+  Builder as _, BundlerInfoOptionsBuilder, CacheGroupOptionsBuilder, CompilerBuilder,
+  CompilerOptionsBuilder, Devtool, ExperimentsBuilder, ModuleOptionsBuilder,
+  OptimizationOptionsBuilder, OutputOptionsBuilder, PerformanceHints, PerformanceOptionsBuilder,
+  RspackFutureOptionsBuilder, SplitChunksChunks, SplitChunksOptionsBuilder,
+};
+
+const SWC_LOADER: &str = "builtin:swc-loader";
+const LIGHTNINGCSS_LOADER: &str = "builtin:lightningcss-loader";
+const REACT_REFRESH_LOADER: &str = "builtin:react-refresh-loader";
+const PREACT_REFRESH_LOADER: &str = "builtin:preact-refresh-loader";
+
+/// Builtin loaders and whether they are compiled into this build.
+const BUILTIN_LOADERS: &[(&str, bool)] = &[
+  (SWC_LOADER, cfg!(feature = "loader_swc")),
+  (LIGHTNINGCSS_LOADER, cfg!(feature = "loader_lightningcss")),
+  (REACT_REFRESH_LOADER, cfg!(feature = "loader_react_refresh")),
+  (
+    PREACT_REFRESH_LOADER,
+    cfg!(feature = "loader_preact_refresh"),
+  ),
+];
+
+/// A compiler configuration loaded from a JSON or TOML document.
+///
+/// Use [`TryFrom`] to convert it into a [`CompilerOptionsBuilder`], or into a
+/// [`CompilerBuilder`] which also enables the builtin loaders used by the module rules.
+#[derive(Debug, Clone)]
+pub struct Config {
+  value: Value,
+  base: Option<Utf8PathBuf>,
+}
+
+impl Config {
+  /// Create a config from a parsed JSON value.
+  pub fn from_value(value: Value) -> Self {
+    Self { value, base: None }
+  }
+
+  /// Parse a config from a JSON string.
+  pub fn from_json_str(source: &str) -> Result<Self> {
+    let value = serde_json::from_str(source)
+      .map_err(|e| error!("Failed to parse configuration as JSON: {e}"))?;
+    Ok(Self::from_value(value))
+  }
+
+  /// Parse a config from a TOML string.
+  pub fn from_toml_str(source: &str) -> Result<Self> {
+    let value =
+      toml::from_str(source).map_err(|e| error!("Failed to parse configuration as TOML: {e}"))?;
+    Ok(Self::from_value(value))
+  }
+
+  /// Load a config from a `.json` or `.toml` file.
+  ///
+  /// A relative `path` is resolved against the current working directory. A relative
+  /// `context` is resolved against the directory of the file, and the directory itself
+  /// is used when `context` is not set.
+  pub fn from_path<P>(path: P) -> Result<Self>
+  where
+    P: AsRef<Utf8Path>,
+  {
+    let path = path.as_ref();
+    let path = std::path::absolute(path)
+      .ok()
+      .and_then(|path| Utf8PathBuf::from_path_buf(path).ok())
+      .ok_or_else(|| error!("Failed to resolve configuration file {path}"))?;
+    let source = std::fs::read_to_string(&path)
+      .map_err(|e| error!("Failed to read configuration file {path}: {e}"))?;
+    let mut config = match path.extension() {
+      Some("json") => Self::from_json_str(&source)?,
+      Some("toml") => Self::from_toml_str(&source)?,
+      _ => {
+        return Err(error!(
+          "Unsupported configuration file {path}, expected a `.json` or `.toml` file"
+        ))
+      }
+    };
+    config.base = path.parent().map(Utf8Path::to_path_buf);
+    Ok(config)
+  }
+
+  fn parse(&self) -> Result<(CompilerOptionsBuilder, Vec<&'static str>)> {
+    let config = Object::new(&self.value, String::new(), CONFIG_KEYS)?;
+    let mut builder = CompilerOptionsBuilder::default();
+    let mut loaders = vec![];
+
+    if let Some(name) = config.get::<String>("name")? {
+      builder.name(name);
+    }
+    let context = config
+      .get::<String>("context")?
+      .map(|context| resolve_path(self.base.as_deref(), context))
+      .or_else(|| self.base.clone());
+    if let Some(context) = &context {
+      builder.context(context.as_str());
+    }
+    if let Some((mode, path)) = config.get_with_path::<String>("mode")? {
+      builder.mode(match mode.as_str() {
+        "development" => Mode::Development,
+        "production" => Mode::Production,
+        "none" => Mode::None,
+        _ => {
+          return Err(invalid(
+            &path,
+            format!(
+              "unknown mode \"{mode}\", expected \"development\", \"production\" or \"none\""
+            ),
+          ))
+        }
+      });
+    }
+    if let Some(target) = config.get::<OneOrMany<String>>("target")? {
+      builder.target(target.into_vec());
+    }
+    if let Some(value) = config.value("entry") {
+      parse_entry(&mut builder, value)?;
+    }
+    if let Some((devtool, path)) = config.get_with_path::<BoolOr<String>>("devtool")? {
+      builder.devtool(match devtool {
+        BoolOr::Bool(false) => Devtool::False,
+        BoolOr::Bool(true) => return Err(invalid(&path, "expected a string or `false`")),
+        BoolOr::Value(devtool) => Devtool::from_str(&devtool).map_err(|e| invalid(&path, e))?,
+      });
+    }
+    if let Some(bail) = config.get("bail")? {
+      builder.bail(bail);
+    }
+    if let Some(profile) = config.get("profile")? {
+      builder.profile(profile);
+    }
+    if let Some(path) = config.get::<String>("recordsPath")? {
+      builder.records_path(resolve_path(context.as_deref(), path));
+    }
+    if let Some(path) = config.get::<String>("recordsInputPath")? {
+      builder.records_input_path(resolve_path(context.as_deref(), path));
+    }
+    if let Some(path) = config.get::<String>("recordsOutputPath")? {
+      builder.records_output_path(resolve_path(context.as_deref(), path));
+    }
+    if let Some(value) = config.value("output") {
+      builder.output(parse_output(value, context.as_deref())?);
+    }
+    if let Some(value) = config.value("module") {
+      builder.module(parse_module(value, &mut loaders)?);
+    }
+    if let Some(value) = config.value("resolve") {
+      builder.resolve(parse_resolve(value)?);
+    }
+    if let Some(value) = config.value("resolveLoader") {
+      builder.resolve_loader(parse_resolve(value)?);
+    }
+    if let Some(value) = config.value("optimization") {
+      builder.optimization(parse_optimization(value)?);
+    }
+    if let Some(value) = config.value("experiments") {
+      builder.experiments(parse_experiments(value)?);
+    }
+    if let Some(value) = config.value("performance") {
+      match parse_performance(value)? {
+        Some(performance) => builder.performance(performance),
+        None => builder.disable_performance(),
+      };
+    }
+
+    loaders.sort_unstable();
+    loaders.dedup();
+    Ok((builder, loaders))
+  }
+}
+
+impl TryFrom<&Config> for CompilerOptionsBuilder {
+  type Error = Error;
+
+  fn try_from(config: &Config) -> Result<Self> {
+    config.parse().map(|(builder, _)| builder)
+  }
+}
+
+impl TryFrom<Config> for CompilerOptionsBuilder {
+  type Error = Error;
+
+  fn try_from(config: Config) -> Result<Self> {
+    Self::try_from(&config)
+  }
+}
+
+impl TryFrom<&Config> for CompilerBuilder {
+  type Error = Error;
+
+  fn try_from(config: &Config) -> Result<Self> {
+    let (options, loaders) = config.parse()?;
+    let mut builder = CompilerBuilder::with_options(options);
+    for loader in loaders {
+      enable_loader(&mut builder, loader);
+    }
+    Ok(builder)
+  }
+}
+
+impl TryFrom<Config> for CompilerBuilder {
+  type Error = Error;
+
+  fn try_from(config: Config) -> Result<Self> {
+    Self::try_from(&config)
+  }
+}
+
+#[allow(unused_variables)]
+fn enable_loader(builder: &mut CompilerBuilder, loader: &str) {
+  match loader {
+    #[cfg(feature = "loader_swc")]
+    SWC_LOADER => {
+      builder.enable_loader_swc();
+    }
+    #[cfg(feature = "loader_lightningcss")]
+    LIGHTNINGCSS_LOADER => {
+      builder.enable_loader_lightningcss();
+    }
+    #[cfg(feature = "loader_react_refresh")]
+    REACT_REFRESH_LOADER => {
+      builder.enable_loader_react_refresh();
+    }
+    #[cfg(feature = "loader_preact_refresh")]
+    PREACT_REFRESH_LOADER => {
+      builder.enable_loader_preact_refresh();
+    }
+    _ => {}
+  }
+}
+
+const CONFIG_KEYS: &[&str] = &[
+  "name",
+  "context",
+  "mode",
+  "target",
+  "entry",
+  "devtool",
+  "bail",
+  "profile",
+  "recordsPath",
+  "recordsInputPath",
+  "recordsOutputPath",
+  "output",
+  "module",
+  "resolve",
+  "resolveLoader",
+  "optimization",
+  "experiments",
+  "performance",
+];
+
+fn invalid(path: &str, message: impl std::fmt::Display) -> Error {
+  error!("Invalid configuration at \"{path}\": {message}")
+}
+
+fn join(path: &str, key: &str) -> String {
+  if path.is_empty() {
+    key.to_string()
+  } else {
+    format!("{path}.{key}")
+  }
+}
+
+fn index(path: &str, index: usize) -> String {
+  format!("{path}[{index}]")
+}
+
+fn deserialize<T: DeserializeOwned>(value: &Value, path: &str) -> Result<T> {
+  T::deserialize(value).map_err(|e| invalid(path, e))
+}
+
+/// A JSON object whose keys have been validated.
+struct Object<'a> {
+  map: &'a Map<String, Value>,
+  path: String,
+}
+
+impl<'a> Object<'a> {
+  fn new(value: &'a Value, path: String, keys: &[&str]) -> Result<Self> {
+    let Value::Object(map) = value else {
+      return Err(invalid(&path, "expected an object"));
+    };
+    if let Some(key) = map.keys().find(|key| !keys.contains(&key.as_str())) {
+      return Err(invalid(
+        &join(&path, key),
+        format!("unknown key, expected one of {}", keys.join(", ")),
+      ));
+    }
+    Ok(Self { map, path })
+  }
+
+  fn path(&self, key: &str) -> String {
+    join(&self.path, key)
+  }
+
+  fn value(&self, key: &str) -> Option<(&'a Value, String)> {
+    self.map.get(key).map(|value| (value, self.path(key)))
+  }
+
+  fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+    Ok(self.get_with_path(key)?.map(|(value, _)| value))
+  }
+
+  fn get_with_path<T: DeserializeOwned>(&self, key: &str) -> Result<Option<(T, String)>> {
+    self
+      .value(key)
+      .map(|(value, path)| Ok((deserialize(value, &path)?, path)))
+      .transpose()
+  }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+  One(T),
+  Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+  fn into_vec(self) -> Vec<T> {
+    match self {
+      Self::One(value) => vec![value],
+      Self::Many(values) => values,
+    }
+  }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum BoolOr<T> {
+  Bool(bool),
+  Value(T),
+}
+
+fn parse_entry(
+  builder: &mut CompilerOptionsBuilder,
+  (value, path): (&Value, String),
+) -> Result<()> {
+  if let Value::Object(map) = value {
+    for (name, value) in map {
+      builder.entry(name, parse_entry_description(value, join(&path, name))?);
+    }
+    return Ok(());
+  }
+  let import = deserialize::<OneOrMany<String>>(value, &path)?;
+  builder.entry(
+    "main",
+    EntryDescription {
+      import: Some(import.into_vec()),
+      ..Default::default()
+    },
+  );
+  Ok(())
+}
+
+fn parse_entry_description(value: &Value, path: String) -> Result<EntryDescription> {
+  if !value.is_object() {
+    return Ok(EntryDescription {
+      import: Some(deserialize::<OneOrMany<String>>(value, &path)?.into_vec()),
+      ..Default::default()
+    });
+  }
+  let entry = Object::new(
+    value,
+    path,
+    &[
+      "import",
+      "runtime",
+      "dependOn",
+      "filename",
+      "publicPath",
+      "asyncChunks",
+      "baseUri",
+      "library",
+    ],
+  )?;
+  Ok(EntryDescription {
+    import: entry
+      .get::<OneOrMany<String>>("import")?
+      .map(OneOrMany::into_vec),
+    runtime: entry.get("runtime")?,
+    depend_on: entry
+      .get::<OneOrMany<String>>("dependOn")?
+      .map(OneOrMany::into_vec),
+    filename: entry.get::<String>("filename")?.map(Into::into),
+    public_path: entry.get::<String>("publicPath")?.map(PublicPath::from),
+    async_chunks: entry.get("asyncChunks")?,
+    base_uri: entry.get("baseUri")?,
+    library: entry.value("library").map(parse_library).transpose()?,
+    ..Default::default()
+  })
+}
+
+fn parse_library((value, path): (&Value, String)) -> Result<LibraryOptions> {
+  if let Value::String(library_type) = value {
+    return Ok(LibraryOptions {
+      library_type: library_type.clone(),
+      name: None,
+      export: None,
+      umd_named_define: None,
+      auxiliary_comment: None,
+      amd_container: None,
+    });
+  }
+  let library = Object::new(value, path, &["type", "name", "export", "umdNamedDefine"])?;
+  let Some(library_type) = library.get::<String>("type")? else {
+    return Err(invalid(&library.path("type"), "missing library type"));
+  };
+  Ok(LibraryOptions {
+    library_type,
+    name: library
+      .get::<OneOrMany<String>>("name")?
+      .map(|name| match name {
+        OneOrMany::One(name) => LibraryNonUmdObject::String(name),
+        OneOrMany::Many(names) => LibraryNonUmdObject::Array(names),
+      })
+      .map(LibraryName::NonUmdObject),
+    export: library
+      .get::<OneOrMany<String>>("export")?
+      .map(OneOrMany::into_vec),
+    umd_named_define: library.get("umdNamedDefine")?,
+    auxiliary_comment: None,
+    amd_container: None,
+  })
+}
+
+/// Resolve a relative path against `base`, if any.
+fn resolve_path(base: Option<&Utf8Path>, path: String) -> Utf8PathBuf {
+  let path = Utf8PathBuf::from(path);
+  match base {
+    Some(base) if path.is_relative() => base.join(path),
+    _ => path,
+  }
+}
+
+fn parse_output(
+  (value, path): (&Value, String),
+  context: Option<&Utf8Path>,
+) -> Result<OutputOptionsBuilder> {
+  let output = Object::new(
+    value,
+    path,
+    &[
+      "path",
+      "clean",
+      "publicPath",
+      "filename",
+      "chunkFilename",
+      "cssFilename",
+      "cssChunkFilename",
+      "assetModuleFilename",
+      "uniqueName",
+      "library",
+      "globalObject",
+      "iife",
+      "module",
+      "chunkFormat",
+      "chunkLoadingGlobal",
+      "chunkLoadTimeout",
+      "charset",
+      "crossOriginLoading",
+      "strictModuleErrorHandling",
+      "importFunctionName",
+      "scriptType",
+      "asyncChunks",
+      "hashFunction",
+      "hashDigest",
+      "hashDigestLength",
+      "hashSalt",
+      "compareBeforeEmit",
+    ],
+  )?;
+  let mut builder = OutputOptions::builder();
+
+  if let Some(path) = output.get::<String>("path")? {
+    builder.path(resolve_path(context, path));
+  }
+  if let Some(clean) = output.value("clean") {
+    builder.clean(match clean.0 {
+      Value::Bool(clean) => CleanOptions::CleanAll(*clean),
+      _ => {
+        let clean = Object::new(clean.0, clean.1, &["keep"])?;
+        match clean.get::<String>("keep")? {
+          Some(keep) => CleanOptions::from(keep),
+          None => CleanOptions::CleanAll(true),
+        }
+      }
+    });
+  }
+  if let Some(public_path) = output.get::<String>("publicPath")? {
+    builder.public_path(PublicPath::from(public_path));
+  }
+  if let Some(filename) = output.get::<String>("filename")? {
+    builder.filename(filename.into());
+  }
+  if let Some(filename) = output.get::<String>("chunkFilename")? {
+    builder.chunk_filename(filename.into());
+  }
+  if let Some(filename) = output.get::<String>("cssFilename")? {
+    builder.css_filename(filename.into());
+  }
+  if let Some(filename) = output.get::<String>("cssChunkFilename")? {
+    builder.css_chunk_filename(filename.into());
+  }
+  if let Some(filename) = output.get::<String>("assetModuleFilename")? {
+    builder.asset_module_filename(filename.into());
+  }
+  if let Some(name) = output.get("uniqueName")? {
+    builder.unique_name(name);
+  }
+  if let Some(library) = output.value("library") {
+    builder.library(parse_library(library)?);
+  }
+  if let Some(global_object) = output.get("globalObject")? {
+    builder.global_object(global_object);
+  }
+  if let Some(iife) = output.get("iife")? {
+    builder.iife(iife);
+  }
+  if let Some(module) = output.get("module")? {
+    builder.module(module);
+  }
+  if let Some(chunk_format) = output.get("chunkFormat")? {
+    builder.chunk_format(chunk_format);
+  }
+  if let Some(global) = output.get("chunkLoadingGlobal")? {
+    builder.chunk_loading_global(global);
+  }
+  if let Some(timeout) = output.get("chunkLoadTimeout")? {
+    builder.chunk_load_timeout(timeout);
+  }
+  if let Some(charset) = output.get("charset")? {
+    builder.charset(charset);
+  }
+  if let Some((loading, path)) = output.get_with_path::<BoolOr<String>>("crossOriginLoading")? {
+    builder.cross_origin_loading(match loading {
+      BoolOr::Bool(false) => CrossOriginLoading::Disable,
+      BoolOr::Bool(true) => return Err(invalid(&path, "expected a string or `false`")),
+      BoolOr::Value(loading) => CrossOriginLoading::Enable(loading),
+    });
+  }
+  if let Some(strict) = output.get("strictModuleErrorHandling")? {
+    builder.strict_module_error_handling(strict);
+  }
+  if let Some(name) = output.get("importFunctionName")? {
+    builder.import_function_name(name);
+  }
+  if let Some(script_type) = output.get("scriptType")? {
+    builder.script_type(script_type);
+  }
+  if let Some(async_chunks) = output.get("asyncChunks")? {
+    builder.async_chunks(async_chunks);
+  }
+  if let Some((function, path)) = output.get_with_path::<String>("hashFunction")? {
+    builder
+      .hash_function(HashFunction::try_from(function.as_str()).map_err(|e| invalid(&path, e))?);
+  }
+  if let Some((digest, path)) = output.get_with_path::<String>("hashDigest")? {
+    builder.hash_digest(HashDigest::try_from(digest.as_str()).map_err(|e| invalid(&path, e))?);
+  }
+  if let Some(length) = output.get("hashDigestLength")? {
+    builder.hash_digest_length(length);
+  }
+  if let Some(salt) = output.get("hashSalt")? {
+    builder.hash_salt(HashSalt::Salt(salt));
+  }
+  if let Some(compare) = output.get("compareBeforeEmit")? {
+    builder.compare_before_emit(compare);
+  }
+
+  Ok(builder)
+}
+
+fn parse_module(
+  (value, path): (&Value, String),
+  loaders: &mut Vec<&'static str>,
+) -> Result<ModuleOptionsBuilder> {
+  let module = Object::new(value, path, &["rules"])?;
+  let mut builder = ModuleOptions::builder();
+  if let Some(rules) = module.value("rules") {
+    builder.rules(parse_rules(rules, loaders)?);
+  }
+  Ok(builder)
+}
+
+fn parse_rules(
+  (value, path): (&Value, String),
+  loaders: &mut Vec<&'static str>,
+) -> Result<Vec<ModuleRule>> {
+  let Value::Array(rules) = value else {
+    return Err(invalid(&path, "expected an array of rules"));
+  };
+  rules
+    .iter()
+    .enumerate()
+    .map(|(i, rule)| parse_rule((rule, index(&path, i)), loaders))
+    .collect()
+}
+
+fn parse_rule(
+  (value, path): (&Value, String),
+  loaders: &mut Vec<&'static str>,
+) -> Result<ModuleRule> {
+  let rule = Object::new(
+    value,
+    path,
+    &[
+      "test",
+      "include",
+      "exclude",
+      "resource",
+      "type",
+      "layer",
+      "sideEffects",
+      "enforce",
+      "loader",
+      "options",
+      "use",
+      "oneOf",
+      "rules",
+    ],
+  )?;
+  let condition = |key| rule.value(key).map(parse_condition).transpose();
+
+  let r#type = rule
+    .get_with_path::<String>("type")?
+    .map(|(r#type, path)| match ModuleType::from(r#type.as_str()) {
+      ModuleType::Custom(_) => Err(invalid(&path, format!("unknown module type \"{type}\""))),
+      module_type => Ok(module_type),
+    })
+    .transpose()?;
+  let enforce = match rule.get_with_path::<String>("enforce")? {
+    None => ModuleRuleEnforce::Normal,
+    Some((enforce, _)) if enforce == "pre" => ModuleRuleEnforce::Pre,
+    Some((enforce, _)) if enforce == "post" => ModuleRuleEnforce::Post,
+    Some((enforce, path)) => {
+      return Err(invalid(
+        &path,
+        format!("unknown enforce \"{enforce}\", expected \"pre\" or \"post\""),
+      ))
+    }
+  };
+
+  let mut r#use = vec![];
+  if let Some(loader) = rule.value("loader") {
+    r#use.push(parse_loader(loader, rule.value("options"), loaders)?);
+  } else if let Some((_, path)) = rule.value("options") {
+    return Err(invalid(
+      &path,
+      "`options` can only be used together with `loader`",
+    ));
+  }
+  if let Some((value, path)) = rule.value("use") {
+    if !r#use.is_empty() {
+      return Err(invalid(
+        &path,
+        "`use` can not be used together with `loader`",
+      ));
+    }
+    let items = match value {
+      Value::Array(items) => items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| (item, index(&path, i)))
+        .collect(),
+      _ => vec![(value, path)],
+    };
+    for item in items {
+      r#use.push(parse_use_item(item, loaders)?);
+    }
+  }
+
+  Ok(ModuleRule {
+    test: condition("test")?,
+    include: condition("include")?,
+    exclude: condition("exclude")?,
+    resource: condition("resource")?,
+    one_of: rule
+      .value("oneOf")
+      .map(|rules| parse_rules(rules, loaders))
+      .transpose()?,
+    rules: rule
+      .value("rules")
+      .map(|rules| parse_rules(rules, loaders))
+      .transpose()?,
+    effect: ModuleRuleEffect {
+      side_effects: rule.get("sideEffects")?,
+      r#type,
+      layer: rule.get("layer")?,
+      r#use: ModuleRuleUse::Array(r#use),
+      enforce,
+      ..Default::default()
+    },
+    ..Default::default()
+  })
+}
+
+fn parse_use_item(
+  (value, path): (&Value, String),
+  loaders: &mut Vec<&'static str>,
+) -> Result<ModuleRuleUseLoader> {
+  if value.is_string() {
+    return parse_loader((value, path), None, loaders);
+  }
+  let item = Object::new(value, path, &["loader", "options"])?;
+  let Some(loader) = item.value("loader") else {
+    return Err(invalid(&item.path("loader"), "missing loader"));
+  };
+  parse_loader(loader, item.value("options"), loaders)
+}
+
+fn parse_loader(
+  (value, path): (&Value, String),
+  options: Option<(&Value, String)>,
+  loaders: &mut Vec<&'static str>,
+) -> Result<ModuleRuleUseLoader> {
+  let loader = deserialize::<String>(value, &path)?;
+  let name = loader
+    .split_once('?')
+    .map_or(loader.as_str(), |(name, _)| name);
+  match BUILTIN_LOADERS.iter().find(|(builtin, _)| *builtin == name) {
+    Some((builtin, true)) => loaders.push(builtin),
+    Some((builtin, false)) => {
+      return Err(invalid(
+        &path,
+        format!("loader \"{builtin}\" is not enabled in this build"),
+      ))
+    }
+    None => {
+      return Err(invalid(
+        &path,
+        format!(
+          "unknown loader \"{loader}\", only builtin loaders are supported: {}",
+          BUILTIN_LOADERS
+            .iter()
+            .map(|(builtin, _)| *builtin)
+            .collect::<Vec<_>>()
+            .join(", ")
+        ),
+      ))
+    }
+  }
+  let options = match options {
+    None => None,
+    Some((Value::String(options), _)) => Some(options.clone()),
+    Some((options @ Value::Object(_), _)) => Some(options.to_string()),
+    Some((_, path)) => return Err(invalid(&path, "expected a string or an object")),
+  };
+  Ok(ModuleRuleUseLoader { loader, options })
+}
+
+fn parse_regex((value, path): (&Value, String)) -> Result<RspackRegex> {
+  let regex = Object::new(value, path, &["regex", "flags"])?;
+  let Some((source, path)) = regex.get_with_path::<String>("regex")? else {
+    return Err(invalid(&regex.path("regex"), "missing regex source"));
+  };
+  let flags = regex.get::<String>("flags")?.unwrap_or_default();
+  RspackRegex::with_flags(&source, &flags).map_err(|e| invalid(&path, e))
+}
+
+fn parse_condition((value, path): (&Value, String)) -> Result<RuleSetCondition> {
+  match value {
+    Value::String(prefix) => Ok(RuleSetCondition::String(prefix.clone())),
+    Value::Array(_) => Ok(RuleSetCondition::Array(parse_conditions((value, path))?)),
+    Value::Object(map) if map.contains_key("regex") => {
+      Ok(RuleSetCondition::Regexp(parse_regex((value, path))?))
+    }
+    Value::Object(_) => {
+      let logical = Object::new(value, path, &["and", "or", "not"])?;
+      Ok(RuleSetCondition::Logical(Box::new(
+        RuleSetLogicalConditions {
+          and: logical.value("and").map(parse_conditions).transpose()?,
+          or: logical.value("or").map(parse_conditions).transpose()?,
+          not: logical.value("not").map(parse_condition).transpose()?,
+        },
+      )))
+    }
+    _ => Err(invalid(
+      &path,
+      "expected a string, a regex object, a logical condition or an array of conditions",
+    )),
+  }
+}
+
+fn parse_conditions((value, path): (&Value, String)) -> Result<Vec<RuleSetCondition>> {
+  let Value::Array(conditions) = value else {
+    return Err(invalid(&path, "expected an array of conditions"));
+  };
+  conditions
+    .iter()
+    .enumerate()
+    .map(|(i, condition)| parse_condition((condition, index(&path, i))))
+    .collect()
+}
+
+fn parse_alias((value, path): (&Value, String)) -> Result<Vec<(String, Vec<AliasMap>)>> {
+  let Value::Object(map) = value else {
+    return Err(invalid(&path, "expected an object"));
+  };
+  map
+    .iter()
+    .map(|(request, value)| {
+      let path = join(&path, request);
+      let values = deserialize::<OneOrMany<BoolOr<String>>>(value, &path)?
+        .into_vec()
+        .into_iter()
+        .map(|value| match value {
+          BoolOr::Bool(false) => Ok(AliasMap::Ignore),
+          BoolOr::Bool(true) => Err(invalid(&path, "expected a string or `false`")),
+          BoolOr::Value(value) => Ok(AliasMap::Path(value)),
+        })
+        .collect::<Result<_>>()?;
+      Ok((request.clone(), values))
+    })
+    .collect()
+}
+
+fn parse_fields(fields: Option<Vec<OneOrMany<String>>>) -> Option<Vec<Vec<String>>> {
+  fields.map(|fields| fields.into_iter().map(OneOrMany::into_vec).collect())
+}
+
+fn parse_resolve((value, path): (&Value, String)) -> Result<Resolve> {
+  let resolve = Object::new(
+    value,
+    path,
+    &[
+      "extensions",
+      "alias",
+      "fallback",
+      "preferRelative",
+      "preferAbsolute",
+      "symlinks",
+      "mainFiles",
+      "mainFields",
+      "conditionNames",
+      "modules",
+      "fullySpecified",
+      "exportsFields",
+      "importsFields",
+      "aliasFields",
+      "descriptionFiles",
+      "enforceExtension",
+      "extensionAlias",
+      "roots",
+    ],
+  )?;
+  Ok(Resolve {
+    extensions: resolve.get("extensions")?,
+    alias: resolve.value("alias").map(parse_alias).transpose()?,
+    fallback: resolve.value("fallback").map(parse_alias).transpose()?,
+    prefer_relative: resolve.get("preferRelative")?,
+    prefer_absolute: resolve.get("preferAbsolute")?,
+    symlinks: resolve.get("symlinks")?,
+    main_files: resolve.get("mainFiles")?,
+    main_fields: resolve.get("mainFields")?,
+    condition_names: resolve.get("conditionNames")?,
+    modules: resolve.get("modules")?,
+    fully_specified: resolve.get("fullySpecified")?,
+    exports_fields: parse_fields(resolve.get("exportsFields")?),
+    imports_fields: parse_fields(resolve.get("importsFields")?),
+    alias_fields: parse_fields(resolve.get("aliasFields")?),
+    description_files: resolve.get("descriptionFiles")?,
+    enforce_extension: resolve.get("enforceExtension")?,
+    extension_alias: resolve
+      .get::<indexmap::IndexMap<String, OneOrMany<String>>>("extensionAlias")?
+      .map(|alias| {
+        alias
+          .into_iter()
+          .map(|(extension, aliases)| (extension, aliases.into_vec()))
+          .collect()
+      }),
+    roots: resolve.get("roots")?,
+    ..Default::default()
+  })
+}
+
+fn parse_optimization((value, path): (&Value, String)) -> Result<OptimizationOptionsBuilder> {
+  let optimization = Object::new(
+    value,
+    path,
+    &[
+      "removeAvailableModules",
+      "removeEmptyChunks",
+      "mergeDuplicateChunks",
+      "moduleIds",
+      "hashedModuleIds",
+      "chunkIds",
+      "minimize",
+      "sideEffects",
+      "providedExports",
+      "usedExports",
+      "innerGraph",
+      "mangleExports",
+      "concatenateModules",
+      "realContentHash",
+      "avoidEntryIife",
+      "nodeEnv",
+      "emitOnErrors",
+      "runtimeChunk",
+      "splitChunks",
+    ],
+  )?;
+  let mut builder = OptimizationOptionsBuilder::default();
+
+  if let Some(value) = optimization.get("removeAvailableModules")? {
+    builder.remove_available_modules(value);
+  }
+  if let Some(value) = optimization.get("removeEmptyChunks")? {
+    builder.remove_empty_chunks(value);
+  }
+  if let Some(value) = optimization.get("mergeDuplicateChunks")? {
+    builder.merge_duplicate_chunks(value);
+  }
+  if let Some((module_ids, path)) = optimization.get_with_path::<String>("moduleIds")? {
+    expect_one_of(
+      &module_ids,
+      &path,
+      &["named", "natural", "deterministic", "hashed", "size"],
+    )?;
+    builder.module_ids(module_ids);
+  }
+  if let Some(hashed_module_ids) = optimization.value("hashedModuleIds") {
+    builder.hashed_module_ids(parse_hashed_module_ids(hashed_module_ids)?);
+  }
+  if let Some((chunk_ids, path)) = optimization.get_with_path::<String>("chunkIds")? {
+    expect_one_of(
+      &chunk_ids,
+      &path,
+      &["named", "natural", "deterministic", "size", "total-size"],
+    )?;
+    builder.chunk_ids(chunk_ids);
+  }
+  if let Some(value) = optimization.get("minimize")? {
+    builder.minimize(value);
+  }
+  if let Some((side_effects, path)) = optimization.get_with_path::<BoolOr<String>>("sideEffects")? {
+    builder.side_effects(match side_effects {
+      BoolOr::Bool(value) => SideEffectOption::from(value.to_string().as_str()),
+      BoolOr::Value(value) => {
+        expect_one_of(&value, &path, &["flag"])?;
+        SideEffectOption::from(value.as_str())
+      }
+    });
+  }
+  if let Some(value) = optimization.get("providedExports")? {
+    builder.provided_exports(value);
+  }
+  if let Some((used_exports, path)) = optimization.get_with_path::<BoolOr<String>>("usedExports")? {
+    builder.used_exports(match used_exports {
+      BoolOr::Bool(value) => UsedExportsOption::from(value.to_string().as_str()),
+      BoolOr::Value(value) => {
+        expect_one_of(&value, &path, &["global"])?;
+        UsedExportsOption::from(value.as_str())
+      }
+    });
+  }
+  if let Some(value) = optimization.get("innerGraph")? {
+    builder.inner_graph(value);
+  }
+  if let Some((mangle_exports, path)) =
+    optimization.get_with_path::<BoolOr<String>>("mangleExports")?
+  {
+    builder.mangle_exports(match mangle_exports {
+      BoolOr::Bool(value) => MangleExportsOption::from(value.to_string().as_str()),
+      BoolOr::Value(value) => {
+        expect_one_of(&value, &path, &["size", "deterministic"])?;
+        MangleExportsOption::from(value.as_str())
+      }
+    });
+  }
+  if let Some(value) = optimization.get("concatenateModules")? {
+    builder.concatenate_modules(value);
+  }
+  if let Some(value) = optimization.get("realContentHash")? {
+    builder.real_content_hash(value);
+  }
+  if let Some(value) = optimization.get("avoidEntryIife")? {
+    builder.avoid_entry_iife(value);
+  }
+  if let Some((node_env, path)) = optimization.get_with_path::<BoolOr<String>>("nodeEnv")? {
+    match node_env {
+      BoolOr::Bool(false) => {}
+      BoolOr::Bool(true) => return Err(invalid(&path, "expected a string or `false`")),
+      BoolOr::Value(value) => {
+        builder.node_env(value);
+      }
+    }
+  }
+  if let Some(value) = optimization.get("emitOnErrors")? {
+    builder.emit_on_errors(value);
+  }
+  if let Some((runtime_chunk, path)) = optimization.value("runtimeChunk") {
+    let name = match runtime_chunk {
+      Value::Bool(false) => None,
+      Value::Bool(true) => Some(RuntimeChunkName::Multiple),
+      Value::String(name) if name == "single" => Some(RuntimeChunkName::Single),
+      Value::String(name) if name == "multiple" => Some(RuntimeChunkName::Multiple),
+      Value::Object(_) => {
+        let runtime_chunk = Object::new(runtime_chunk, path, &["name"])?;
+        let Some(name) = runtime_chunk.get::<String>("name")? else {
+          return Err(invalid(&runtime_chunk.path("name"), "missing name"));
+        };
+        Some(RuntimeChunkName::String(name))
+      }
+      _ => {
+        return Err(invalid(
+          &path,
+          "expected a boolean, \"single\", \"multiple\" or an object with `name`",
+        ))
+      }
+    };
+    if let Some(name) = name {
+      builder.runtime_chunk(RuntimeChunkOptions { name });
+    }
+  }
+  if let Some(split_chunks) = optimization.value("splitChunks") {
+    match parse_split_chunks(split_chunks)? {
+      Some(split_chunks) => builder.split_chunks(split_chunks),
+      None => builder.disable_split_chunks(),
+    };
+  }
+
+  Ok(builder)
+}
+
+fn parse_hashed_module_ids(
+  (value, path): (&Value, String),
+) -> Result<rspack_ids::HashedModuleIdsPluginOptions> {
+  let hashed = Object::new(
+    value,
+    path,
+    &["context", "hashFunction", "hashDigest", "hashDigestLength"],
+  )?;
+  let mut options = rspack_ids::HashedModuleIdsPluginOptions {
+    context: hashed.get("context")?,
+    ..Default::default()
+  };
+  if let Some((function, path)) = hashed.get_with_path::<String>("hashFunction")? {
+    options.hash_function =
+      HashFunction::try_from(function.as_str()).map_err(|e| invalid(&path, e))?;
+  }
+  if let Some((digest, path)) = hashed.get_with_path::<String>("hashDigest")? {
+    options.hash_digest = HashDigest::try_from(digest.as_str()).map_err(|e| invalid(&path, e))?;
+  }
+  if let Some(length) = hashed.get("hashDigestLength")? {
+    options.hash_digest_length = length;
+  }
+  Ok(options)
+}
+
+fn expect_one_of(value: &str, path: &str, expected: &[&str]) -> Result<()> {
+  if expected.contains(&value) {
+    return Ok(());
+  }
+  Err(invalid(
+    path,
+    format!(
+      "unknown value \"{value}\", expected one of {}",
+      expected
+        .iter()
+        .map(|value| format!("\"{value}\""))
+        .collect::<Vec<_>>()
+        .join(", ")
+    ),
+  ))
+}
+
+fn parse_chunks((value, path): (&Value, String)) -> Result<SplitChunksChunks> {
+  if value.is_object() {
+    return parse_regex((value, path)).map(SplitChunksChunks::Regex);
+  }
+  let chunks = deserialize::<String>(value, &path)?;
+  match chunks.as_str() {
+    "async" => Ok(SplitChunksChunks::Async),
+    "initial" => Ok(SplitChunksChunks::Initial),
+    "all" => Ok(SplitChunksChunks::All),
+    _ => Err(invalid(
+      &path,
+      format!("unknown chunks \"{chunks}\", expected \"async\", \"initial\" or \"all\""),
+    )),
+  }
+}
+
+macro_rules! split_chunks_common {
+  ($object:expr, $builder:expr) => {{
+    let object = $object;
+    let builder = $builder;
+    if let Some(chunks) = object.value("chunks") {
+      builder.chunks(parse_chunks(chunks)?);
+    }
+    if let Some(name) = object.get::<String>("name")? {
+      builder.name(name);
+    }
+    if let Some(value) = object.get("minChunks")? {
+      builder.min_chunks(value);
+    }
+    if let Some(value) = object.get::<f64>("minSize")? {
+      builder.min_size(value);
+    }
+    if let Some(value) = object.get::<f64>("minSizeReduction")? {
+      builder.min_size_reduction(value);
+    }
+    if let Some(value) = object.get::<f64>("maxSize")? {
+      builder.max_size(value);
+    }
+    if let Some(value) = object.get::<f64>("maxAsyncSize")? {
+      builder.max_async_size(value);
+    }
+    if let Some(value) = object.get::<f64>("maxInitialSize")? {
+      builder.max_initial_size(value);
+    }
+    if let Some(value) = object.get("maxAsyncRequests")? {
+      builder.max_async_requests(value);
+    }
+    if let Some(value) = object.get("maxInitialRequests")? {
+      builder.max_initial_requests(value);
+    }
+    if let Some(filename) = object.get::<String>("filename")? {
+      builder.filename(filename.into());
+    }
+    if let Some(delimiter) = object.get::<String>("automaticNameDelimiter")? {
+      builder.automatic_name_delimiter(delimiter);
+    }
+    if let Some(value) = object.get("usedExports")? {
+      builder.used_exports(value);
+    }
+  }};
+}
+
+const SPLIT_CHUNKS_COMMON_KEYS: &[&str] = &[
+  "chunks",
+  "name",
+  "minChunks",
+  "minSize",
+  "minSizeReduction",
+  "maxSize",
+  "maxAsyncSize",
+  "maxInitialSize",
+  "maxAsyncRequests",
+  "maxInitialRequests",
+  "filename",
+  "automaticNameDelimiter",
+  "usedExports",
+];
+
+fn parse_split_chunks(
+  (value, path): (&Value, String),
+) -> Result<Option<SplitChunksOptionsBuilder>> {
+  if let Value::Bool(false) = value {
+    return Ok(None);
+  }
+  let keys = [SPLIT_CHUNKS_COMMON_KEYS, &["hidePathInfo", "cacheGroups"]].concat();
+  let split_chunks = Object::new(value, path, &keys)?;
+  let mut builder = SplitChunksOptionsBuilder::default();
+  split_chunks_common!(&split_chunks, &mut builder);
+  if let Some(value) = split_chunks.get("hidePathInfo")? {
+    builder.hide_path_info(value);
+  }
+  if let Some((cache_groups, path)) = split_chunks.value("cacheGroups") {
+    let Value::Object(cache_groups) = cache_groups else {
+      return Err(invalid(&path, "expected an object"));
+    };
+    for (key, cache_group) in cache_groups {
+      match parse_cache_group((cache_group, join(&path, key)))? {
+        Some(cache_group) => builder.cache_group(key, cache_group),
+        None => builder.disable_cache_group(key),
+      };
+    }
+  }
+  Ok(Some(builder))
+}
+
+fn parse_cache_group((value, path): (&Value, String)) -> Result<Option<CacheGroupOptionsBuilder>> {
+  if let Value::Bool(false) = value {
+    return Ok(None);
+  }
+  let keys = [
+    SPLIT_CHUNKS_COMMON_KEYS,
+    &[
+      "test",
+      "type",
+      "layer",
+      "priority",
+      "enforce",
+      "reuseExistingChunk",
+      "idHint",
+    ],
+  ]
+  .concat();
+  let cache_group = Object::new(value, path, &keys)?;
+  let mut builder = CacheGroupOptionsBuilder::default();
+  split_chunks_common!(&cache_group, &mut builder);
+  if let Some(test) = cache_group.value("test") {
+    builder.test(parse_regex(test)?);
+  }
+  if let Some(r#type) = cache_group.value("type") {
+    builder.r#type(parse_regex(r#type)?);
+  }
+  if let Some(layer) = cache_group.get::<String>("layer")? {
+    builder.layer(layer);
+  }
+  if let Some(value) = cache_group.get("priority")? {
+    builder.priority(value);
+  }
+  if let Some(value) = cache_group.get("enforce")? {
+    builder.enforce(value);
+  }
+  if let Some(value) = cache_group.get("reuseExistingChunk")? {
+    builder.reuse_existing_chunk(value);
+  }
+  if let Some(id_hint) = cache_group.get::<String>("idHint")? {
+    builder.id_hint(id_hint);
+  }
+  Ok(Some(builder))
+}
+
+fn parse_experiments((value, path): (&Value, String)) -> Result<ExperimentsBuilder> {
+  let experiments = Object::new(
+    value,
+    path,
+    &[
+      "layers",
+      "topLevelAwait",
+      "futureDefaults",
+      "css",
+      "asyncWebAssembly",
+      "parallelCodeSplitting",
+      "lazyBarrel",
+      "inlineConst",
+      "rspackFuture",
+    ],
+  )?;
+  let mut builder = ExperimentsBuilder::default();
+  if let Some(value) = experiments.get("layers")? {
+    builder.layers(value);
+  }
+  if let Some(value) = experiments.get("topLevelAwait")? {
+    builder.top_level_await(value);
+  }
+  if let Some(value) = experiments.get("futureDefaults")? {
+    builder.future_defaults(value);
+  }
+  if let Some(value) = experiments.get("css")? {
+    builder.css(value);
+  }
+  if let Some(value) = experiments.get("asyncWebAssembly")? {
+    builder.async_web_assembly(value);
+  }
+  if let Some(value) = experiments.get("parallelCodeSplitting")? {
+    builder.parallel_code_splitting(value);
+  }
+  if let Some(value) = experiments.get("lazyBarrel")? {
+    builder.lazy_barrel(value);
+  }
+  if let Some(value) = experiments.get("inlineConst")? {
+    builder.inline_const(value);
+  }
+  if let Some(value) = experiments.value("rspackFuture") {
+    builder.rspack_future(parse_rspack_future(value)?);
+  }
+  Ok(builder)
+}
+
+fn parse_rspack_future((value, path): (&Value, String)) -> Result<RspackFutureOptionsBuilder> {
+  let rspack_future = Object::new(value, path, &["bundlerInfo"])?;
+  let mut builder = RspackFutureOptionsBuilder::default();
+  if let Some((value, path)) = rspack_future.value("bundlerInfo") {
+    match value {
+      Value::Bool(false) => {}
+      Value::Bool(true) => {
+        builder.bundler_info(BundlerInfoOptionsBuilder::default());
+      }
+      _ => {
+        builder.bundler_info(parse_bundler_info((value, path))?);
+      }
+    }
+  }
+  Ok(builder)
+}
+
+fn parse_bundler_info((value, path): (&Value, String)) -> Result<BundlerInfoOptionsBuilder> {
+  let bundler_info = Object::new(value, path, &["version", "bundler", "force"])?;
+  let mut builder = BundlerInfoOptionsBuilder::default();
+  if let Some(version) = bundler_info.get::<String>("version")? {
+    builder.version(version);
+  }
+  if let Some(bundler) = bundler_info.get::<String>("bundler")? {
+    builder.bundler(bundler);
+  }
+  if let Some(force) = bundler_info.get::<BoolOr<Vec<String>>>("force")? {
+    builder.force(match force {
+      BoolOr::Bool(true) => BundlerInfoForceMode::All,
+      BoolOr::Bool(false) => BundlerInfoForceMode::Auto,
+      BoolOr::Value(force) => BundlerInfoForceMode::Partial(force.into_iter().collect()),
+    });
+  }
+  Ok(builder)
+}
+
+fn parse_performance((value, path): (&Value, String)) -> Result<Option<PerformanceOptionsBuilder>> {
+  if let Value::Bool(false) = value {
+    return Ok(None);
+  }
+  let performance = Object::new(value, path, &["hints", "maxAssetSize", "maxEntrypointSize"])?;
+  let mut builder = PerformanceOptionsBuilder::default();
+  if let Some((hints, path)) = performance.get_with_path::<BoolOr<String>>("hints")? {
+    builder.hints(match hints {
+      BoolOr::Bool(false) => PerformanceHints::False,
+      BoolOr::Value(hints) if hints == "warning" => PerformanceHints::Warning,
+      BoolOr::Value(hints) if hints == "error" => PerformanceHints::Error,
+      _ => return Err(invalid(&path, "expected \"warning\", \"error\" or `false`")),
+    });
+  }
+  if let Some(value) = performance.get("maxAssetSize")? {
+    builder.max_asset_size(value);
+  }
+  if let Some(value) = performance.get("maxEntrypointSize")? {
+    builder.max_entrypoint_size(value);
+  }
+  Ok(Some(builder))
+}
@@ -3,3 +3,4 @@
 //! The Rspack compiler.
 
 pub mod builder;
+pub mod config;
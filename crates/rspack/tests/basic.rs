@@ -1,6 +1,18 @@
-use rspack::builder::{Builder as _, Devtool};
-use rspack_core::Compiler;
+mod common;
+
+use std::collections::HashSet;
+
+use rspack::builder::{
+  Builder as _, CacheGroupOptionsBuilder, Devtool, SplitChunksChunks, SplitChunksOptionsBuilder,
+};
+use rspack_core::{
+  Compiler, Experiments, ModuleOptions, ModuleRule, ModuleRuleEffect, ModuleType, Optimization,
+  RuleSetCondition,
+};
 use rspack_paths::Utf8Path;
+use rspack_regex::RspackRegex;
+
+use crate::common::{copy_fixture, main_source};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn basic() {
@@ -38,3 +50,318 @@ async fn basic_sourcemap() {
   );
   assert!(compiler.compilation.assets().get("main.js.map").is_some());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn split_chunks() {
+  let mut split_chunks = SplitChunksOptionsBuilder::default();
+  split_chunks.cache_group(
+    "shared",
+    CacheGroupOptionsBuilder::default()
+      .chunks(SplitChunksChunks::All)
+      .name("shared")
+      .min_chunks(2)
+      .min_size(0.0)
+      .enforce(true),
+  );
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/split-chunks"))
+    .entry("a", "./src/a.js")
+    .entry("b", "./src/b.js")
+    .optimization(Optimization::builder().split_chunks(&mut split_chunks))
+    .build();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty());
+
+  let assets = compiler.compilation.assets();
+  assert!(assets.contains_key("a.js"));
+  assert!(assets.contains_key("b.js"));
+  assert!(assets.contains_key("shared.js"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_attributes() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/import-attributes"))
+    .entry("main", "./src/index.js")
+    .build();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty());
+
+  let source = main_source(&compiler);
+  assert!(source.contains("rspack-json"));
+  assert!(source.contains(r#""rspack text""#));
+  // base64 of "rspack bytes"
+  assert!(source.contains("cnNwYWNrIGJ5dGVz"));
+  assert!(source.contains("new CSSStyleSheet"));
+  assert!(!compiler.compilation.assets().contains_key("main.css"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_attributes_errors() {
+  let mut compiler = Compiler::builder()
+    .context(
+      Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/import-attributes-errors"),
+    )
+    .entry("main", "./src/index.js")
+    .build();
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler
+    .compilation
+    .get_errors()
+    .map(|e| e.to_string())
+    .collect();
+  assert_eq!(errors.len(), 2, "{errors:?}");
+  assert!(errors.iter().any(|e| e
+    .contains(r#"does not match the import attribute type "json" (expected "application/json")"#)));
+  assert!(errors
+    .iter()
+    .any(|e| e.contains(r#"Unsupported import attribute type "html""#)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_attributes_rule_type_mismatch() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/import-attributes"))
+    .entry("main", "./src/index.js")
+    .module(ModuleOptions::builder().rule(ModuleRule {
+      test: Some(RuleSetCondition::Regexp(
+        RspackRegex::new("\\.json$").unwrap(),
+      )),
+      effect: ModuleRuleEffect {
+        r#type: Some(ModuleType::AssetSource),
+        ..Default::default()
+      },
+      ..Default::default()
+    }))
+    // the `css/auto` type of the default rules matches the `css` import attribute
+    .experiments(Experiments::builder().css(true))
+    .build();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler
+    .compilation
+    .get_errors()
+    .map(|e| e.to_string())
+    .collect();
+  assert_eq!(errors.len(), 1, "{errors:?}");
+  assert!(errors[0].contains(
+    r#"has the module type "asset/source", which does not match the import attribute type "json" (expected "json")"#
+  ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_phases() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/import-phases"))
+    .entry("main", "./src/index.js")
+    .experiments(Experiments::builder().async_web_assembly(true))
+    .optimization(Optimization::builder().minimize(false))
+    .build();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty(), "{errors:?}");
+
+  let assets = compiler.compilation.assets();
+  assert!(assets.keys().any(|name| name.ends_with(".module.wasm")));
+  let source = main_source(&compiler);
+  // source phase imports compile the wasm module without instantiating it
+  assert!(source.contains("__webpack_require__.vs = function(wasmModuleId, wasmModuleHash)"));
+  assert!(source.contains("exports[\"default\"] = wasmModule"));
+  assert!(!source.contains("__webpack_require__.v = function"));
+  // deferred imports evaluate the module on the first access of the namespace
+  assert!(source.contains("__webpack_require__.z = function(moduleId)"));
+  assert!(source.contains("/*#__PURE__*/__webpack_require__.z("));
+  assert!(source.contains("rspack-lazy-evaluated"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_phases_errors() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/import-phases-errors"))
+    .entry("main", "./src/index.js")
+    .build();
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler
+    .compilation
+    .get_errors()
+    .map(|e| e.to_string())
+    .collect();
+  assert_eq!(errors.len(), 1, "{errors:?}");
+  assert!(errors[0].contains("Source phase imports are only supported for WebAssembly modules"));
+}
+
+fn lazy_barrel_compiler(lazy_barrel: bool) -> Compiler {
+  Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/lazy-barrel"))
+    .entry("main", "./src/index.js")
+    .experiments(Experiments::builder().lazy_barrel(lazy_barrel))
+    .optimization(Optimization::builder().minimize(false))
+    .build()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn lazy_barrel() {
+  let mut compiler = lazy_barrel_compiler(true);
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty(), "{errors:?}");
+
+  let module_graph = compiler.compilation.get_module_graph();
+  let built = |name: &str| {
+    module_graph
+      .modules()
+      .keys()
+      .any(|identifier| identifier.ends_with(name))
+  };
+  assert!(built("icons/add.js"));
+  assert!(built("icons/nested/edit.js"));
+  // unused re-exports are not built
+  assert!(!built("icons/remove.js"));
+  assert!(!built("icons/search.js"));
+  assert!(!built("icons/nested/copy.js"));
+
+  let source = main_source(&compiler);
+  assert!(source.contains("icon-add"));
+  assert!(source.contains("icon-edit"));
+  assert!(!source.contains("Cannot find module"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn lazy_barrel_rebuild() {
+  let context = copy_fixture("lazy-barrel", "lazy_barrel_rebuild");
+  let entry = context.join("src/index.js");
+
+  let mut compiler = Compiler::builder()
+    .context(context)
+    .entry("main", "./src/index.js")
+    .experiments(Experiments::builder().lazy_barrel(true))
+    .optimization(Optimization::builder().minimize(false))
+    .build();
+  compiler.build().await.unwrap();
+  let source = main_source(&compiler);
+  assert!(source.contains("icon-add"));
+  assert!(!source.contains("icon-remove"));
+
+  // the barrel is not rebuilt, but the newly imported re-export is built
+  std::fs::write(
+    &entry,
+    "import { Add, Remove } from './icons';\nconsole.log(Add, Remove);",
+  )
+  .unwrap();
+  compiler
+    .rebuild(HashSet::from([entry.to_string()]), HashSet::new())
+    .await
+    .unwrap();
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty(), "{errors:?}");
+  let source = main_source(&compiler);
+  assert!(source.contains("icon-add"));
+  assert!(source.contains("icon-remove"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn lazy_barrel_disabled() {
+  let mut compiler = lazy_barrel_compiler(false);
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler
+    .compilation
+    .get_errors()
+    .map(|e| e.to_string())
+    .collect();
+  assert_eq!(errors.len(), 1, "{errors:?}");
+  assert!(errors[0].contains("./missing"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn commonjs_tree_shaking() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/commonjs-tree-shaking"))
+    .entry("main", "./src/index.js")
+    .optimization(Optimization::builder().minimize(false))
+    .build();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty(), "{errors:?}");
+
+  let source = main_source(&compiler);
+  // statically analyzable CommonJS modules are concatenated into the importer
+  assert!(source.contains("// CONCATENATED MODULE: ./src/lib.js"));
+  assert!(source.contains("lib_namespaceObject.used"));
+  // unused exports of imported and destructured required modules are removed
+  assert!(source.contains("cjs-used"));
+  assert!(source.contains("cjs-picked"));
+  assert!(!source.contains("cjs-unused"));
+  assert!(!source.contains("cjs-dropped"));
+}
+
+fn inline_const_compiler(inline_const: bool, minimize: bool) -> Compiler {
+  Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/inline-const"))
+    .entry("main", "./src/index.js")
+    .experiments(Experiments::builder().inline_const(inline_const))
+    .optimization(
+      Optimization::builder()
+        .minimize(minimize)
+        // keep the modules apart so only the inlining can fold the constants
+        .concatenate_modules(false),
+    )
+    .build()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inline_const() {
+  let mut compiler = inline_const_compiler(true, false);
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty(), "{errors:?}");
+
+  let source = main_source(&compiler);
+  assert!(source.contains("if (/* inlined export .FLAG */ false)"));
+  assert!(source.contains("/* inlined export .MODE */ \"prod\""));
+  // long strings and mutable bindings are still imported
+  assert!(!source.contains("/* inlined export .LABEL */"));
+  assert!(!source.contains("/* inlined export .counter */"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inline_const_disabled() {
+  let mut compiler = inline_const_compiler(false, false);
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty(), "{errors:?}");
+
+  let source = main_source(&compiler);
+  assert!(!source.contains("/* inlined export"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inline_const_minimized() {
+  for (inline_const, dead_branch) in [(true, false), (false, true)] {
+    let mut compiler = inline_const_compiler(inline_const, true);
+    compiler.build().await.unwrap();
+
+    let errors: Vec<_> = compiler.compilation.get_errors().collect();
+    assert!(errors.is_empty(), "{errors:?}");
+
+    let source = main_source(&compiler);
+    // the minimizer drops the branch only when the condition is inlined
+    assert_eq!(source.contains("dead-branch"), dead_branch, "{source}");
+  }
+}
@@ -0,0 +1,48 @@
+// each test crate only uses some of the helpers
+#![allow(dead_code)]
+
+use rspack_core::Compiler;
+use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+
+/// The path of the checked-in fixture
+pub fn fixture(name: &str) -> Utf8PathBuf {
+  Utf8Path::new(env!("CARGO_MANIFEST_DIR"))
+    .join("tests/fixtures")
+    .join(name)
+}
+
+/// Copy the fixture to the temporary directory `dir`, for the tests which change
+/// the files of the fixture
+pub fn copy_fixture(name: &str, dir: &str) -> Utf8PathBuf {
+  let context = std::env::temp_dir()
+    .join("rspack_test")
+    .join(dir)
+    .assert_utf8();
+  let _ = std::fs::remove_dir_all(&context);
+  copy_dir(&fixture(name), &context);
+  context
+}
+
+fn copy_dir(from: &Utf8Path, to: &Utf8Path) {
+  std::fs::create_dir_all(to).expect("should create the directory");
+  for entry in from.read_dir_utf8().expect("should read the directory") {
+    let entry = entry.expect("should read the directory entry");
+    let target = to.join(entry.file_name());
+    if entry.path().is_dir() {
+      copy_dir(entry.path(), &target);
+    } else {
+      std::fs::copy(entry.path(), &target).expect("should copy the file");
+    }
+  }
+}
+
+/// The source of the `main.js` asset, or an empty string if it is not emitted
+pub fn main_source(compiler: &Compiler) -> String {
+  compiler
+    .compilation
+    .assets()
+    .get("main.js")
+    .and_then(|asset| asset.source.as_ref())
+    .map(|source| source.source().to_string())
+    .unwrap_or_default()
+}
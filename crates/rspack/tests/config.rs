@@ -0,0 +1,163 @@
+use rspack::{builder::CompilerBuilder, config::Config};
+use rspack_core::ChunkGraph;
+use rspack_paths::Utf8Path;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn toml_config() {
+  let config = Config::from_path(
+    Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config/rspack.config.toml"),
+  )
+  .unwrap();
+  let mut compiler = CompilerBuilder::try_from(config).unwrap().build();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty());
+
+  let asset = &compiler.compilation.assets().get("main.bundle.js").unwrap();
+  assert_eq!(asset.source.as_ref().unwrap().source(), "console.log(123);");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn json_config() {
+  let config = Config::from_json_str(&format!(
+    r#"{{
+      "context": "{}",
+      "mode": "development",
+      "entry": {{ "app": {{ "import": ["./src/index.js"] }} }},
+      "experiments": {{ "rspackFuture": {{ "bundlerInfo": {{ "force": ["version"] }} }} }},
+      "optimization": {{ "moduleIds": "named" }}
+    }}"#,
+    Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config")
+  ))
+  .unwrap();
+  let mut compiler = CompilerBuilder::try_from(config).unwrap().build();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty());
+  let asset = compiler.compilation.assets().get("app.js").unwrap();
+  let source = asset.source.as_ref().unwrap().source();
+  assert!(source.contains("__webpack_require__.rv"), "{source}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn module_ids_config() {
+  let context = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config");
+  for (module_ids, id_len) in [
+    (
+      r#""moduleIds": "hashed", "hashedModuleIds": { "hashDigest": "hex", "hashDigestLength": 8 }"#,
+      Some(8),
+    ),
+    (r#""moduleIds": "size""#, None),
+  ] {
+    let config = Config::from_json_str(&format!(
+      r#"{{
+        "context": "{context}",
+        "entry": "./src/index.js",
+        "optimization": {{ {module_ids}, "concatenateModules": false }}
+      }}"#
+    ))
+    .unwrap();
+    let mut compiler = CompilerBuilder::try_from(config).unwrap().build();
+
+    compiler.build().await.unwrap();
+
+    let errors: Vec<_> = compiler.compilation.get_errors().collect();
+    assert!(errors.is_empty());
+    let compilation = &compiler.compilation;
+    let ids = compilation
+      .get_module_graph()
+      .modules()
+      .keys()
+      .filter_map(|identifier| {
+        ChunkGraph::get_module_id(&compilation.module_ids_artifact, *identifier)
+      })
+      .map(|id| id.to_string())
+      .collect::<Vec<_>>();
+    assert_eq!(ids.len(), 1);
+    match id_len {
+      Some(len) => assert_eq!(ids[0].len(), len, "{ids:?}"),
+      // the size ids are numbers from 0
+      None => assert_eq!(ids[0], "0"),
+    }
+  }
+}
+
+#[test]
+fn relative_config_path() {
+  // integration tests run in the package directory
+  let config = Config::from_path("tests/fixtures/config/rspack.config.toml").unwrap();
+  let compiler = CompilerBuilder::try_from(config).unwrap().build();
+
+  let context = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config");
+  assert_eq!(compiler.options.context.as_path(), context);
+  assert_eq!(compiler.options.output.path, context.join("dist"));
+}
+
+#[test]
+fn relative_records_paths() {
+  let context = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config");
+  let output = context.join("dist/records.json");
+  let config = Config::from_json_str(&format!(
+    r#"{{
+      "context": "{context}",
+      "recordsInputPath": "records/input.json",
+      "recordsOutputPath": "{output}"
+    }}"#
+  ))
+  .unwrap();
+  let compiler = CompilerBuilder::try_from(config).unwrap().build();
+
+  assert_eq!(
+    compiler.options.records_input_path.as_deref(),
+    Some(context.join("records/input.json").as_path())
+  );
+  assert_eq!(
+    compiler.options.records_output_path.as_deref(),
+    Some(output.as_path())
+  );
+}
+
+fn error(source: &str) -> String {
+  let config = Config::from_toml_str(source).expect("config should be valid TOML");
+  CompilerBuilder::try_from(config)
+    .err()
+    .expect("config should be invalid")
+    .to_string()
+}
+
+#[test]
+fn config_errors() {
+  assert_eq!(
+    error("mode = \"prod\""),
+    "Invalid configuration at \"mode\": unknown mode \"prod\", expected \"development\", \"production\" or \"none\""
+  );
+  assert!(error("[output]\nfilenam = \"[name].js\"")
+    .starts_with("Invalid configuration at \"output.filenam\": unknown key"));
+  assert_eq!(
+    error(
+      r#"
+[[module.rules]]
+test = { regex = "\\.json$" }
+type = "json"
+
+[[module.rules]]
+test = { regex = "\\.js$" }
+use = [{ loader = "babel-loader" }]
+"#
+    )
+    .split_once(',')
+    .unwrap()
+    .0,
+    "Invalid configuration at \"module.rules[1].use[0].loader\": unknown loader \"babel-loader\""
+  );
+  assert!(error("[optimization.splitChunks.cacheGroups.vendors]\nminChunks = \"2\"")
+    .starts_with("Invalid configuration at \"optimization.splitChunks.cacheGroups.vendors.minChunks\": invalid type"));
+  assert!(
+    error("[optimization.hashedModuleIds]\nhashFunction = \"sha1\"")
+      .starts_with("Invalid configuration at \"optimization.hashedModuleIds.hashFunction\": ")
+  );
+}
@@ -0,0 +1,5 @@
+import { used } from "./lib";
+
+const { picked } = require("./picked");
+
+console.log(used, picked);
@@ -0,0 +1,6 @@
+"use strict";
+
+exports.used = "cjs-used";
+exports.unused = function () {
+  return "cjs-unused";
+};
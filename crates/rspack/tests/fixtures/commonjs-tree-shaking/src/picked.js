@@ -0,0 +1,4 @@
+"use strict";
+
+exports.picked = "cjs-picked";
+exports.dropped = "cjs-dropped";
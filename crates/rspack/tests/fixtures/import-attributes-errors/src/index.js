@@ -0,0 +1,4 @@
+import a from './a.js' with { type: 'json' };
+import b from './b.txt' with { type: 'html' };
+
+console.log(a, b);
@@ -0,0 +1,6 @@
+import data from "./data.json" with { type: "json" };
+import text from "./text.txt" with { type: "text" };
+import bytes from "./bytes.bin" with { type: "bytes" };
+import sheet from "./style.css" with { type: "css" };
+
+console.log(data.name, text, bytes, sheet);
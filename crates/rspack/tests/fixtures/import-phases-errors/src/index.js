@@ -0,0 +1,3 @@
+import source a from './a.js';
+
+console.log(a);
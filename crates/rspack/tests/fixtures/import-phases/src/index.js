@@ -0,0 +1,5 @@
+import source wasmModule from "./module.wasm";
+import defer * as lazy from "./lazy.js";
+
+console.log(wasmModule instanceof WebAssembly.Module);
+setTimeout(() => console.log(lazy.value));
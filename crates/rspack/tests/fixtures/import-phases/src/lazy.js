@@ -0,0 +1,3 @@
+console.log("rspack-lazy-evaluated");
+
+export const value = "rspack-lazy";
@@ -0,0 +1,4 @@
+export const FLAG = false;
+export const MODE = "prod";
+export const LABEL = "not inlined";
+export let counter = 0;
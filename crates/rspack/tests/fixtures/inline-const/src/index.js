@@ -0,0 +1,7 @@
+import { FLAG, MODE, LABEL, counter } from "./constants";
+
+if (FLAG) {
+	console.log("dead-branch");
+}
+
+console.log(MODE, LABEL, counter);
@@ -0,0 +1 @@
+export const Add = "icon-add";
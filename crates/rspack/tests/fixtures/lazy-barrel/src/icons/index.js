@@ -0,0 +1,5 @@
+export { Add } from "./add";
+export { Remove } from "./remove";
+export { default as Search } from "./search";
+export { Edit } from "./nested";
+export { Missing } from "./missing";
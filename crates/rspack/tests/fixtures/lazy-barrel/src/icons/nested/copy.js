@@ -0,0 +1 @@
+export const Copy = "icon-copy";
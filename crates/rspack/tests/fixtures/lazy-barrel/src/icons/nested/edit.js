@@ -0,0 +1 @@
+export const Edit = "icon-edit";
@@ -0,0 +1,2 @@
+export { Edit } from "./edit";
+export { Copy } from "./copy";
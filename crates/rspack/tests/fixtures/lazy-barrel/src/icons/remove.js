@@ -0,0 +1 @@
+export const Remove = "icon-remove";
@@ -0,0 +1,3 @@
+import { Add, Edit } from "./icons";
+
+console.log(Add, Edit);
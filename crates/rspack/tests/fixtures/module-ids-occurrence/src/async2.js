@@ -0,0 +1,3 @@
+import './c';
+
+console.log('async2');
@@ -0,0 +1,3 @@
+import './a';
+import('./async1');
+import('./async2');
@@ -0,0 +1 @@
+console.log(123);
\ No newline at end of file
@@ -0,0 +1,147 @@
+mod common;
+
+use rspack::builder::{Builder as _, OptimizationOptionsBuilder};
+use rspack_core::{ChunkGraph, Compiler, Optimization};
+use rspack_hash::{HashDigest, HashFunction};
+use rspack_ids::{HashedModuleIdsPluginOptions, OccurrenceModuleIdsPluginOptions};
+use rspack_paths::Utf8Path;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::common::{copy_fixture, fixture};
+
+/// Build the context and return the module ids by the file names
+async fn build(
+  context: &Utf8Path,
+  optimization: OptimizationOptionsBuilder,
+) -> HashMap<String, String> {
+  let mut compiler = Compiler::builder()
+    .context(context.to_owned())
+    .entry("main", "./src/index.js")
+    .optimization(optimization)
+    .build();
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_errors().next().is_none());
+
+  let compilation = &compiler.compilation;
+  compilation
+    .get_module_graph()
+    .modules()
+    .keys()
+    .filter_map(|identifier| {
+      let id = ChunkGraph::get_module_id(&compilation.module_ids_artifact, *identifier)?;
+      let name = Utf8Path::new(identifier.as_str()).file_name()?;
+      Some((name.to_string(), id.to_string()))
+    })
+    .collect()
+}
+
+fn hashed(options: HashedModuleIdsPluginOptions) -> OptimizationOptionsBuilder {
+  let mut builder = Optimization::builder();
+  builder
+    .module_ids("hashed".to_string())
+    .hashed_module_ids(options)
+    .concatenate_modules(false)
+    .minimize(false);
+  builder
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hashed_ids_are_stable_across_rebuilds() {
+  let context = copy_fixture("module-ids", "hashed_ids_are_stable_across_rebuilds");
+  let ids = build(&context, hashed(Default::default())).await;
+  let b_id = ids["b.js"].clone();
+  assert_eq!(b_id.len(), 4);
+
+  // a new module does not change the ids of other modules
+  std::fs::write(context.join("src/index.js"), "import './a';\nimport './b';").unwrap();
+  std::fs::write(context.join("src/a.js"), "console.log('a');").unwrap();
+  let new_ids = build(&context, hashed(Default::default())).await;
+  assert_eq!(new_ids["b.js"], b_id);
+  assert_ne!(new_ids["a.js"], b_id);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hashed_ids_resolve_collisions() {
+  let context = fixture("module-ids-collisions");
+
+  // there are only 16 ids of a single hex char, so some of the 21 modules collide
+  let options = HashedModuleIdsPluginOptions {
+    context: None,
+    hash_function: HashFunction::MD4,
+    hash_digest: HashDigest::Hex,
+    hash_digest_length: 1,
+  };
+  let ids = build(&context, hashed(options.clone())).await;
+  assert_eq!(ids.len(), 21, "{ids:?}");
+  let mut unique = ids.values().collect::<Vec<_>>();
+  unique.sort_unstable();
+  unique.dedup();
+  assert_eq!(unique.len(), ids.len());
+  assert!(ids.values().any(|id| id.len() > 1));
+
+  // the collisions are resolved in the same way in the next build
+  let new_ids = build(&context, hashed(options)).await;
+  assert_eq!(new_ids, ids);
+}
+
+fn occurrence(options: OccurrenceModuleIdsPluginOptions) -> OptimizationOptionsBuilder {
+  let mut builder = Optimization::builder();
+  builder
+    .module_ids("size".to_string())
+    .occurrence_module_ids(options)
+    .concatenate_modules(false)
+    .minimize(false);
+  builder
+}
+
+fn numeric_id(ids: &HashMap<String, String>, name: &str) -> usize {
+  ids[name].parse().expect("should be a numeric id")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn occurrence_ids_order_by_occurrences() {
+  // `a.js` is only in the initial chunk, `c.js` is referenced by two async chunks
+  let context = fixture("module-ids-occurrence");
+  let ids = build(
+    &context,
+    occurrence(OccurrenceModuleIdsPluginOptions {
+      prioritise_initial: false,
+    }),
+  )
+  .await;
+  assert_eq!(ids.len(), 5);
+  // the module which occurs more gets the smaller id
+  assert!(numeric_id(&ids, "c.js") < numeric_id(&ids, "a.js"));
+  assert!(numeric_id(&ids, "c.js") < numeric_id(&ids, "async1.js"));
+
+  let mut sorted = ids.values().map(String::as_str).collect::<Vec<_>>();
+  sorted.sort_unstable_by_key(|id| id.parse::<usize>().expect("should be a numeric id"));
+  assert_eq!(sorted, ["0", "1", "2", "3", "4"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn occurrence_ids_prioritise_initial() {
+  let context = fixture("module-ids-occurrence");
+  let ids = build(
+    &context,
+    occurrence(OccurrenceModuleIdsPluginOptions {
+      prioritise_initial: true,
+    }),
+  )
+  .await;
+  // the modules in initial chunks get smaller ids than the modules only in async chunks
+  assert!(numeric_id(&ids, "a.js") < numeric_id(&ids, "c.js"));
+  assert!(numeric_id(&ids, "index.js") < numeric_id(&ids, "c.js"));
+
+  // `prioritise_initial` is used by default
+  let default_ids = build(&context, {
+    let mut builder = Optimization::builder();
+    builder
+      .module_ids("size".to_string())
+      .concatenate_modules(false)
+      .minimize(false);
+    builder
+  })
+  .await;
+  assert_eq!(default_ids, ids);
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use rspack::builder::Builder as _;
+use rspack_core::{Compiler, Optimization, RecordId, Records};
+use rspack_paths::Utf8Path;
+
+use crate::common::copy_fixture;
+
+async fn build(context: &Utf8Path) -> Records {
+  let mut compiler = Compiler::builder()
+    .context(context.to_owned())
+    .entry("main", "./src/index.js")
+    .records_path(context.join("records.json"))
+    .optimization(
+      Optimization::builder()
+        .module_ids("natural".to_string())
+        .chunk_ids("natural".to_string())
+        .concatenate_modules(false)
+        .minimize(false),
+    )
+    .build();
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_errors().next().is_none());
+
+  let content = std::fs::read(context.join("records.json")).expect("should emit records");
+  serde_json::from_slice(&content).expect("should be valid records")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn records() {
+  let context = copy_fixture("records", "records");
+
+  let records = build(&context).await;
+  let b_id = records.modules.by_identifier["./src/b.js"].clone();
+  let main_id = records.chunks.by_name["main"].clone();
+  // the natural ids are written as numbers
+  assert!(matches!(b_id, RecordId::Number(_)));
+  assert!(matches!(main_id, RecordId::Number(_)));
+
+  // the natural id of b.js will be changed without records
+  std::fs::write(context.join("src/index.js"), "import './a';\nimport './b';").unwrap();
+  std::fs::write(context.join("src/a.js"), "console.log('a');").unwrap();
+
+  let new_records = build(&context).await;
+  assert_eq!(new_records.modules.by_identifier["./src/b.js"], b_id);
+  assert_eq!(new_records.chunks.by_name["main"], main_id);
+  let a_id = &new_records.modules.by_identifier["./src/a.js"];
+  assert!(!records.modules.used_ids.contains(a_id));
+}
+
+#[test]
+fn records_with_mixed_ids() {
+  let records: Records = serde_json::from_str(
+    r#"{
+      "modules": { "byIdentifier": { "./a.js": 0, "./b.js": "b" }, "usedIds": [0, "b"] },
+      "chunks": { "byName": { "main": 1 }, "bySource": {}, "usedIds": [1] }
+    }"#,
+  )
+  .expect("should parse records");
+  assert_eq!(records.modules.by_identifier["./a.js"], RecordId::Number(0));
+  assert_eq!(
+    records.modules.by_identifier["./b.js"],
+    RecordId::String("b".to_string())
+  );
+  assert_eq!(records.chunks.by_name["main"].to_string(), "1");
+  assert_eq!(RecordId::from("01"), RecordId::String("01".to_string()));
+
+  let content = serde_json::to_value(&records).expect("should serialize records");
+  assert_eq!(content["modules"]["usedIds"], serde_json::json!([0, "b"]));
+}
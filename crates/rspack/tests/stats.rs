@@ -0,0 +1,154 @@
+mod common;
+
+use rspack::builder::Builder as _;
+use rspack_core::{
+  Compiler, EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsOptions, StatsPreset,
+};
+use rspack_paths::Utf8Path;
+
+use crate::common::fixture;
+
+async fn build(context: &Utf8Path) -> Compiler {
+  let mut compiler = Compiler::builder()
+    .context(context.to_owned())
+    .entry("main", "./src/index.js")
+    .stats(StatsOptions { colors: false })
+    .build();
+  compiler.build().await.expect("should build");
+  compiler
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stats_json() {
+  let context = fixture("stats");
+  let mut compiler = Compiler::builder()
+    .context(context.clone())
+    .entry("main", "./src/index.js")
+    .build();
+  compiler.build().await.unwrap();
+
+  let stats = Stats::new(&compiler.compilation);
+  let json = stats
+    .to_json(&ExtendedStatsOptions::default())
+    .expect("should generate stats json");
+  assert!(json["hash"].is_string());
+  assert_eq!(json["version"], "5.75.0");
+  assert!(json["rspackVersion"].is_string());
+  assert!(json["time"].is_number());
+  assert!(json["builtAt"].as_u64().unwrap() > 0);
+  assert_eq!(json["outputPath"], context.join("dist").as_str());
+  assert_eq!(json["publicPath"], "auto");
+  assert_eq!(json["errorsCount"], 0);
+  assert_eq!(json["warningsCount"], 0);
+  assert_eq!(json["assetsByChunkName"]["main"][0], "main.js");
+  assert_eq!(json["entrypoints"]["main"]["name"], "main");
+  assert_eq!(json["chunks"].as_array().unwrap().len(), 2);
+  let modules = json["modules"].as_array().unwrap();
+  let a = modules
+    .iter()
+    .find(|m| m["name"] == "./src/a.js")
+    .expect("should have a.js");
+  assert_eq!(a["moduleType"], "javascript/auto");
+  assert!(a["sizes"]["javascript"].is_number());
+  assert!(a["reasons"].is_array());
+
+  let json = stats
+    .to_json(&ExtendedStatsOptions::from_preset(
+      StatsPreset::ErrorsOnly,
+      false,
+    ))
+    .expect("should generate stats json");
+  assert!(json.get("modules").is_none());
+  assert!(json.get("assets").is_none());
+  assert!(json.get("version").is_none());
+  assert!(json.get("warningsCount").is_none());
+  assert_eq!(json["errorsCount"], 0);
+  assert!(json["errors"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stats_auto_entrypoints() {
+  let compiler = build(&fixture("basic")).await;
+  let stats = Stats::new(&compiler.compilation);
+
+  // the entrypoint only has a single chunk with a single file
+  let mut options = ExtendedStatsOptions::from_preset(StatsPreset::Normal, false);
+  options.entrypoints = EntrypointsStatsOption::String("auto".to_string());
+  options.chunk_groups = false;
+  options.chunk_group_children = false;
+  let json = stats.to_json(&options).expect("should generate stats json");
+  assert!(json.get("entrypoints").is_none());
+
+  options.chunk_groups = true;
+  let json = stats.to_json(&options).expect("should generate stats json");
+  assert_eq!(json["entrypoints"]["main"]["name"], "main");
+
+  options.chunk_groups = false;
+  options.chunk_group_children = true;
+  let json = stats.to_json(&options).expect("should generate stats json");
+  assert_eq!(json["entrypoints"]["main"]["name"], "main");
+
+  let output = stats
+    .to_string(&ExtendedStatsOptions::from_preset(
+      StatsPreset::Normal,
+      true,
+    ))
+    .expect("should generate stats string");
+  assert!(!output.contains("Entrypoint main"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stats_summary() {
+  let summary = ExtendedStatsOptions::from_preset(StatsPreset::Summary, true);
+
+  let compiler = build(&fixture("basic")).await;
+  let stats = Stats::new(&compiler.compilation);
+  let version = stats.to_json(&summary).expect("should generate stats json")["rspackVersion"]
+    .as_str()
+    .expect("should have rspack version")
+    .to_string();
+  let output = stats
+    .to_string(&summary)
+    .expect("should generate stats string");
+  assert_eq!(output, format!("Rspack {version} compiled successfully"));
+
+  let compiler = build(&fixture("stats-errors")).await;
+  let stats = Stats::new(&compiler.compilation);
+  let output = stats
+    .to_string(&summary)
+    .expect("should generate stats string");
+  assert!(output.ends_with("compiled with 1 error"), "{output}");
+
+  // the errors are only counted in the errors-only preset
+  let output = stats
+    .to_string(&ExtendedStatsOptions::from_preset(
+      StatsPreset::ErrorsOnly,
+      true,
+    ))
+    .expect("should generate stats string");
+  assert!(output.ends_with("Rspack compiled with 1 error"), "{output}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stats_to_string() {
+  let compiler = build(&fixture("stats")).await;
+
+  let stats = Stats::new(&compiler.compilation);
+  let output = stats
+    .to_string(&ExtendedStatsOptions::from_preset(
+      StatsPreset::Normal,
+      true,
+    ))
+    .expect("should generate stats string");
+  assert!(output.contains("asset main.js"));
+  assert!(output.contains("./src/index.js"));
+  assert!(output.contains(" compiled successfully in "));
+
+  let output = stats
+    .to_string(&ExtendedStatsOptions::from_preset(
+      StatsPreset::ErrorsOnly,
+      true,
+    ))
+    .expect("should generate stats string");
+  assert!(output.is_empty());
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use std::{collections::HashSet, ops::ControlFlow, time::Duration};
+
+use rspack::builder::Builder as _;
+use rspack_core::{Compiler, WatchOptions};
+use rspack_paths::Utf8PathBuf;
+
+use crate::common::{copy_fixture, main_source};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watch() {
+  let context = copy_fixture("watch", "watch");
+  let entry: Utf8PathBuf = context.join("src/index.js");
+
+  let mut compiler = Compiler::builder()
+    .context(context.clone())
+    .entry("main", "./src/index.js")
+    .build();
+
+  let mut builds = 0;
+  tokio::time::timeout(
+    Duration::from_secs(30),
+    compiler.watch(
+      WatchOptions {
+        ignored: vec!["**/dist/**".into()],
+        ..Default::default()
+      },
+      |compiler, result| {
+        result.unwrap();
+        assert!(compiler.compilation.get_errors().next().is_none());
+        builds += 1;
+        match builds {
+          1 => {
+            assert_eq!(main_source(compiler), "console.log(123);");
+            // the first touch after the build is skipped by the file snapshot
+            let entry = entry.clone();
+            std::thread::spawn(move || {
+              std::thread::sleep(Duration::from_millis(200));
+              std::fs::write(entry, "console.log(123);").unwrap();
+            });
+            ControlFlow::Continue(())
+          }
+          2 => {
+            assert!(compiler.compilation.modified_files.is_empty());
+            assert_eq!(main_source(compiler), "console.log(123);");
+            let entry = entry.clone();
+            std::thread::spawn(move || {
+              std::thread::sleep(Duration::from_millis(200));
+              std::fs::write(entry, "console.log(456);").unwrap();
+            });
+            ControlFlow::Continue(())
+          }
+          _ => {
+            assert_eq!(main_source(compiler), "console.log(456);");
+            ControlFlow::Break(())
+          }
+        }
+      },
+    ),
+  )
+  .await
+  .expect("should rebuild after the entry is changed")
+  .unwrap();
+
+  assert_eq!(builds, 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebuild_skip_unchanged_files() {
+  let context = copy_fixture("watch", "rebuild_skip_unchanged_files");
+  let entry: Utf8PathBuf = context.join("src/index.js");
+
+  let mut compiler = Compiler::builder()
+    .context(context.clone())
+    .entry("main", "./src/index.js")
+    .build();
+  compiler.build().await.unwrap();
+  compiler.create_file_snapshot().await;
+
+  // touch the entry without changing the content, the compilation still runs
+  // without modified files
+  std::fs::write(&entry, "console.log(123);").unwrap();
+  compiler
+    .rebuild(HashSet::from([entry.to_string()]), HashSet::new())
+    .await
+    .unwrap();
+  assert_eq!(compiler.compilation.hot_index, 1);
+  assert!(compiler.compilation.modified_files.is_empty());
+  assert_eq!(main_source(&compiler), "console.log(123);");
+
+  std::fs::write(&entry, "console.log(456);").unwrap();
+  compiler
+    .rebuild(HashSet::from([entry.to_string()]), HashSet::new())
+    .await
+    .unwrap();
+  assert_eq!(compiler.compilation.hot_index, 2);
+  assert_eq!(compiler.compilation.modified_files.len(), 1);
+  assert_eq!(main_source(&compiler), "console.log(456);");
+
+  // the snapshot is updated with the modified files
+  std::fs::write(&entry, "console.log(456);").unwrap();
+  compiler
+    .rebuild(HashSet::from([entry.to_string()]), HashSet::new())
+    .await
+    .unwrap();
+  assert!(compiler.compilation.modified_files.is_empty());
+
+  std::fs::write(&entry, "console.log(789);").unwrap();
+  compiler
+    .rebuild(HashSet::from([entry.to_string()]), HashSet::new())
+    .await
+    .unwrap();
+  assert_eq!(compiler.compilation.modified_files.len(), 1);
+  assert_eq!(main_source(&compiler), "console.log(789);");
+}
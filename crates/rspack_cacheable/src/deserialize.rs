@@ -1,5 +1,3 @@
-use std::any::Any;
-
 use rkyv::{
   access,
   api::{deserialize_using, high::HighValidator},
@@ -10,7 +8,7 @@ use rkyv::{
   Archive, Deserialize,
 };
 
-use crate::context::ContextGuard;
+use crate::context::{CacheableContext, ContextGuard};
 
 #[derive(Debug)]
 pub enum DeserializeError {
@@ -72,7 +70,7 @@ pub type Deserializer = Strategy<Pool, DeserializeError>;
 ///
 /// This function implementation refers to rkyv::from_bytes and
 /// add custom error and context support
-pub fn from_bytes<T, C: Any>(bytes: &[u8], context: &C) -> Result<T, DeserializeError>
+pub fn from_bytes<T, C: CacheableContext>(bytes: &[u8], context: &C) -> Result<T, DeserializeError>
 where
   T: Archive,
   T::Archived: for<'a> CheckBytes<Validator<'a>> + Deserialize<T, Deserializer>,
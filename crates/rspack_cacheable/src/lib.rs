@@ -17,6 +17,7 @@ pub mod __private {
   pub extern crate rkyv;
 }
 
+pub use context::CacheableContext;
 pub use deserialize::{from_bytes, DeserializeError, Deserializer, Validator};
 pub use serialize::{to_bytes, SerializeError, Serializer};
 pub use xxhash_rust;
@@ -1,5 +1,3 @@
-use std::any::Any;
-
 use rkyv::{
   api::{high::HighSerializer, serialize_using},
   rancor::{BoxedError, Source, Trace},
@@ -12,7 +10,7 @@ use rkyv::{
   Serialize,
 };
 
-use crate::context::ContextGuard;
+use crate::context::{CacheableContext, ContextGuard};
 
 #[derive(Debug)]
 pub enum SerializeError {
@@ -69,7 +67,7 @@ pub type Serializer<'a> = HighSerializer<AlignedVec, ArenaHandle<'a>, SerializeE
 ///
 /// This function implementation refers to rkyv::to_bytes and
 /// add custom error and context support
-pub fn to_bytes<T, C: Any>(value: &T, ctx: &C) -> Result<Vec<u8>, SerializeError>
+pub fn to_bytes<T, C: CacheableContext>(value: &T, ctx: &C) -> Result<Vec<u8>, SerializeError>
 where
   T: for<'a> Serialize<Serializer<'a>>,
 {
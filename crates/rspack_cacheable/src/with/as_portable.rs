@@ -0,0 +1,185 @@
+use std::{borrow::Cow, path::Path};
+
+use rkyv::{
+  de::Pooling,
+  rancor::Fallible,
+  ser::{Sharing, Writer},
+  string::{ArchivedString, StringResolver},
+  with::{ArchiveWith, DeserializeWith, SerializeWith},
+  Place,
+};
+
+use crate::{context::ContextGuard, DeserializeError, SerializeError};
+
+const PROJECT_ROOT_PLACEHOLDER: &str = "<PROJECT_ROOT>";
+
+/// Serialize a string which may contain the absolute path of the project
+///
+/// The project root provided by the context will be replaced with a placeholder,
+/// and the placeholder will be replaced with the project root of the current
+/// context when deserializing, so the serialized data can be moved to another directory.
+pub struct AsPortable;
+
+pub trait AsPortableConverter {
+  fn as_str(&self) -> &str;
+  fn from_str(s: &str) -> Self
+  where
+    Self: Sized;
+}
+
+pub struct AsPortableResolver {
+  resolver: StringResolver,
+  /// The portable string, None means the string is not changed
+  value: Option<String>,
+}
+
+impl<T> ArchiveWith<T> for AsPortable
+where
+  T: AsPortableConverter,
+{
+  type Archived = ArchivedString;
+  type Resolver = AsPortableResolver;
+
+  #[inline]
+  fn resolve_with(field: &T, resolver: Self::Resolver, out: Place<Self::Archived>) {
+    let AsPortableResolver { resolver, value } = resolver;
+    ArchivedString::resolve_from_str(value.as_deref().unwrap_or(field.as_str()), resolver, out);
+  }
+}
+
+impl<T, S> SerializeWith<T, S> for AsPortable
+where
+  T: AsPortableConverter,
+  S: Fallible<Error = SerializeError> + Writer + Sharing + ?Sized,
+{
+  #[inline]
+  fn serialize_with(field: &T, serializer: &mut S) -> Result<Self::Resolver, SerializeError> {
+    let value = ContextGuard::sharing_guard(serializer)?
+      .project_root()
+      .and_then(|root| to_portable(field.as_str(), root));
+    let resolver =
+      ArchivedString::serialize_from_str(value.as_deref().unwrap_or(field.as_str()), serializer)?;
+    Ok(AsPortableResolver { resolver, value })
+  }
+}
+
+impl<T, D> DeserializeWith<ArchivedString, T, D> for AsPortable
+where
+  T: AsPortableConverter,
+  D: Fallible<Error = DeserializeError> + Pooling + ?Sized,
+{
+  #[inline]
+  fn deserialize_with(field: &ArchivedString, de: &mut D) -> Result<T, DeserializeError> {
+    let value = match ContextGuard::pooling_guard(de)?.project_root() {
+      Some(root) => from_portable(field.as_str(), root),
+      None => Cow::Borrowed(field.as_str()),
+    };
+    Ok(AsPortableConverter::from_str(&value))
+  }
+}
+
+/// Replace the project root at the start of paths in `s` with placeholder
+///
+/// `s` is split by `|` and `!` like module identifiers and requests, a part is
+/// replaced only when the project root is a prefix of its path components, so
+/// `/root` does not match `/root1` or `/a/root`.
+///
+/// Return None if no part is in the project root.
+pub fn to_portable(s: &str, root: &str) -> Option<String> {
+  let root = root.trim_end_matches(['/', '\\']);
+  if root.is_empty() {
+    return None;
+  }
+  let mut replaced = false;
+  let result = split_paths(s)
+    .map(|part| {
+      if Path::new(part).strip_prefix(root).is_err() {
+        return Cow::Borrowed(part);
+      }
+      // the path components are the same, so the root is a prefix of the part
+      let Some(rest) = part.strip_prefix(root) else {
+        return Cow::Borrowed(part);
+      };
+      replaced = true;
+      Cow::Owned(format!("{PROJECT_ROOT_PLACEHOLDER}{rest}"))
+    })
+    .collect::<String>();
+  replaced.then_some(result)
+}
+
+/// Replace the placeholder at the start of paths in `s` with project root
+pub fn from_portable<'a>(s: &'a str, root: &str) -> Cow<'a, str> {
+  if !s.contains(PROJECT_ROOT_PLACEHOLDER) {
+    return Cow::Borrowed(s);
+  }
+  let root = root.trim_end_matches(['/', '\\']);
+  Cow::Owned(
+    split_paths(s)
+      .map(|part| match part.strip_prefix(PROJECT_ROOT_PLACEHOLDER) {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => {
+          Cow::Owned(format!("{root}{rest}"))
+        }
+        _ => Cow::Borrowed(part),
+      })
+      .collect::<String>(),
+  )
+}
+
+/// Split `s` into paths and the separators between them
+fn split_paths(s: &str) -> impl Iterator<Item = &str> {
+  let mut rest = s;
+  std::iter::from_fn(move || {
+    if rest.is_empty() {
+      return None;
+    }
+    let end = match rest.find(['|', '!']) {
+      // yield the separator itself
+      Some(0) => 1,
+      Some(index) => index,
+      None => rest.len(),
+    };
+    let (part, remain) = rest.split_at(end);
+    rest = remain;
+    Some(part)
+  })
+}
+
+// for String
+impl AsPortableConverter for String {
+  fn as_str(&self) -> &str {
+    self
+  }
+  fn from_str(s: &str) -> Self {
+    s.into()
+  }
+}
+
+// for Ustr
+impl AsPortableConverter for ustr::Ustr {
+  fn as_str(&self) -> &str {
+    ustr::Ustr::as_str(self)
+  }
+  fn from_str(s: &str) -> Self {
+    s.into()
+  }
+}
+
+// for Atom
+impl AsPortableConverter for swc_core::ecma::atoms::Atom {
+  fn as_str(&self) -> &str {
+    self
+  }
+  fn from_str(s: &str) -> Self {
+    s.into()
+  }
+}
+
+// for Utf8PathBuf
+impl AsPortableConverter for camino::Utf8PathBuf {
+  fn as_str(&self) -> &str {
+    camino::Utf8Path::as_str(self)
+  }
+  fn from_str(s: &str) -> Self {
+    s.into()
+  }
+}
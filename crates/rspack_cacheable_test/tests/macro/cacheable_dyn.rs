@@ -1,9 +1,10 @@
-use rspack_cacheable::{cacheable, cacheable_dyn, from_bytes, to_bytes};
+use rspack_cacheable::{cacheable, cacheable_dyn, from_bytes, to_bytes, CacheableContext};
 
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_cacheable_dyn_macro() {
   struct Context;
+  impl CacheableContext for Context {}
 
   #[cacheable_dyn]
   trait Animal {
@@ -75,6 +76,7 @@ fn test_cacheable_dyn_macro() {
 #[cfg_attr(miri, ignore)]
 fn test_cacheable_dyn_macro_with_generics() {
   struct Context;
+  impl CacheableContext for Context {}
 
   #[cacheable_dyn]
   trait Animal<T = ()>: Send + Sync
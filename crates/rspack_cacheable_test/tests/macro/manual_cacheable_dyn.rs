@@ -1,9 +1,10 @@
-use rspack_cacheable::{cacheable, from_bytes, r#dyn::VTablePtr, to_bytes};
+use rspack_cacheable::{cacheable, from_bytes, r#dyn::VTablePtr, to_bytes, CacheableContext};
 
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_manual_cacheable_dyn_macro() {
   struct Context;
+  impl CacheableContext for Context {}
 
   trait Animal: rspack_cacheable::r#dyn::SerializeDyn {
     fn color(&self) -> &str;
@@ -0,0 +1,120 @@
+use camino::{Utf8Path, Utf8PathBuf};
+use rspack_cacheable::{
+  cacheable, from_bytes, to_bytes,
+  with::{from_portable, to_portable, AsOption, AsPortable},
+  CacheableContext,
+};
+
+struct Context {
+  root: Utf8PathBuf,
+}
+
+impl CacheableContext for Context {
+  fn project_root(&self) -> Option<&Utf8Path> {
+    Some(&self.root)
+  }
+}
+
+#[cacheable]
+#[derive(Debug, PartialEq, Eq)]
+struct Module {
+  #[cacheable(with=AsPortable)]
+  request: String,
+  #[cacheable(with=AsOption<AsPortable>)]
+  resource: Option<String>,
+  name: String,
+}
+
+#[test]
+fn test_as_portable() {
+  let module = Module {
+    request: String::from("/root/project/loader.js!/root/project/src/index.js"),
+    resource: Some(String::from("/root/project/src/index.js")),
+    name: String::from("/root/project/src/index.js"),
+  };
+  let bytes = to_bytes(
+    &module,
+    &Context {
+      root: Utf8PathBuf::from("/root/project"),
+    },
+  )
+  .unwrap();
+
+  // deserialize with the same root
+  let new_module: Module = from_bytes(
+    &bytes,
+    &Context {
+      root: Utf8PathBuf::from("/root/project"),
+    },
+  )
+  .unwrap();
+  assert_eq!(module, new_module);
+
+  // deserialize with another root
+  let new_module: Module = from_bytes(
+    &bytes,
+    &Context {
+      root: Utf8PathBuf::from("/home/project/"),
+    },
+  )
+  .unwrap();
+  assert_eq!(
+    new_module.request,
+    "/home/project/loader.js!/home/project/src/index.js"
+  );
+  assert_eq!(
+    new_module.resource.as_deref(),
+    Some("/home/project/src/index.js")
+  );
+  // fields without AsPortable are not changed
+  assert_eq!(new_module.name, "/root/project/src/index.js");
+
+  // deserialize without root
+  let new_module: Module = from_bytes(&bytes, &()).unwrap();
+  assert_eq!(
+    new_module.request,
+    "<PROJECT_ROOT>/loader.js!<PROJECT_ROOT>/src/index.js"
+  );
+}
+
+#[test]
+fn test_to_portable() {
+  assert_eq!(
+    to_portable("/root/project/src/a.js", "/root/project/").as_deref(),
+    Some("<PROJECT_ROOT>/src/a.js")
+  );
+  assert_eq!(
+    to_portable("/root/project1/src/a.js", "/root/project"),
+    None
+  );
+  assert_eq!(
+    to_portable("/a/root/project/src/a.js", "/root/project"),
+    None
+  );
+  assert_eq!(to_portable("/home/project/a.js", "/root/project"), None);
+  // only the parts start with project root are replaced
+  assert_eq!(
+    to_portable(
+      "javascript/auto|/root/project/a.js|/a/root/project/b.js!/root/project",
+      "/root/project"
+    )
+    .as_deref(),
+    Some("javascript/auto|<PROJECT_ROOT>/a.js|/a/root/project/b.js!<PROJECT_ROOT>")
+  );
+}
+
+#[test]
+fn test_from_portable() {
+  assert_eq!(
+    from_portable(
+      "javascript/auto|<PROJECT_ROOT>/a.js!<PROJECT_ROOT>",
+      "/home/project/"
+    ),
+    "javascript/auto|/home/project/a.js!/home/project"
+  );
+  // placeholder not at the start of a path is kept
+  assert_eq!(
+    from_portable("/a/<PROJECT_ROOT>/b.js|<PROJECT_ROOT>1", "/home/project"),
+    "/a/<PROJECT_ROOT>/b.js|<PROJECT_ROOT>1"
+  );
+}
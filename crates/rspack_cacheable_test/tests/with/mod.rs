@@ -3,6 +3,7 @@ mod as_cacheable;
 mod as_inner;
 mod as_map;
 mod as_owned;
+mod as_portable;
 mod as_preset;
 mod as_ref_str;
 mod as_string;
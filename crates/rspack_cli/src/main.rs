@@ -0,0 +1,159 @@
+extern crate rspack_allocator;
+
+use std::{io::IsTerminal, ops::ControlFlow, process::ExitCode};
+
+use rspack::{builder::CompilerBuilder, config::Config};
+use rspack_core::{Compiler, ExtendedStatsOptions, Stats, StatsOptions, StatsPreset, WatchOptions};
+use rspack_error::{error, Result};
+use rspack_paths::{AssertUtf8, Utf8PathBuf};
+
+const USAGE: &str = "Usage: rspack [options]
+
+Options:
+  -c, --config <path>    config file in JSON or TOML, defaults to rspack.config.json or
+                         rspack.config.toml in the current directory
+  -w, --watch            rebuild when the files are changed
+  --stats <preset>       stats preset: none, errors-only, errors-warnings, summary, minimal,
+                         normal (default), detailed or verbose
+  --no-color             disable colors in the output
+  -h, --help             print this help message
+  -v, --version          print the version";
+
+const DEFAULT_CONFIGS: &[&str] = &["rspack.config.json", "rspack.config.toml"];
+
+/// Exit code of a build with compilation errors.
+const EXIT_COMPILATION_ERROR: u8 = 1;
+/// Exit code of invalid arguments, invalid configs and failed builds.
+const EXIT_INTERNAL_ERROR: u8 = 2;
+
+struct Args {
+  config: Option<Utf8PathBuf>,
+  watch: bool,
+  stats: StatsPreset,
+  colors: bool,
+}
+
+enum Command {
+  Build(Args),
+  Help,
+  Version,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command> {
+  let mut parsed = Args {
+    config: None,
+    watch: false,
+    stats: StatsPreset::Normal,
+    colors: std::io::stdout().is_terminal(),
+  };
+  let mut args = args.into_iter();
+  while let Some(arg) = args.next() {
+    let (name, mut value) = match arg.split_once('=') {
+      Some((name, value)) if name.starts_with("--") => (name.to_string(), Some(value.to_string())),
+      _ => (arg, None),
+    };
+    let mut value = |name: &str| {
+      value
+        .take()
+        .or_else(|| args.next())
+        .ok_or_else(|| error!("Missing value of {name}"))
+    };
+    match name.as_str() {
+      "-c" | "--config" => parsed.config = Some(value(&name)?.into()),
+      "-w" | "--watch" => parsed.watch = true,
+      "--stats" => parsed.stats = StatsPreset::try_from(value(&name)?.as_str())?,
+      "--no-color" => parsed.colors = false,
+      "-h" | "--help" => return Ok(Command::Help),
+      "-v" | "--version" => return Ok(Command::Version),
+      _ => return Err(error!("Unknown argument: {name}")),
+    }
+  }
+  Ok(Command::Build(parsed))
+}
+
+fn load_config(path: Option<Utf8PathBuf>) -> Result<Config> {
+  let cwd = std::env::current_dir()
+    .map_err(|e| error!("Failed to get the current directory: {e}"))?
+    .assert_utf8();
+  let path = match path {
+    // a relative config path is resolved against the current directory
+    Some(path) => cwd.join(path),
+    None => DEFAULT_CONFIGS
+      .iter()
+      .map(|name| cwd.join(name))
+      .find(|path| path.is_file())
+      .ok_or_else(|| {
+        error!(
+          "No config file found in {cwd}, expected one of {}",
+          DEFAULT_CONFIGS.join(", ")
+        )
+      })?,
+  };
+  Config::from_path(path)
+}
+
+fn print_stats(compiler: &Compiler, options: &ExtendedStatsOptions) -> Result<()> {
+  let output = Stats::new(&compiler.compilation).to_string(options)?;
+  if !output.is_empty() {
+    println!("{output}");
+  }
+  Ok(())
+}
+
+fn has_errors(compiler: &Compiler) -> bool {
+  compiler.compilation.get_errors().next().is_some()
+}
+
+async fn run(args: Args) -> Result<bool> {
+  let config = load_config(args.config)?;
+  let mut compiler = CompilerBuilder::try_from(config)?
+    .stats(StatsOptions {
+      colors: args.colors,
+    })
+    .build();
+  let stats_options = ExtendedStatsOptions::from_preset(args.stats, true);
+
+  if args.watch {
+    compiler
+      .watch(WatchOptions::default(), |compiler, result| {
+        let printed = result.and_then(|_| print_stats(compiler, &stats_options));
+        if let Err(e) = printed {
+          eprintln!("{e:?}");
+        }
+        ControlFlow::Continue(())
+      })
+      .await?;
+    return Ok(true);
+  }
+
+  compiler.build().await?;
+  print_stats(&compiler, &stats_options)?;
+  Ok(!has_errors(&compiler))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let args = match parse_args(std::env::args().skip(1)) {
+    Ok(Command::Build(args)) => args,
+    Ok(Command::Help) => {
+      println!("{USAGE}");
+      return ExitCode::SUCCESS;
+    }
+    Ok(Command::Version) => {
+      println!("{}", env!("CARGO_PKG_VERSION"));
+      return ExitCode::SUCCESS;
+    }
+    Err(e) => {
+      eprintln!("{e}\n\n{USAGE}");
+      return ExitCode::from(EXIT_INTERNAL_ERROR);
+    }
+  };
+  match run(args).await {
+    Ok(true) => ExitCode::SUCCESS,
+    Ok(false) => ExitCode::from(EXIT_COMPILATION_ERROR),
+    Err(e) => {
+      eprintln!("{e:?}");
+      ExitCode::from(EXIT_INTERNAL_ERROR)
+    }
+  }
+}
@@ -0,0 +1,77 @@
+use std::process::Command;
+
+use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+
+/// Copy the fixture to a temporary directory, as the builds emit into the context
+fn setup(name: &str) -> Utf8PathBuf {
+  let context = std::env::temp_dir()
+    .join("rspack_cli_test")
+    .join(name)
+    .assert_utf8();
+  let _ = std::fs::remove_dir_all(&context);
+  copy_dir(
+    &Utf8Path::new(env!("CARGO_MANIFEST_DIR"))
+      .join("tests/fixtures")
+      .join(name),
+    &context,
+  );
+  context
+}
+
+fn copy_dir(from: &Utf8Path, to: &Utf8Path) {
+  std::fs::create_dir_all(to).expect("should create the directory");
+  for entry in from.read_dir_utf8().expect("should read the directory") {
+    let entry = entry.expect("should read the directory entry");
+    let target = to.join(entry.file_name());
+    if entry.path().is_dir() {
+      copy_dir(entry.path(), &target);
+    } else {
+      std::fs::copy(entry.path(), &target).expect("should copy the file");
+    }
+  }
+}
+
+fn rspack(context: &Utf8PathBuf, args: &[&str]) -> (Option<i32>, String) {
+  let output = Command::new(env!("CARGO_BIN_EXE_rspack"))
+    .current_dir(context)
+    .args(args)
+    .output()
+    .expect("should run rspack");
+  (
+    output.status.code(),
+    String::from_utf8_lossy(&output.stdout).to_string(),
+  )
+}
+
+#[test]
+fn build() {
+  let context = setup("build");
+
+  let (code, stdout) = rspack(&context, &["--no-color"]);
+  assert_eq!(code, Some(0));
+  assert!(stdout.contains("asset main.js"));
+  assert!(stdout.contains(" compiled successfully in "));
+  assert!(context.join("dist/main.js").exists());
+}
+
+#[test]
+fn exit_codes() {
+  let context = setup("exit-codes");
+  let (code, _) = rspack(&context, &["--stats", "none"]);
+  assert_eq!(code, Some(1));
+
+  let (code, _) = rspack(&context, &["--config", "invalid.toml"]);
+  assert_eq!(code, Some(2));
+
+  let (code, _) = rspack(&context, &["--unknown"]);
+  assert_eq!(code, Some(2));
+}
+
+#[test]
+fn relative_config() {
+  let context = setup("relative-config");
+
+  let (code, _) = rspack(&context, &["-c", "config/rspack.config.toml"]);
+  assert_eq!(code, Some(0));
+  assert!(context.join("dist/main.js").exists());
+}
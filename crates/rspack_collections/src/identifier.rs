@@ -5,7 +5,7 @@ use std::{convert::From, fmt, ops::Deref};
 use dashmap::{DashMap, DashSet};
 use hashlink::{LinkedHashMap, LinkedHashSet};
 use indexmap::{IndexMap, IndexSet};
-use rspack_cacheable::{cacheable, with::AsPreset};
+use rspack_cacheable::{cacheable, with::AsPortable};
 use serde::Serialize;
 use ustr::Ustr;
 
@@ -32,7 +32,7 @@ pub type IdentifierLinkedSet = LinkedHashSet<Identifier, BuildHasherDefault<Iden
 
 #[cacheable(hashable)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
-pub struct Identifier(#[cacheable(with=AsPreset)] Ustr);
+pub struct Identifier(#[cacheable(with=AsPortable)] Ustr);
 
 impl Deref for Identifier {
   type Target = Ustr;
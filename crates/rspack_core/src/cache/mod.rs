@@ -4,11 +4,15 @@ pub mod persistent;
 
 use std::{fmt::Debug, sync::Arc};
 
+use rspack_collections::{IdentifierSet, UkeySet};
 use rspack_error::Result;
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 
 pub use self::{disable::DisableCache, memory::MemoryCache, persistent::PersistentCache};
-use crate::{make::MakeArtifact, Compilation, CompilerOptions, ExperimentCacheOptions};
+use crate::{
+  make::MakeArtifact, ChunkRenderArtifact, ChunkUkey, Compilation, CompilerOptions,
+  ExperimentCacheOptions,
+};
 
 /// Cache trait
 ///
@@ -37,6 +41,41 @@ pub trait Cache: Debug + Send + Sync {
   async fn after_make(&self, _make_artifact: &MakeArtifact) -> Result<()> {
     Ok(())
   }
+
+  /// Recovery code generation results into compilation and remove the
+  /// recovered modules from `modules`, the rest of modules will be generated.
+  async fn before_code_generation(
+    &self,
+    _compilation: &mut Compilation,
+    _modules: &mut IdentifierSet,
+  ) -> Result<()> {
+    Ok(())
+  }
+  async fn after_code_generation(
+    &self,
+    _compilation: &Compilation,
+    _modules: &IdentifierSet,
+  ) -> Result<()> {
+    Ok(())
+  }
+
+  /// Recovery render results into `artifact` and remove the recovered chunks
+  /// from `chunks`, the rest of chunks will be rendered.
+  async fn before_chunks_render(
+    &self,
+    _compilation: &Compilation,
+    _chunks: &mut UkeySet<ChunkUkey>,
+    _artifact: &mut ChunkRenderArtifact,
+  ) -> Result<()> {
+    Ok(())
+  }
+  async fn after_chunks_render(
+    &self,
+    _compilation: &Compilation,
+    _artifact: &ChunkRenderArtifact,
+  ) -> Result<()> {
+    Ok(())
+  }
 }
 
 pub fn new_cache(
@@ -1,7 +1,12 @@
 use std::{any::Any, sync::Arc};
 
-use rspack_cacheable::{cacheable, with::AsConverter, DeserializeError, SerializeError};
+use rspack_cacheable::{
+  cacheable,
+  with::{from_portable, to_portable, AsConverter},
+  DeserializeError, SerializeError,
+};
 use rspack_fs::ReadableFileSystem;
+use rspack_paths::Utf8Path;
 
 use crate::CompilerOptions;
 
@@ -11,6 +16,28 @@ pub struct CacheableContext {
   pub input_filesystem: Arc<dyn ReadableFileSystem>,
 }
 
+impl CacheableContext {
+  /// Convert a string which may contain the project root to storage key.
+  ///
+  /// The project root will be replaced with a placeholder, so the storage can
+  /// be shared between different project directories.
+  pub fn to_storage_key(&self, s: &str) -> Vec<u8> {
+    to_portable(s, self.options.context.as_str())
+      .map_or_else(|| s.as_bytes().to_vec(), String::into_bytes)
+  }
+
+  /// Convert the storage key generated by `to_storage_key` back to string.
+  pub fn from_storage_key(&self, key: &[u8]) -> String {
+    from_portable(&String::from_utf8_lossy(key), self.options.context.as_str()).into_owned()
+  }
+}
+
+impl rspack_cacheable::CacheableContext for CacheableContext {
+  fn project_root(&self) -> Option<&Utf8Path> {
+    Some(self.options.context.as_path())
+  }
+}
+
 #[cacheable]
 pub struct FromContext;
 
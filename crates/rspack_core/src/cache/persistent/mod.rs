@@ -3,10 +3,18 @@ mod occasion;
 pub mod snapshot;
 pub mod storage;
 mod version;
-use std::{hash::Hash, path::PathBuf, sync::Arc};
+use std::{
+  hash::Hash,
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+};
 
 pub use cacheable_context::{CacheableContext, FromContext};
-use occasion::MakeOccasion;
+use occasion::{ChunkRenderOccasion, CodeGenerateOccasion, MakeOccasion};
+use rspack_collections::{IdentifierSet, UkeySet};
 use rspack_error::Result;
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 use rspack_macros::rspack_version;
@@ -20,7 +28,7 @@ use self::{
 use super::Cache;
 use crate::{
   make::{MakeArtifact, MakeArtifactState},
-  Compilation, CompilerOptions,
+  ChunkRenderArtifact, ChunkUkey, Compilation, CompilerOptions,
 };
 
 #[derive(Debug, Clone)]
@@ -37,6 +45,14 @@ pub struct PersistentCache {
   storage: Arc<dyn Storage>,
   snapshot: Snapshot,
   make_occasion: MakeOccasion,
+  code_generate_occasion: CodeGenerateOccasion,
+  /// Code generation results only need to be recovered by the first compilation,
+  /// the rebuild will reuse the results of the previous compilation.
+  code_generate_recovered: AtomicBool,
+  chunk_render_occasion: ChunkRenderOccasion,
+  /// Same as `code_generate_recovered`, chunk render results only need to be
+  /// recovered by the first compilation.
+  chunk_render_recovered: AtomicBool,
   async_mode: bool,
 }
 
@@ -65,11 +81,23 @@ impl PersistentCache {
       options: compiler_options,
       input_filesystem: input_filesystem.clone(),
     });
-    let make_occasion = MakeOccasion::new(storage.clone(), context);
+    let snapshot = Snapshot::new(
+      option.snapshot.clone(),
+      input_filesystem,
+      storage.clone(),
+      Some(context.options.context.as_path().to_path_buf()),
+    );
+    let make_occasion = MakeOccasion::new(storage.clone(), context.clone());
+    let code_generate_occasion = CodeGenerateOccasion::new(storage.clone(), context);
+    let chunk_render_occasion = ChunkRenderOccasion::new(storage.clone());
     Self {
-      snapshot: Snapshot::new(option.snapshot.clone(), input_filesystem, storage.clone()),
+      snapshot,
       storage,
       make_occasion,
+      code_generate_occasion,
+      code_generate_recovered: AtomicBool::new(false),
+      chunk_render_occasion,
+      chunk_render_recovered: AtomicBool::new(false),
       async_mode,
     }
   }
@@ -146,4 +174,51 @@ impl Cache for PersistentCache {
     self.make_occasion.save(make_artifact);
     Ok(())
   }
+
+  async fn before_code_generation(
+    &self,
+    compilation: &mut Compilation,
+    modules: &mut IdentifierSet,
+  ) -> Result<()> {
+    if !self.code_generate_recovered.swap(true, Ordering::Relaxed) {
+      self
+        .code_generate_occasion
+        .recovery(compilation, modules)
+        .await?;
+    }
+    Ok(())
+  }
+
+  async fn after_code_generation(
+    &self,
+    compilation: &Compilation,
+    modules: &IdentifierSet,
+  ) -> Result<()> {
+    self.code_generate_occasion.save(compilation, modules);
+    Ok(())
+  }
+
+  async fn before_chunks_render(
+    &self,
+    compilation: &Compilation,
+    chunks: &mut UkeySet<ChunkUkey>,
+    artifact: &mut ChunkRenderArtifact,
+  ) -> Result<()> {
+    if !self.chunk_render_recovered.swap(true, Ordering::Relaxed) {
+      self
+        .chunk_render_occasion
+        .recovery(compilation, chunks, artifact)
+        .await?;
+    }
+    Ok(())
+  }
+
+  async fn after_chunks_render(
+    &self,
+    compilation: &Compilation,
+    artifact: &ChunkRenderArtifact,
+  ) -> Result<()> {
+    self.chunk_render_occasion.save(compilation, artifact);
+    Ok(())
+  }
 }
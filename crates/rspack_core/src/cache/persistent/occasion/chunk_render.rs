@@ -0,0 +1,194 @@
+use std::sync::{Arc, Mutex};
+
+use rspack_cacheable::{cacheable, from_bytes, to_bytes, with::AsPreset};
+use rspack_collections::{DatabaseItem, UkeySet};
+use rspack_error::Result;
+use rspack_sources::BoxSource;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::super::Storage;
+use crate::{
+  AssetInfo, Chunk, ChunkRenderArtifact, ChunkRenderResult, ChunkUkey, Compilation,
+  RenderManifestEntry,
+};
+
+const SCOPE: &str = "occasion_chunk_render";
+
+#[cacheable]
+struct Manifest {
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+  filename: String,
+  has_filename: bool,
+  info: AssetInfo,
+  auxiliary: bool,
+}
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  /// The chunk hash and content hashes which the manifests are rendered from
+  hash: String,
+  manifests: Vec<Manifest>,
+}
+
+/// Chunk render occasion
+///
+/// Save the render manifests of chunk by chunk id, and recovery them when the
+/// chunk hash and content hashes are not changed.
+#[derive(Debug)]
+pub struct ChunkRenderOccasion {
+  storage: Arc<dyn Storage>,
+  /// The keys in storage, used to remove the nodes of removed or renamed chunks
+  saved_keys: Mutex<HashSet<String>>,
+}
+
+impl ChunkRenderOccasion {
+  pub fn new(storage: Arc<dyn Storage>) -> Self {
+    Self {
+      storage,
+      saved_keys: Default::default(),
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkRender::save", skip_all)]
+  pub fn save(&self, compilation: &Compilation, artifact: &ChunkRenderArtifact) {
+    let mut saved_keys = self.saved_keys.lock().expect("should lock saved keys");
+    let current_keys = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| chunk_key(compilation, chunk))
+      .collect::<HashSet<_>>();
+    saved_keys.retain(|key| {
+      if current_keys.contains(key) {
+        return true;
+      }
+      self.storage.remove(SCOPE, key.as_bytes());
+      false
+    });
+
+    let mut saved = 0;
+    for (chunk_ukey, result) in artifact {
+      // diagnostics do not support cache
+      if !result.diagnostics.is_empty() {
+        continue;
+      }
+      // the filename may contain full hash which is not a part of chunk hash
+      if result
+        .manifests
+        .iter()
+        .any(|manifest| !manifest.info.full_hash.is_empty())
+      {
+        continue;
+      }
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      let (Some(key), Some(hash)) = (
+        chunk_key(compilation, chunk),
+        chunk_hash(compilation, chunk),
+      ) else {
+        continue;
+      };
+      let node = Node {
+        hash,
+        manifests: result
+          .manifests
+          .iter()
+          .map(|manifest| Manifest {
+            source: manifest.source.clone(),
+            filename: manifest.filename.clone(),
+            has_filename: manifest.has_filename,
+            info: manifest.info.clone(),
+            auxiliary: manifest.auxiliary,
+          })
+          .collect(),
+      };
+      self.storage.set(
+        SCOPE,
+        key.clone().into_bytes(),
+        to_bytes(&node, &()).expect("should to bytes success"),
+      );
+      saved_keys.insert(key);
+      saved += 1;
+    }
+    tracing::info!("save info {}/{}", saved, artifact.len());
+  }
+
+  /// Recovery render results of chunks
+  ///
+  /// The recovered chunks will be removed from `chunks`.
+  #[tracing::instrument(name = "Cache::Occasion::ChunkRender::recovery", skip_all)]
+  pub async fn recovery(
+    &self,
+    compilation: &Compilation,
+    chunks: &mut UkeySet<ChunkUkey>,
+    artifact: &mut ChunkRenderArtifact,
+  ) -> Result<()> {
+    let key_to_chunk = chunks
+      .iter()
+      .filter_map(|chunk_ukey| {
+        let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+        chunk_key(compilation, chunk).map(|key| (key, chunk))
+      })
+      .collect::<HashMap<_, _>>();
+    let mut recovered = 0;
+    let mut saved_keys = vec![];
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let key = String::from_utf8_lossy(&key).into_owned();
+      let chunk = key_to_chunk.get(&key).copied();
+      saved_keys.push(key);
+      let Some(chunk) = chunk else {
+        continue;
+      };
+      let node: Node = from_bytes(&value, &()).expect("should from bytes success");
+      if chunk_hash(compilation, chunk).as_ref() != Some(&node.hash) {
+        continue;
+      }
+      let manifests = node
+        .manifests
+        .into_iter()
+        .map(|manifest| RenderManifestEntry {
+          source: manifest.source,
+          filename: manifest.filename,
+          has_filename: manifest.has_filename,
+          info: manifest.info,
+          auxiliary: manifest.auxiliary,
+        })
+        .collect();
+      chunks.remove(&chunk.ukey());
+      artifact.insert(
+        chunk.ukey(),
+        ChunkRenderResult {
+          manifests,
+          diagnostics: vec![],
+        },
+      );
+      recovered += 1;
+    }
+    tracing::info!("recovery {} chunk", recovered);
+    self
+      .saved_keys
+      .lock()
+      .expect("should lock saved keys")
+      .extend(saved_keys);
+    Ok(())
+  }
+}
+
+/// The storage key of chunk, chunks without id will not be cached.
+fn chunk_key(compilation: &Compilation, chunk: &Chunk) -> Option<String> {
+  chunk
+    .id(&compilation.chunk_ids_artifact)
+    .map(|id| id.to_string())
+}
+
+/// Combine chunk hash and content hashes of all source types.
+fn chunk_hash(compilation: &Compilation, chunk: &Chunk) -> Option<String> {
+  let hash = chunk.hash(&compilation.chunk_hashes_artifact)?;
+  let mut content_hashes = chunk
+    .content_hash(&compilation.chunk_hashes_artifact)?
+    .iter()
+    .map(|(source_type, hash)| format!("{source_type}:{}", hash.encoded()))
+    .collect::<Vec<_>>();
+  content_hashes.sort_unstable();
+  Some(format!("{}|{}", hash.encoded(), content_hashes.join("|")))
+}
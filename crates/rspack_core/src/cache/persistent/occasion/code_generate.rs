@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{
+  cacheable, from_bytes, to_bytes,
+  with::{AsCacheable, AsPreset, AsTuple2, AsVec},
+};
+use rspack_collections::IdentifierSet;
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rspack_sources::BoxSource;
+
+use super::super::{cacheable_context::CacheableContext, Storage};
+use crate::{
+  ChunkGraph, CodeGenerationResult, Compilation, ModuleIdentifier, RuntimeGlobals, RuntimeSpec,
+  SourceType,
+};
+
+const SCOPE: &str = "occasion_code_generate";
+
+/// A code generation result shared by all runtimes with the same module hash
+#[cacheable]
+struct Item {
+  /// The module hash which the result is generated from
+  hash: RspackHashDigest,
+  #[cacheable(with=AsVec<AsTuple2<AsCacheable, AsPreset>>)]
+  sources: Vec<(SourceType, BoxSource)>,
+  runtime_requirements: RuntimeGlobals,
+  result_hash: Option<RspackHashDigest>,
+}
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  items: Vec<Item>,
+}
+
+/// Code generation occasion
+///
+/// Save the code generation results to storage and recovery them when the
+/// module hash of target runtime is not changed.
+#[derive(Debug)]
+pub struct CodeGenerateOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+}
+
+impl CodeGenerateOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self { storage, context }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::CodeGenerate::save", skip_all)]
+  pub fn save(&self, compilation: &Compilation, modules: &IdentifierSet) {
+    let nodes = modules
+      .par_iter()
+      .filter_map(|identifier| {
+        let node = create_node(compilation, identifier)?;
+        Some((
+          self.context.to_storage_key(identifier),
+          to_bytes(&node, &*self.context).expect("should to bytes success"),
+        ))
+      })
+      .collect::<Vec<_>>();
+
+    tracing::info!("save info {}/{}", nodes.len(), modules.len());
+
+    for (id, bytes) in nodes {
+      self.storage.set(SCOPE, id, bytes);
+    }
+  }
+
+  /// Recovery code generation results into compilation
+  ///
+  /// The recovered modules will be removed from `modules`, the result of module
+  /// will be dropped if the module has been removed from module graph.
+  #[tracing::instrument(name = "Cache::Occasion::CodeGenerate::recovery", skip_all)]
+  pub async fn recovery(
+    &self,
+    compilation: &mut Compilation,
+    modules: &mut IdentifierSet,
+  ) -> Result<()> {
+    let data = self.storage.load(SCOPE).await?;
+    let mut removed_keys = vec![];
+    let mut recovered = vec![];
+    {
+      let module_graph = compilation.get_module_graph();
+      for (key, value) in data {
+        let identifier = ModuleIdentifier::from(self.context.from_storage_key(&key));
+        if module_graph.module_by_identifier(&identifier).is_none() {
+          removed_keys.push(key);
+          continue;
+        }
+        if !modules.contains(&identifier) {
+          continue;
+        }
+        let node: Node = from_bytes(&value, &*self.context).expect("should from bytes success");
+        if let Some(results) = validate_node(compilation, &identifier, node) {
+          recovered.push((identifier, results));
+        }
+      }
+    }
+
+    tracing::info!("recovery {} module", recovered.len());
+    for key in removed_keys {
+      self.storage.remove(SCOPE, &key);
+    }
+    for (identifier, results) in recovered {
+      for (result, runtimes) in results {
+        compilation
+          .code_generation_results
+          .insert(identifier, result, runtimes);
+      }
+      modules.remove(&identifier);
+    }
+    Ok(())
+  }
+}
+
+/// Group the code generation results of module by module hash.
+///
+/// Return None if the result contains fields that can not be cached.
+fn create_node(compilation: &Compilation, identifier: &ModuleIdentifier) -> Option<Node> {
+  let runtimes = compilation
+    .chunk_graph
+    .get_module_runtimes(*identifier, &compilation.chunk_by_ukey);
+  let mut items: Vec<Item> = vec![];
+  for runtime in runtimes.into_values() {
+    let hash = ChunkGraph::get_module_hash(compilation, *identifier, &runtime)?;
+    if items.iter().any(|item| &item.hash == hash) {
+      continue;
+    }
+    let result = compilation
+      .code_generation_results
+      .get(identifier, Some(&runtime));
+    // data, chunk_init_fragments and concatenation_scope do not support cache,
+    // and a result without sources is usually created by a failed generation.
+    if result.inner.is_empty()
+      || !result.data.is_empty()
+      || !result.chunk_init_fragments.is_empty()
+      || result.concatenation_scope.is_some()
+    {
+      return None;
+    }
+    items.push(Item {
+      hash: hash.clone(),
+      sources: result
+        .inner
+        .iter()
+        .map(|(source_type, source)| (*source_type, source.clone()))
+        .collect(),
+      runtime_requirements: result.runtime_requirements,
+      result_hash: result.hash.clone(),
+    });
+  }
+  (!items.is_empty()).then_some(Node { items })
+}
+
+/// Check whether the saved results cover all of current runtimes with the same module hash.
+fn validate_node(
+  compilation: &Compilation,
+  identifier: &ModuleIdentifier,
+  node: Node,
+) -> Option<Vec<(CodeGenerationResult, Vec<RuntimeSpec>)>> {
+  let runtimes = compilation
+    .chunk_graph
+    .get_module_runtimes(*identifier, &compilation.chunk_by_ukey);
+  if runtimes.is_empty() {
+    return None;
+  }
+  let mut results: Vec<(RspackHashDigest, CodeGenerationResult, Vec<RuntimeSpec>)> = vec![];
+  for runtime in runtimes.into_values() {
+    let hash = ChunkGraph::get_module_hash(compilation, *identifier, &runtime)?;
+    if let Some((_, _, runtimes)) = results.iter_mut().find(|(h, _, _)| h == hash) {
+      runtimes.push(runtime);
+      continue;
+    }
+    let item = node.items.iter().find(|item| &item.hash == hash)?;
+    let mut result = CodeGenerationResult::default();
+    for (source_type, source) in &item.sources {
+      result.add(*source_type, source.clone());
+    }
+    result.runtime_requirements = item.runtime_requirements;
+    result.hash = item.result_hash.clone();
+    results.push((hash.clone(), result, vec![runtime]));
+  }
+  Some(
+    results
+      .into_iter()
+      .map(|(_, result, runtimes)| (result, runtimes))
+      .collect(),
+  )
+}
@@ -8,7 +8,7 @@ use rustc_hash::FxHashSet as HashSet;
 
 use super::super::{cacheable_context::CacheableContext, Storage};
 use crate::{
-  make::{MakeArtifact, MakeArtifactState},
+  make::{LazyBarrels, MakeArtifact, MakeArtifactState},
   FactorizeInfo, FileCounter,
 };
 
@@ -41,6 +41,7 @@ impl MakeOccasion {
       has_module_graph_change: _,
       make_failed_dependencies: _,
       make_failed_module: _,
+      lazy_barrels: _,
     } = artifact;
 
     module_graph::save_module_graph(
@@ -101,6 +102,29 @@ impl MakeOccasion {
         }
       }
     }
+    // recovery lazy_barrels, the lazy dependencies of barrels are not added to the module
+    let mut lazy_barrels = LazyBarrels::default();
+    for (module_identifier, mgm) in mg.module_graph_modules() {
+      let module = mg
+        .module_by_identifier(&module_identifier)
+        .expect("should have module");
+      let top_level_dependencies = mgm
+        .all_dependencies
+        .iter()
+        .filter(|dep_id| mg.get_parent_block(dep_id).is_none())
+        .collect::<Vec<_>>();
+      if top_level_dependencies.len() == module.get_dependencies().len() {
+        continue;
+      }
+      if let Some(pending) = LazyBarrels::collect_dependencies(
+        top_level_dependencies
+          .into_iter()
+          .filter_map(|dep_id| mg.dependency_by_id(dep_id)),
+      ) {
+        lazy_barrels.recover_barrel(module_identifier, pending, module.get_dependencies());
+      }
+    }
+    artifact.lazy_barrels = lazy_barrels;
     artifact.make_failed_dependencies = make_failed_dependencies;
     artifact.file_dependencies = file_dep;
     artifact.context_dependencies = context_dep;
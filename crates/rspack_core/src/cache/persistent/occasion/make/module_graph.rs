@@ -59,7 +59,7 @@ pub fn save_module_graph(
 ) {
   let mg = ModuleGraph::new(vec![partial], None);
   for identifier in revoked_modules {
-    storage.remove(SCOPE, identifier.as_bytes());
+    storage.remove(SCOPE, &context.to_storage_key(identifier));
   }
 
   // save module_graph
@@ -103,7 +103,7 @@ pub fn save_module_graph(
         blocks,
       };
       match to_bytes(&node, context) {
-        Ok(bytes) => Some((identifier.as_bytes().to_vec(), bytes)),
+        Ok(bytes) => Some((context.to_storage_key(identifier), bytes)),
         Err(err) => {
           if matches!(err, SerializeError::UnsupportedField) {
             tracing::warn!("to bytes failed {:?}", err);
@@ -3,14 +3,17 @@ mod strategy;
 
 use std::{path::Path, sync::Arc};
 
-use rspack_cacheable::{from_bytes, to_bytes};
+use rspack_cacheable::{
+  from_bytes, to_bytes,
+  with::{from_portable, to_portable},
+};
 use rspack_error::Result;
 use rspack_fs::ReadableFileSystem;
 use rspack_futures::FuturesResults;
-use rspack_paths::{ArcPath, AssertUtf8};
+use rspack_paths::{ArcPath, AssertUtf8, Utf8PathBuf};
 use rustc_hash::FxHashSet as HashSet;
 
-pub use self::option::{PathMatcher, SnapshotOptions};
+pub use self::option::{FileStrategy, PathMatcher, SnapshotOptions};
 use self::strategy::{Strategy, StrategyHelper, ValidateResult};
 use super::storage::Storage;
 use crate::FutureConsumer;
@@ -26,6 +29,9 @@ pub struct Snapshot {
   options: SnapshotOptions,
   fs: Arc<dyn ReadableFileSystem>,
   storage: Arc<dyn Storage>,
+  /// The paths in project root will be saved as relative paths,
+  /// so the snapshot can be reused after the project is moved.
+  project_root: Option<Utf8PathBuf>,
 }
 
 impl Snapshot {
@@ -33,11 +39,30 @@ impl Snapshot {
     options: SnapshotOptions,
     fs: Arc<dyn ReadableFileSystem>,
     storage: Arc<dyn Storage>,
+    project_root: Option<Utf8PathBuf>,
   ) -> Self {
     Self {
       options,
       fs,
       storage,
+      project_root,
+    }
+  }
+
+  fn path_to_key(&self, path: &Path) -> Vec<u8> {
+    let path = path.assert_utf8();
+    self
+      .project_root
+      .as_ref()
+      .and_then(|root| to_portable(path.as_str(), root.as_str()))
+      .map_or_else(|| path.as_str().as_bytes().to_vec(), String::into_bytes)
+  }
+
+  fn key_to_path(&self, key: &[u8]) -> ArcPath {
+    let key = String::from_utf8_lossy(key);
+    match &self.project_root {
+      Some(root) => Path::new(&*from_portable(&key, root.as_str())).into(),
+      None => Path::new(&*key).into(),
     }
   }
 
@@ -62,27 +87,41 @@ impl Snapshot {
           if let Some(v) = helper.package_version(path).await {
             self.storage.set(
               SCOPE,
-              path.as_os_str().as_encoded_bytes().to_vec(),
+              self.path_to_key(path),
               to_bytes::<_, ()>(&v, &()).expect("should to bytes success"),
             );
             return;
           }
         }
-        // compiler time
+        let strategy = match self.options.file_strategy() {
+          FileStrategy::CompileTime => None,
+          FileStrategy::ContentHash => helper.content_hash(path).await,
+          FileStrategy::CompileTimeAndContentHash => {
+            helper.compile_time_and_content_hash(path).await
+          }
+        };
+        // compiler time, also used as fallback when the path can not be read, e.g. directory
         self.storage.set(
           SCOPE,
-          path.as_os_str().as_encoded_bytes().to_vec(),
-          to_bytes::<_, ()>(&default_strategy, &()).expect("should to bytes success"),
+          self.path_to_key(path),
+          to_bytes::<_, ()>(strategy.as_ref().unwrap_or(&default_strategy), &())
+            .expect("should to bytes success"),
         );
       })
       .collect::<FuturesResults<_>>();
   }
 
+  fn update_strategy(&self, path: &Path, strategy: &Strategy) {
+    self.storage.set(
+      SCOPE,
+      self.path_to_key(path),
+      to_bytes::<_, ()>(strategy, &()).expect("should to bytes success"),
+    );
+  }
+
   pub fn remove(&self, paths: impl Iterator<Item = &Path>) {
     for item in paths {
-      self
-        .storage
-        .remove(SCOPE, item.as_os_str().as_encoded_bytes())
+      self.storage.remove(SCOPE, &self.path_to_key(item))
     }
   }
 
@@ -99,8 +138,8 @@ impl Snapshot {
       .into_iter()
       .map(|(key, value)| {
         let helper = helper.clone();
+        let path = self.key_to_path(&key);
         async move {
-          let path: ArcPath = Path::new(&*String::from_utf8_lossy(&key)).into();
           let strategy: Strategy =
             from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
           let validate = helper.validate(&path, &strategy).await;
@@ -114,22 +153,68 @@ impl Snapshot {
         ValidateResult::Deleted => {
           deleted_path.insert(path);
         }
+        ValidateResult::Touched(strategy) => self.update_strategy(&path, &strategy),
         ValidateResult::NoChanged => {}
       })
       .await;
 
     Ok((modified_path, deleted_path))
   }
+
+  /// Check the given paths through the saved strategies and return the modified paths.
+  ///
+  /// The paths which are not in snapshot are considered as modified.
+  #[tracing::instrument("Cache::Snapshot::filter_modified_paths", skip_all)]
+  pub async fn filter_modified_paths(
+    &self,
+    paths: impl Iterator<Item = ArcPath> + Send,
+  ) -> Result<HashSet<ArcPath>> {
+    let mut modified_path = HashSet::default();
+    let helper = Arc::new(StrategyHelper::new(self.fs.clone()));
+    // only look up the given paths instead of loading all of strategies
+    let mut strategies = vec![];
+    for path in paths {
+      let value = self.storage.get(SCOPE, &self.path_to_key(&path)).await?;
+      strategies.push((path, value));
+    }
+
+    strategies
+      .into_iter()
+      .map(|(path, value)| {
+        let helper = helper.clone();
+        async move {
+          let Some(value) = value else {
+            return (path, ValidateResult::Modified);
+          };
+          let strategy: Strategy =
+            from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
+          let validate = helper.validate(&path, &strategy).await;
+          (path, validate)
+        }
+      })
+      .fut_consume(|(path, validate)| match validate {
+        ValidateResult::NoChanged => {}
+        ValidateResult::Touched(strategy) => self.update_strategy(&path, &strategy),
+        ValidateResult::Modified | ValidateResult::Deleted => {
+          modified_path.insert(path);
+        }
+      })
+      .await;
+
+    Ok(modified_path)
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use std::sync::Arc;
 
-  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+  use rspack_cacheable::from_bytes;
+  use rspack_fs::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+  use rspack_paths::ArcPath;
 
-  use super::super::storage::MemoryStorage;
-  use super::{PathMatcher, Snapshot, SnapshotOptions};
+  use super::super::storage::{MemoryStorage, Storage};
+  use super::{FileStrategy, PathMatcher, Snapshot, SnapshotOptions, Strategy, SCOPE};
 
   macro_rules! p {
     ($tt:tt) => {
@@ -174,7 +259,7 @@ mod tests {
       .await
       .unwrap();
 
-    let snapshot = Snapshot::new(options, fs.clone(), storage);
+    let snapshot = Snapshot::new(options, fs.clone(), storage, None);
 
     snapshot
       .add(
@@ -220,4 +305,109 @@ mod tests {
     assert!(modified_paths.contains(p!("/node_modules/project/file1")));
     assert!(modified_paths.contains(p!("/node_modules/lib/file1")));
   }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_snapshot_file_strategy_work() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let options = SnapshotOptions::new(vec![], vec![], vec![])
+      .with_file_strategy(FileStrategy::CompileTimeAndContentHash);
+
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abc".as_bytes()).await.unwrap();
+
+    let snapshot = Snapshot::new(options, fs.clone(), storage.clone(), None);
+    snapshot.add([p!("/file1"), p!("/file2")].into_iter()).await;
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abcd".as_bytes()).await.unwrap();
+
+    let (modified_paths, deleted_paths) = snapshot.calc_modified_paths().await.unwrap();
+    assert!(deleted_paths.is_empty());
+    assert!(!modified_paths.contains(p!("/file1")));
+    assert!(modified_paths.contains(p!("/file2")));
+
+    // the compile time of touched file is refreshed, so its content will not be hashed again
+    let (_, value) = storage
+      .load(SCOPE)
+      .await
+      .unwrap()
+      .into_iter()
+      .find(|(key, _)| key.as_slice() == b"/file1")
+      .unwrap();
+    let Strategy::CompileTimeAndContentHash(compile_time, _) =
+      from_bytes::<Strategy, ()>(&value, &()).unwrap()
+    else {
+      panic!("should be compile time and content hash strategy");
+    };
+    assert!(compile_time >= fs.metadata("/file1".into()).unwrap().mtime_ms);
+  }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_snapshot_filter_modified_paths() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let options =
+      SnapshotOptions::new(vec![], vec![], vec![]).with_file_strategy(FileStrategy::ContentHash);
+
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file3".into(), "abc".as_bytes()).await.unwrap();
+
+    let snapshot = Snapshot::new(options, fs.clone(), storage, None);
+    snapshot.add([p!("/file1"), p!("/file2")].into_iter()).await;
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abcd".as_bytes()).await.unwrap();
+
+    let paths = [p!("/file1"), p!("/file2"), p!("/file3")].map(ArcPath::from);
+    let modified_paths = snapshot
+      .filter_modified_paths(paths.into_iter())
+      .await
+      .unwrap();
+    assert!(!modified_paths.contains(p!("/file1")));
+    assert!(modified_paths.contains(p!("/file2")));
+    // not in snapshot
+    assert!(modified_paths.contains(p!("/file3")));
+  }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_snapshot_relocatable() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+
+    fs.create_dir_all("/old/project".into()).await.unwrap();
+    fs.create_dir_all("/new/project".into()).await.unwrap();
+    fs.write("/old/project/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/old/project/file2".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/new/project/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let snapshot = Snapshot::new(
+      SnapshotOptions::default(),
+      fs.clone(),
+      storage.clone(),
+      Some("/old/project".into()),
+    );
+    snapshot
+      .add([p!("/old/project/file1"), p!("/old/project/file2")].into_iter())
+      .await;
+
+    let snapshot = Snapshot::new(
+      SnapshotOptions::default(),
+      fs.clone(),
+      storage,
+      Some("/new/project".into()),
+    );
+    let (modified_paths, deleted_paths) = snapshot.calc_modified_paths().await.unwrap();
+    assert!(modified_paths.is_empty());
+    assert_eq!(deleted_paths.len(), 1);
+    assert!(deleted_paths.contains(p!("/new/project/file2")));
+  }
 }
@@ -16,6 +16,19 @@ impl PathMatcher {
   }
 }
 
+/// The strategy to check files which are not in managed paths
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FileStrategy {
+  /// Compare the file update time with the compile time
+  #[default]
+  CompileTime,
+  /// Compare the hash of file content
+  ContentHash,
+  /// Compare the file update time with the compile time first, and compare
+  /// the hash of file content only when the file has been updated
+  CompileTimeAndContentHash,
+}
+
 /// Snapshot options
 #[derive(Debug, Default, Clone)]
 pub struct SnapshotOptions {
@@ -26,6 +39,8 @@ pub struct SnapshotOptions {
   unmanaged_paths: Vec<PathMatcher>,
   /// managed_paths, snapshot will use lib version strategy
   managed_paths: Vec<PathMatcher>,
+  /// the strategy used by files which are not in managed paths
+  file_strategy: FileStrategy,
 }
 
 impl SnapshotOptions {
@@ -38,9 +53,19 @@ impl SnapshotOptions {
       immutable_paths,
       unmanaged_paths,
       managed_paths,
+      file_strategy: FileStrategy::default(),
     }
   }
 
+  pub fn with_file_strategy(mut self, file_strategy: FileStrategy) -> Self {
+    self.file_strategy = file_strategy;
+    self
+  }
+
+  pub fn file_strategy(&self) -> FileStrategy {
+    self.file_strategy
+  }
+
   pub fn is_immutable_path(&self, path_str: &str) -> bool {
     for item in &self.immutable_paths {
       if item.try_match(path_str) {
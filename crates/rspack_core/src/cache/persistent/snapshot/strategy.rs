@@ -8,6 +8,7 @@ use dashmap::DashMap;
 use rspack_cacheable::cacheable;
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, AssertUtf8};
+use xxhash_rust::xxh64::xxh64;
 
 /// Snapshot check strategy
 #[cacheable]
@@ -23,6 +24,17 @@ pub enum Strategy {
   ///
   /// This strategy will compare the compile time and the file update time
   CompileTime(u64),
+
+  /// Check by content hash
+  ///
+  /// This strategy will compare the xxhash64 of the file content
+  ContentHash(u64),
+
+  /// Check by compile time and content hash
+  ///
+  /// This strategy will compare the compile time and the file update time first,
+  /// the content hash will only be compared when the file has been updated.
+  CompileTimeAndContentHash(u64, u64),
 }
 
 /// Validate Result
@@ -34,6 +46,9 @@ pub enum ValidateResult {
   Modified,
   /// The target file has no changed
   NoChanged,
+  /// The target file has been touched without changing the content,
+  /// the new strategy should be saved to skip the content check next time
+  Touched(Strategy),
 }
 
 pub struct StrategyHelper {
@@ -58,6 +73,12 @@ impl StrategyHelper {
     }
   }
 
+  /// get path file content hash
+  async fn content_hash_value(&self, path: &Path) -> Option<u64> {
+    let content = self.fs.async_read(path.assert_utf8()).await.ok()?;
+    Some(xxh64(&content, 0))
+  }
+
   /// get path file version in package.json
   #[async_recursion::async_recursion]
   async fn package_version_with_cache(&self, path: &Path) -> Option<String> {
@@ -90,13 +111,31 @@ impl StrategyHelper {
     res
   }
 
-  /// get current time as compile time strategy
-  pub fn compile_time() -> Strategy {
-    let now = SystemTime::now()
+  /// get current time in milliseconds
+  pub fn compile_time_now() -> u64 {
+    SystemTime::now()
       .duration_since(UNIX_EPOCH)
       .expect("get current time failed")
-      .as_millis() as u64;
-    Strategy::CompileTime(now)
+      .as_millis() as u64
+  }
+  /// get current time as compile time strategy
+  pub fn compile_time() -> Strategy {
+    Strategy::CompileTime(Self::compile_time_now())
+  }
+  /// get path file content hash strategy
+  pub async fn content_hash(&self, path: &Path) -> Option<Strategy> {
+    self
+      .content_hash_value(path)
+      .await
+      .map(Strategy::ContentHash)
+  }
+  /// get path file compile time and content hash strategy
+  pub async fn compile_time_and_content_hash(&self, path: &Path) -> Option<Strategy> {
+    let compile_time = Self::compile_time_now();
+    self
+      .content_hash_value(path)
+      .await
+      .map(|hash| Strategy::CompileTimeAndContentHash(compile_time, hash))
   }
   /// get path file package version strategy
   pub async fn package_version(&self, path: &Path) -> Option<Strategy> {
@@ -131,6 +170,27 @@ impl StrategyHelper {
           ValidateResult::Deleted
         }
       }
+      Strategy::ContentHash(hash) => match self.content_hash_value(path).await {
+        Some(ref cur_hash) if cur_hash == hash => ValidateResult::NoChanged,
+        Some(_) => ValidateResult::Modified,
+        None => ValidateResult::Deleted,
+      },
+      Strategy::CompileTimeAndContentHash(compile_time, hash) => {
+        let Some(ref modified_time) = self.modified_time(path).await else {
+          return ValidateResult::Deleted;
+        };
+        if modified_time <= compile_time {
+          return ValidateResult::NoChanged;
+        }
+        if self.content_hash_value(path).await.as_ref() == Some(hash) {
+          ValidateResult::Touched(Strategy::CompileTimeAndContentHash(
+            Self::compile_time_now(),
+            *hash,
+          ))
+        } else {
+          ValidateResult::Modified
+        }
+      }
     }
   }
 }
@@ -163,13 +223,9 @@ mod tests {
     fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
 
     // compile_time
-    let Strategy::CompileTime(time1) = StrategyHelper::compile_time() else {
-      unreachable!()
-    };
+    let time1 = StrategyHelper::compile_time_now();
     std::thread::sleep(std::time::Duration::from_millis(100));
-    let Strategy::CompileTime(time2) = StrategyHelper::compile_time() else {
-      unreachable!()
-    };
+    let time2 = StrategyHelper::compile_time_now();
     assert!(time1 < time2);
 
     let helper = StrategyHelper::new(fs.clone());
@@ -271,4 +327,79 @@ mod tests {
       ValidateResult::Deleted
     ));
   }
+
+  #[tokio::test]
+  async fn should_content_hash_strategy_works() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abc".as_bytes()).await.unwrap();
+    let helper = StrategyHelper::new(fs.clone());
+
+    // content_hash
+    let hash = helper.content_hash(Path::new("/file1")).await.unwrap();
+    assert_eq!(
+      hash,
+      helper.content_hash(Path::new("/file2")).await.unwrap()
+    );
+    assert!(helper.content_hash(Path::new("/file3")).await.is_none());
+
+    // compile_time_and_content_hash
+    let time_and_hash = helper
+      .compile_time_and_content_hash(Path::new("/file1"))
+      .await
+      .unwrap();
+    assert!(matches!(
+      time_and_hash,
+      Strategy::CompileTimeAndContentHash(_, _)
+    ));
+    assert!(helper
+      .compile_time_and_content_hash(Path::new("/file3"))
+      .await
+      .is_none());
+
+    // validate
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &hash).await,
+      ValidateResult::NoChanged
+    ));
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &time_and_hash).await,
+      ValidateResult::NoChanged
+    ));
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    // touch file without changing content
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &hash).await,
+      ValidateResult::NoChanged
+    ));
+    let ValidateResult::Touched(refreshed) =
+      helper.validate(Path::new("/file1"), &time_and_hash).await
+    else {
+      panic!("touched file should refresh the compile time");
+    };
+    // the refreshed strategy skips the content check
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &refreshed).await,
+      ValidateResult::NoChanged
+    ));
+    fs.write("/file1".into(), "abcd".as_bytes()).await.unwrap();
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &hash).await,
+      ValidateResult::Modified
+    ));
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &time_and_hash).await,
+      ValidateResult::Modified
+    ));
+    assert!(matches!(
+      helper.validate(Path::new("/file3"), &hash).await,
+      ValidateResult::Deleted
+    ));
+    assert!(matches!(
+      helper.validate(Path::new("/file3"), &time_and_hash).await,
+      ValidateResult::Deleted
+    ));
+  }
 }
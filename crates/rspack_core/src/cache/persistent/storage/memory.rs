@@ -6,7 +6,8 @@ use tokio::sync::oneshot::{channel, Receiver};
 
 /// Memory Storage
 ///
-/// This storage is used to write unit test cases.
+/// This storage is used to write unit test cases, and to save the snapshot of
+/// file dependencies in watch mode.
 /// Rspack will reuse previous compilation as memory cache.
 #[derive(Debug, Default)]
 pub struct MemoryStorage {
@@ -28,6 +29,17 @@ impl Storage for MemoryStorage {
       Ok(vec![])
     }
   }
+  async fn get(&self, scope: &'static str, key: &[u8]) -> Result<Option<Arc<Vec<u8>>>> {
+    Ok(
+      self
+        .inner
+        .lock()
+        .expect("should get lock")
+        .get(scope)
+        .and_then(|map| map.get(key))
+        .map(|value| Arc::new(value.clone())),
+    )
+  }
   fn set(&self, scope: &str, key: Vec<u8>, value: Vec<u8>) {
     let mut map = self.inner.lock().expect("should get lock");
     let inner = map.entry(String::from(scope)).or_default();
@@ -67,6 +79,12 @@ mod tests {
       }
     }
 
+    assert_eq!(
+      storage.get(scope, "a".as_bytes()).await.unwrap().as_deref(),
+      Some(&"abc".as_bytes().to_vec())
+    );
+    assert!(storage.get(scope, "c".as_bytes()).await.unwrap().is_none());
+
     storage.remove(scope, "b".as_bytes());
     let arr = storage.load(scope).await.unwrap();
     assert_eq!(arr.len(), 1);
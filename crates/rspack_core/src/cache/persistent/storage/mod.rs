@@ -5,16 +5,101 @@ use std::{path::PathBuf, sync::Arc};
 
 pub use memory::MemoryStorage;
 use rspack_fs::IntermediateFileSystem;
-pub use rspack_storage::Storage;
-use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
+use rspack_storage::{
+  BlobFileSystem, BridgeFileSystem, PackStorage, PackStorageLimits, PackStorageOptions,
+};
+pub use rspack_storage::{BlobStore, LocalBlobStore, Storage};
+
+/// Options of packs in storage
+///
+/// Larger packs need fewer file operations for huge projects, and smaller packs
+/// reduce the size to rewrite when a few items are changed.
+#[derive(Debug, Clone)]
+pub struct StoragePackOptions {
+  /// The count of buckets in a scope, it is the max count when `adaptive_bucket` is enabled
+  pub bucket_size: usize,
+  /// Choose the bucket count of each scope by its item count
+  pub adaptive_bucket: bool,
+  /// The max size of a pack in bytes
+  pub pack_size: usize,
+  /// The expire time of a version in milliseconds
+  pub expire: u64,
+  /// The max total size of all versions in bytes, None means no limit
+  pub max_size: Option<usize>,
+  /// The packs not changed in the latest `fresh_generation` saves will be merged
+  pub fresh_generation: Option<usize>,
+  /// The contents of packs not used in the latest `release_generation` saves will be released from memory
+  pub release_generation: Option<usize>,
+}
+
+impl Default for StoragePackOptions {
+  fn default() -> Self {
+    Self {
+      bucket_size: 20,
+      adaptive_bucket: true,
+      pack_size: 500 * 1024,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    }
+  }
+}
 
 /// Storage Options
 ///
 /// This enum contains all of supported storage options.
-/// Since MemoryStorage is only used in unit test, there is no need to add it here.
+/// Since MemoryStorage is not used as persistent cache storage, there is no need to add it here.
 #[derive(Debug, Clone)]
 pub enum StorageOptions {
-  FileSystem { directory: PathBuf },
+  FileSystem {
+    directory: PathBuf,
+    pack: StoragePackOptions,
+  },
+  /// Read and write packs through a blob store, such as `LocalBlobStore`,
+  /// so the cache can be shared by a remote service.
+  BlobStore {
+    store: Arc<dyn BlobStore>,
+    pack: StoragePackOptions,
+  },
+}
+
+/// Create the pack storage with `root` and `fs`, the paths of blob store are
+/// only used to generate the keys.
+///
+/// Panics if the bucket size or pack size is 0, the options from js are
+/// validated before, so only the embedders in rust can pass them.
+fn create_pack_storage(
+  root: PathBuf,
+  fs: Arc<dyn rspack_storage::FileSystem>,
+  pack: StoragePackOptions,
+  version: String,
+) -> Arc<dyn Storage> {
+  assert!(
+    pack.bucket_size > 0,
+    "invalid storage bucket_size: 0, expect a positive integer"
+  );
+  assert!(
+    pack.pack_size > 0,
+    "invalid storage pack_size: 0, expect a positive integer"
+  );
+  let option = PackStorageOptions {
+    temp_root: root.join(".temp"),
+    root,
+    clean: true,
+    bucket_size: pack.bucket_size,
+    pack_size: pack.pack_size,
+    expire: pack.expire,
+    fs,
+    fresh_generation: pack.fresh_generation,
+    release_generation: pack.release_generation,
+    version,
+  };
+  let limits = PackStorageLimits {
+    adaptive_bucket: pack.adaptive_bucket,
+    max_size: pack.max_size,
+  };
+  Arc::new(PackStorage::with_limits(option, limits))
 }
 
 pub fn create_storage(
@@ -23,20 +108,39 @@ pub fn create_storage(
   fs: Arc<dyn IntermediateFileSystem>,
 ) -> Arc<dyn Storage> {
   match options {
-    StorageOptions::FileSystem { directory } => {
-      let option = PackStorageOptions {
-        temp_root: directory.join(".temp"),
-        root: directory,
-        clean: true,
-        bucket_size: 20,
-        pack_size: 500 * 1024,
-        expire: 7 * 24 * 60 * 60 * 1000,
-        fs: Arc::new(BridgeFileSystem(fs)),
-        fresh_generation: Some(1),
-        release_generation: Some(2),
-        version,
-      };
-      Arc::new(PackStorage::new(option))
+    StorageOptions::FileSystem { directory, pack } => {
+      create_pack_storage(directory, Arc::new(BridgeFileSystem(fs)), pack, version)
     }
+    StorageOptions::BlobStore { store, pack } => create_pack_storage(
+      PathBuf::from("/"),
+      Arc::new(BlobFileSystem(store)),
+      pack,
+      version,
+    ),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_fs::MemoryFileSystem;
+
+  use super::{create_storage, StorageOptions, StoragePackOptions};
+
+  #[test]
+  #[should_panic(expected = "invalid storage bucket_size")]
+  fn should_reject_zero_bucket_size() {
+    create_storage(
+      StorageOptions::FileSystem {
+        directory: "/cache".into(),
+        pack: StoragePackOptions {
+          bucket_size: 0,
+          ..Default::default()
+        },
+      },
+      "version".to_string(),
+      Arc::new(MemoryFileSystem::default()),
+    );
   }
 }
@@ -209,6 +209,17 @@ impl ChunkGraph {
     old_cgm.runtime_in_chunks.clear();
   }
 
+  pub fn is_entry_module_in_chunk(
+    &self,
+    module_identifier: &ModuleIdentifier,
+    chunk_ukey: &ChunkUkey,
+  ) -> bool {
+    let chunk_graph_chunk = self.expect_chunk_graph_chunk(chunk_ukey);
+    chunk_graph_chunk
+      .entry_modules
+      .contains_key(module_identifier)
+  }
+
   pub fn get_chunk_entry_modules(&self, chunk_ukey: &ChunkUkey) -> Vec<ModuleIdentifier> {
     let chunk_graph_chunk = self.expect_chunk_graph_chunk(chunk_ukey);
 
@@ -6,6 +6,7 @@ use std::{
     atomic::{AtomicBool, AtomicU32, Ordering},
     Arc,
   },
+  time::Instant,
 };
 
 use dashmap::DashSet;
@@ -37,6 +38,7 @@ use super::{
   hmr::CompilationRecords,
   make::{make_module_graph, update_module_graph, MakeArtifact, MakeParam},
   module_executor::ModuleExecutor,
+  records::Records,
   CompilerId,
 };
 use crate::{
@@ -162,6 +164,12 @@ pub struct Compilation {
   // So use compilation hash update `hot_index` to fix it.
   pub hot_index: u32,
   pub records: Option<CompilationRecords>,
+  /// The records read from `records_input_path`
+  pub input_records: Option<Records>,
+  /// The time when the compilation is created
+  pub start_time: Instant,
+  /// The time when the compilation is sealed and ready to emit
+  pub end_time: Option<Instant>,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
   pub global_entry: EntryData,
@@ -286,6 +294,9 @@ impl Compilation {
       hot_index: 0,
       runtime_template: RuntimeTemplate::new(options.output.environment),
       records,
+      input_records: None,
+      start_time: Instant::now(),
+      end_time: None,
       options,
       other_module_graph: None,
       dependency_factories: Default::default(),
@@ -1043,7 +1054,8 @@ impl Compilation {
     let mutations = self
       .incremental
       .mutations_read(IncrementalPasses::CHUNKS_RENDER);
-    let chunks = if let Some(mutations) = mutations
+    let incremental = mutations.is_some();
+    let mut chunks: UkeySet<ChunkUkey> = if let Some(mutations) = mutations
       && !self.chunk_render_artifact.is_empty()
     {
       let removed_chunks = mutations.iter().filter_map(|mutation| match mutation {
@@ -1068,6 +1080,14 @@ impl Compilation {
     } else {
       self.chunk_by_ukey.keys().copied().collect()
     };
+    let mut recovered_results = ChunkRenderArtifact::default();
+    if let Err(err) = self
+      .cache
+      .before_chunks_render(self, &mut chunks, &mut recovered_results)
+      .await
+    {
+      self.push_diagnostic(err.into());
+    }
     let chunk_render_results = chunks
       .iter()
       .map(|chunk| async {
@@ -1088,12 +1108,20 @@ impl Compilation {
         ))
       })
       .collect::<FuturesResults<Result<_>>>();
-    let chunk_render_results = chunk_render_results
+    let mut chunk_render_results = chunk_render_results
       .into_inner()
       .into_iter()
       .collect::<Result<UkeyMap<_, _>>>()?;
+    if let Err(err) = self
+      .cache
+      .after_chunks_render(self, &chunk_render_results)
+      .await
+    {
+      self.push_diagnostic(err.into());
+    }
+    chunk_render_results.extend(recovered_results);
 
-    let chunk_ukey_and_manifest = if mutations.is_some() {
+    let chunk_ukey_and_manifest = if incremental {
       self.chunk_render_artifact.extend(chunk_render_results);
       self.chunk_render_artifact.clone()
     } else {
@@ -1410,11 +1438,13 @@ impl Compilation {
     // so now we can start to generate assets based on the chunk graph
 
     let start = logger.time("module ids");
+    self.revive_module_ids();
     tracing::info_span!("Compilation:module_ids")
       .in_scope(|| plugin_driver.compilation_hooks.module_ids.call(self))?;
     logger.time_end(start);
 
     let start = logger.time("chunk ids");
+    self.revive_chunk_ids();
     tracing::info_span!("Compilation:chunk_ids")
       .in_scope(|| plugin_driver.compilation_hooks.chunk_ids.call(self))?;
     logger.time_end(start);
@@ -1456,7 +1486,7 @@ impl Compilation {
     logger.time_end(start);
 
     let start = logger.time("code generation");
-    let code_generation_modules = if let Some(mutations) = self
+    let mut code_generation_modules = if let Some(mutations) = self
       .incremental
       .mutations_read(IncrementalPasses::MODULES_CODEGEN)
       && !self.code_generation_results.is_empty()
@@ -1479,7 +1509,20 @@ impl Compilation {
     } else {
       self.get_module_graph().modules().keys().copied().collect()
     };
-    self.code_generation(code_generation_modules)?;
+    let cache = self.cache.clone();
+    if let Err(err) = cache
+      .before_code_generation(self, &mut code_generation_modules)
+      .await
+    {
+      self.push_diagnostic(err.into());
+    }
+    self.code_generation(code_generation_modules.clone())?;
+    if let Err(err) = cache
+      .after_code_generation(self, &code_generation_modules)
+      .await
+    {
+      self.push_diagnostic(err.into());
+    }
 
     plugin_driver
       .compilation_hooks
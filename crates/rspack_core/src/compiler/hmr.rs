@@ -24,6 +24,21 @@ impl Compiler {
     changed_files: std::collections::HashSet<String>,
     deleted_files: std::collections::HashSet<String>,
   ) -> Result<()> {
+    // the files may be touched without changing the content, e.g. saved by editor,
+    // check the content hash to skip them, the compilation still runs to finish
+    // the hooks even if no file is modified
+    let changed_files = match &self.file_snapshot {
+      Some(snapshot) => {
+        snapshot
+          .filter_modified_paths(changed_files.iter().map(|file| Path::new(file).into()))
+          .await?
+      }
+      None => changed_files
+        .iter()
+        .map(|file| Path::new(file).into())
+        .collect(),
+    };
+
     let old = self.compilation.get_stats();
     let old_hash = self.compilation.hash.clone();
 
@@ -57,8 +72,7 @@ impl Compiler {
 
     // build without stats
     {
-      let mut modified_files: HashSet<ArcPath> = HashSet::default();
-      modified_files.extend(changed_files.iter().map(|files| Path::new(files).into()));
+      let modified_files = changed_files;
       let mut removed_files: HashSet<ArcPath> = HashSet::default();
       removed_files.extend(deleted_files.iter().map(|files| Path::new(files).into()));
 
@@ -207,6 +221,7 @@ impl Compiler {
     if let Err(err) = self.cache.after_compile(&self.compilation).await {
       self.compilation.push_diagnostic(err.into());
     }
+    self.update_file_snapshot(true).await;
 
     Ok(())
   }
@@ -0,0 +1,193 @@
+//! Lazy barrel defers building the named re-exports of side effect free modules
+//! until an import references them, e.g. importing one icon from a barrel that
+//! re-exports thousands of icons only builds the imported one.
+
+use rspack_collections::IdentifierMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::ecma::atoms::Atom;
+
+use crate::{
+  BoxDependency, DependencyCategory, DependencyId, DependencyType, ForwardId, ModuleIdentifier,
+};
+
+#[derive(Debug, Default)]
+struct LazyBarrel {
+  // re-export dependencies which are not built yet, keyed by the re-exported name.
+  // Side effect dependencies are shared by all the names re-exported from the same request.
+  pending: HashMap<Atom, Vec<DependencyId>>,
+  released: HashSet<DependencyId>,
+}
+
+impl LazyBarrel {
+  fn release(&mut self, forward_id: &ForwardId) -> Vec<DependencyId> {
+    let dependencies = match forward_id {
+      ForwardId::Empty => return vec![],
+      ForwardId::Id(id) => self.pending.remove(id).unwrap_or_default(),
+      ForwardId::All => self.pending.drain().flat_map(|(_, deps)| deps).collect(),
+    };
+    dependencies
+      .into_iter()
+      .filter(|dep_id| self.released.insert(*dep_id))
+      .collect()
+  }
+
+  fn pending_dependencies(&self) -> HashSet<DependencyId> {
+    self
+      .pending
+      .values()
+      .flatten()
+      .filter(|dep_id| !self.released.contains(dep_id))
+      .copied()
+      .collect()
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct LazyBarrels {
+  barrels: IdentifierMap<LazyBarrel>,
+  // forward ids requested from modules which are still building
+  requested: IdentifierMap<HashSet<ForwardId>>,
+}
+
+impl LazyBarrels {
+  /// Collect the lazy dependencies of a barrel, keyed by the re-exported name.
+  ///
+  /// Returns `None` if the module contains anything other than re-exports.
+  /// Star re-exports need all exports of the target, so they are always built.
+  pub fn collect_dependencies<'a>(
+    dependencies: impl IntoIterator<Item = &'a BoxDependency>,
+  ) -> Option<HashMap<Atom, Vec<DependencyId>>> {
+    let mut named_dependencies: HashMap<&str, Vec<(Atom, DependencyId)>> = HashMap::default();
+    let mut side_effect_dependencies: HashMap<&str, Vec<DependencyId>> = HashMap::default();
+    let mut eager_requests = HashSet::default();
+    for dependency in dependencies {
+      let Some(module_dependency) = dependency.as_module_dependency() else {
+        continue;
+      };
+      if !matches!(dependency.category(), DependencyCategory::Esm) {
+        return None;
+      }
+      let request = module_dependency.request();
+      if let Some(name) = dependency.lazy_export_name() {
+        named_dependencies
+          .entry(request)
+          .or_default()
+          .push((name.clone(), *dependency.id()));
+      } else if matches!(dependency.forward_id(), ForwardId::Empty) {
+        side_effect_dependencies
+          .entry(request)
+          .or_default()
+          .push(*dependency.id());
+      } else if matches!(
+        dependency.dependency_type(),
+        DependencyType::EsmExportImportedSpecifier
+      ) {
+        // star re-exports
+        eager_requests.insert(request);
+      } else {
+        return None;
+      }
+    }
+
+    let mut pending: HashMap<Atom, Vec<DependencyId>> = HashMap::default();
+    for (request, names) in named_dependencies {
+      if eager_requests.contains(request) {
+        continue;
+      }
+      let side_effect_dependencies = side_effect_dependencies
+        .get(request)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+      for (name, dep_id) in names {
+        let dependencies = pending.entry(name).or_default();
+        dependencies.push(dep_id);
+        dependencies.extend_from_slice(side_effect_dependencies);
+      }
+    }
+    (!pending.is_empty()).then_some(pending)
+  }
+
+  /// Record the forward ids requested from a module which is still building.
+  pub fn request(
+    &mut self,
+    module_identifier: ModuleIdentifier,
+    forward_ids: impl IntoIterator<Item = ForwardId>,
+  ) {
+    self
+      .requested
+      .entry(module_identifier)
+      .or_default()
+      .extend(forward_ids);
+  }
+
+  /// Register a built module, `pending` is `None` if the module is not a barrel.
+  /// The requests recorded while the module was building are applied to the barrel.
+  ///
+  /// Returns the dependencies that are still lazy.
+  pub fn add_barrel(
+    &mut self,
+    module_identifier: ModuleIdentifier,
+    pending: Option<HashMap<Atom, Vec<DependencyId>>>,
+  ) -> HashSet<DependencyId> {
+    let requested = self
+      .requested
+      .remove(&module_identifier)
+      .unwrap_or_default();
+    let Some(pending) = pending else {
+      return Default::default();
+    };
+    let mut barrel = LazyBarrel {
+      pending,
+      released: Default::default(),
+    };
+    for forward_id in requested {
+      barrel.release(&forward_id);
+    }
+    let lazy_dependencies = barrel.pending_dependencies();
+    if !lazy_dependencies.is_empty() {
+      self.barrels.insert(module_identifier, barrel);
+    }
+    lazy_dependencies
+  }
+
+  /// Register a barrel recovered from persistent cache, `built_dependencies`
+  /// are the dependencies which have been released before.
+  pub fn recover_barrel(
+    &mut self,
+    module_identifier: ModuleIdentifier,
+    mut pending: HashMap<Atom, Vec<DependencyId>>,
+    built_dependencies: &[DependencyId],
+  ) {
+    let released: HashSet<DependencyId> = built_dependencies.iter().copied().collect();
+    pending.retain(|_, dependencies| dependencies.iter().any(|dep_id| !released.contains(dep_id)));
+    if !pending.is_empty() {
+      self
+        .barrels
+        .insert(module_identifier, LazyBarrel { pending, released });
+    }
+  }
+
+  /// Release the lazy dependencies of a built barrel referenced by `forward_ids`.
+  ///
+  /// Returns `None` if the module is not a barrel with lazy dependencies.
+  pub fn release(
+    &mut self,
+    module_identifier: &ModuleIdentifier,
+    forward_ids: impl IntoIterator<Item = ForwardId>,
+  ) -> Option<Vec<DependencyId>> {
+    let barrel = self.barrels.get_mut(module_identifier)?;
+    let released = forward_ids
+      .into_iter()
+      .flat_map(|forward_id| barrel.release(&forward_id))
+      .collect();
+    if barrel.pending.is_empty() {
+      self.barrels.remove(module_identifier);
+    }
+    Some(released)
+  }
+
+  pub fn revoke(&mut self, module_identifier: &ModuleIdentifier) {
+    self.barrels.remove(module_identifier);
+    self.requested.remove(module_identifier);
+  }
+}
@@ -1,4 +1,5 @@
 pub mod cutout;
+mod lazy_barrel;
 pub mod repair;
 
 use rspack_collections::IdentifierSet;
@@ -6,6 +7,7 @@ use rspack_error::{Diagnostic, Result};
 use rspack_paths::ArcPath;
 use rustc_hash::FxHashSet as HashSet;
 
+pub use self::lazy_barrel::LazyBarrels;
 use self::{cutout::Cutout, repair::repair};
 use crate::{
   utils::FileCounter, BuildDependency, Compilation, DependencyId, FactorizeInfo, ModuleGraph,
@@ -46,6 +48,8 @@ pub struct MakeArtifact {
   pub context_dependencies: FileCounter,
   pub missing_dependencies: FileCounter,
   pub build_dependencies: FileCounter,
+  // re-exports of side effect free modules which are not built yet, see `experiments.lazy_barrel`
+  pub lazy_barrels: LazyBarrels,
 }
 
 impl MakeArtifact {
@@ -84,6 +88,7 @@ impl MakeArtifact {
       .build_dependencies
       .remove_batch_file(&build_info.build_dependencies);
     self.make_failed_module.remove(module_identifier);
+    self.lazy_barrels.revoke(module_identifier);
 
     // clean incoming & all_dependencies(outgoing) factorize info
     let mgm = mg
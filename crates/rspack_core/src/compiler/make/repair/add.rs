@@ -1,6 +1,6 @@
 use rspack_error::Result;
 
-use super::{build::BuildTask, MakeTaskContext};
+use super::{build::BuildTask, process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
   module_graph::{ModuleGraph, ModuleGraphModule},
   utils::task_loop::{Task, TaskResult, TaskType},
@@ -23,6 +23,16 @@ impl Task<MakeTaskContext> for AddTask {
   }
   async fn main_run(self: Box<Self>, context: &mut MakeTaskContext) -> TaskResult<MakeTaskContext> {
     let module_identifier = self.module.identifier();
+    let lazy_barrel = context.compiler_options.experiments.lazy_barrel;
+    let forward_ids = if lazy_barrel {
+      self
+        .dependencies
+        .iter()
+        .map(|dependency| dependency.forward_id())
+        .collect::<Vec<_>>()
+    } else {
+      vec![]
+    };
     let artifact = &mut context.artifact;
     let module_graph =
       &mut MakeTaskContext::get_module_graph_mut(&mut artifact.module_graph_partial);
@@ -56,6 +66,38 @@ impl Task<MakeTaskContext> for AddTask {
         module_identifier,
       )?;
 
+      if lazy_barrel {
+        if let Some(released) = artifact
+          .lazy_barrels
+          .release(&module_identifier, forward_ids.iter().cloned())
+        {
+          // build the re-exports of the barrel which are referenced from now on
+          if !released.is_empty() {
+            let module = module_graph
+              .module_by_identifier_mut(&module_identifier)
+              .expect("should have module");
+            for dep_id in &released {
+              module.add_dependency_id(*dep_id);
+            }
+            // the dependencies of the module changed, it should be treated as rebuilt
+            // by incremental passes and persistent cache
+            artifact.built_modules.insert(module_identifier);
+            return Ok(vec![Box::new(ProcessDependenciesTask {
+              original_module_identifier: module_identifier,
+              dependencies: released,
+            })]);
+          }
+        } else if module_graph
+          .module_by_identifier(&module_identifier)
+          .is_none()
+        {
+          // the module is still building
+          artifact
+            .lazy_barrels
+            .request(module_identifier, forward_ids);
+        }
+      }
+
       // reused module
       return Ok(vec![]);
     }
@@ -69,6 +111,12 @@ impl Task<MakeTaskContext> for AddTask {
       module_identifier,
     )?;
 
+    if lazy_barrel {
+      artifact
+        .lazy_barrels
+        .request(module_identifier, forward_ids);
+    }
+
     tracing::trace!("Module added: {}", self.module.identifier());
 
     artifact.built_modules.insert(module_identifier);
@@ -4,6 +4,7 @@ use rspack_fs::ReadableFileSystem;
 
 use super::{process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
+  compiler::make::LazyBarrels,
   utils::task_loop::{Task, TaskResult, TaskType},
   AsyncDependenciesBlock, BoxDependency, BuildContext, BuildResult, CompilationId, CompilerId,
   CompilerOptions, DependencyParents, Module, ModuleProfile, ResolverFactory, SharedPluginDriver,
@@ -129,6 +130,22 @@ impl Task<MakeTaskContext> for BuildResultTask {
       .build_dependencies
       .add_batch_file(&build_info.build_dependencies);
 
+    // the named re-exports of side effect free barrels are built once they are imported
+    let lazy_dependencies = if context.compiler_options.experiments.lazy_barrel {
+      let side_effect_free = module
+        .factory_meta()
+        .and_then(|meta| meta.side_effect_free)
+        .unwrap_or_default();
+      let pending = (side_effect_free && build_result.blocks.is_empty())
+        .then(|| LazyBarrels::collect_dependencies(&build_result.dependencies))
+        .flatten();
+      artifact
+        .lazy_barrels
+        .add_barrel(module.identifier(), pending)
+    } else {
+      Default::default()
+    };
+
     let mut queue = VecDeque::new();
     let mut all_dependencies = vec![];
     let mut handle_block = |dependencies: Vec<BoxDependency>,
@@ -137,7 +154,7 @@ impl Task<MakeTaskContext> for BuildResultTask {
      -> Vec<Box<AsyncDependenciesBlock>> {
       for (index_in_block, dependency) in dependencies.into_iter().enumerate() {
         let dependency_id = *dependency.id();
-        if current_block.is_none() {
+        if current_block.is_none() && !lazy_dependencies.contains(&dependency_id) {
           module.add_dependency_id(dependency_id);
         }
         all_dependencies.push(dependency_id);
@@ -181,7 +198,10 @@ impl Task<MakeTaskContext> for BuildResultTask {
     module_graph.add_module(module);
 
     Ok(vec![Box::new(ProcessDependenciesTask {
-      dependencies: all_dependencies,
+      dependencies: all_dependencies
+        .into_iter()
+        .filter(|dep_id| !lazy_dependencies.contains(dep_id))
+        .collect(),
       original_module_identifier: module_identifier,
     })])
   }
@@ -2,8 +2,11 @@ mod compilation;
 mod hmr;
 pub mod make;
 mod module_executor;
+mod records;
+mod watch;
 use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
+use std::time::Instant;
 
 use rspack_error::Result;
 use rspack_fs::{IntermediateFileSystem, NativeFileSystem, ReadableFileSystem, WritableFileSystem};
@@ -19,6 +22,12 @@ use tracing::instrument;
 pub use self::compilation::*;
 pub use self::hmr::{collect_changed_modules, CompilationRecords};
 pub use self::module_executor::{ExecuteModuleId, ExecutedRuntimeModule, ModuleExecutor};
+pub use self::records::{ChunkRecords, ModuleRecords, RecordId, Records};
+pub use self::watch::WatchOptions;
+use crate::cache::persistent::{
+  snapshot::{FileStrategy, Snapshot, SnapshotOptions},
+  storage::MemoryStorage,
+};
 use crate::cache::{new_cache, Cache};
 use crate::incremental::IncrementalPasses;
 use crate::old_cache::Cache as OldCache;
@@ -89,6 +98,9 @@ pub struct Compiler {
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
+  /// The content hash of file dependencies, used to skip the files which are
+  /// touched but not modified when rebuilding, it is created by the first rebuild
+  file_snapshot: Option<Snapshot>,
 }
 
 impl Compiler {
@@ -181,6 +193,7 @@ impl Compiler {
       old_cache,
       emitted_asset_versions: Default::default(),
       input_filesystem,
+      file_snapshot: None,
     }
   }
 
@@ -230,11 +243,55 @@ impl Compiler {
     if let Err(err) = self.cache.after_compile(&self.compilation).await {
       self.compilation.push_diagnostic(err.into());
     }
+    self.update_file_snapshot(false).await;
     Ok(())
   }
 
+  /// Save the content hash of the added and modified file dependencies
+  ///
+  /// The files are only hashed after the snapshot is created by `create_file_snapshot`
+  /// or the first `rebuild`.
+  async fn update_file_snapshot(&mut self, is_rebuild: bool) {
+    match &self.file_snapshot {
+      Some(snapshot) => {
+        let (_, file_added, file_removed) = self.compilation.file_dependencies();
+        snapshot.remove(file_removed.map(|item| item.as_ref()));
+        snapshot
+          .add(
+            self
+              .compilation
+              .modified_files
+              .iter()
+              .chain(file_added)
+              .map(|item| item.as_ref()),
+          )
+          .await;
+      }
+      None if is_rebuild => self.create_file_snapshot().await,
+      None => {}
+    }
+  }
+
+  /// Create the snapshot with the content hash of all file dependencies, so the
+  /// files which are touched without changing the content are skipped by `rebuild`
+  ///
+  /// `watch` creates it after the first build, call it after `build` when `rebuild`
+  /// is called directly.
+  pub async fn create_file_snapshot(&mut self) {
+    let snapshot = Snapshot::new(
+      SnapshotOptions::default().with_file_strategy(FileStrategy::ContentHash),
+      self.input_filesystem.clone(),
+      Arc::new(MemoryStorage::default()),
+      None,
+    );
+    let (file_all, _, _) = self.compilation.file_dependencies();
+    snapshot.add(file_all.map(|item| item.as_ref())).await;
+    self.file_snapshot = Some(snapshot);
+  }
+
   #[instrument("Compiler:compile", skip_all)]
   async fn compile(&mut self) -> Result<()> {
+    self.compilation.input_records = self.read_records().await?;
     let mut compilation_params = self.new_compilation_params();
     // FOR BINDING SAFETY:
     // Make sure `thisCompilation` hook was called for each `JsCompilation` update before any access to it.
@@ -308,6 +365,7 @@ impl Compiler {
 
   #[instrument("Compile:done", skip_all)]
   async fn compile_done(&mut self) -> Result<()> {
+    self.compilation.end_time = Some(Instant::now());
     let logger = self.compilation.get_logger("rspack.Compiler");
 
     if matches!(
@@ -326,6 +384,10 @@ impl Compiler {
     self.emit_assets().await?;
     logger.time_end(start);
 
+    let start = logger.time("emitRecords");
+    self.emit_records().await?;
+    logger.time_end(start);
+
     Ok(())
   }
 
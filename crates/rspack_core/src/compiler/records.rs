@@ -0,0 +1,279 @@
+use std::collections::BTreeMap;
+
+use rspack_collections::DatabaseItem;
+use rspack_error::{error, Result};
+use rspack_util::identifier::make_paths_relative;
+use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
+
+use crate::{Chunk, ChunkGraph, Compilation, Compiler};
+
+/// The ids of modules and chunks which are saved between builds
+///
+/// Records are read from `records_input_path` before compiling and written to
+/// `records_output_path` after emitting. The ids in records are revived before
+/// the id plugins run, so the ids keep stable across independent builds.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Records {
+  #[serde(default)]
+  pub modules: ModuleRecords,
+  #[serde(default)]
+  pub chunks: ChunkRecords,
+}
+
+/// The id of module or chunk in records, the numeric ids are written as numbers
+/// like webpack does, so the records of webpack can be read as well
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecordId {
+  Number(u32),
+  String(String),
+}
+
+impl From<&str> for RecordId {
+  fn from(id: &str) -> Self {
+    match id.parse::<u32>() {
+      Ok(number) if number.to_string() == id => Self::Number(number),
+      _ => Self::String(id.to_string()),
+    }
+  }
+}
+
+impl std::fmt::Display for RecordId {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Number(number) => write!(f, "{number}"),
+      Self::String(string) => f.write_str(string),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleRecords {
+  /// The module ids by the module identifiers relative to context
+  #[serde(default)]
+  pub by_identifier: BTreeMap<String, RecordId>,
+  /// The ids of all modules, the id plugins will not assign them to new modules
+  #[serde(default)]
+  pub used_ids: Vec<RecordId>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkRecords {
+  /// The chunk ids by the chunk names
+  #[serde(default)]
+  pub by_name: BTreeMap<String, RecordId>,
+  /// The chunk ids by the origins of chunk groups which contain the chunk
+  #[serde(default)]
+  pub by_source: BTreeMap<String, RecordId>,
+  /// The ids of all chunks, the id plugins will not assign them to new chunks
+  #[serde(default)]
+  pub used_ids: Vec<RecordId>,
+}
+
+/// Get the sources of chunk, which are used to find the chunk of the last build
+fn get_chunk_sources(chunk: &Chunk, compilation: &Compilation) -> Vec<String> {
+  let context = compilation.options.context.as_str();
+  let mut sources = vec![];
+  for group in chunk.groups() {
+    let group = compilation.chunk_group_by_ukey.expect_get(group);
+    let index = group
+      .chunks
+      .iter()
+      .position(|ukey| ukey == &chunk.ukey())
+      .unwrap_or_default();
+    if let Some(name) = group.name() {
+      sources.push(format!("{index} {name}"));
+      continue;
+    }
+    for origin in group.origins() {
+      let Some(module) = origin.module else {
+        continue;
+      };
+      let module = make_paths_relative(context, &module);
+      if let Some(request) = &origin.request {
+        sources.push(format!("{index} {module} {request}"));
+      } else if let Some(loc) = &origin.loc {
+        sources.push(format!("{index} {module} {loc}"));
+      }
+    }
+  }
+  // the groups of chunk are not ordered
+  sources.sort_unstable();
+  sources
+}
+
+impl Records {
+  /// Collect the ids of modules and chunks in compilation
+  pub fn from_compilation(compilation: &Compilation) -> Self {
+    let context = compilation.options.context.as_str();
+    let mut records = Self::default();
+
+    let module_graph = compilation.get_module_graph();
+    for module in module_graph.modules().values() {
+      let Some(id) =
+        ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())
+      else {
+        continue;
+      };
+      records.modules.by_identifier.insert(
+        make_paths_relative(context, &module.identifier()),
+        RecordId::from(id.as_str()),
+      );
+      records.modules.used_ids.push(RecordId::from(id.as_str()));
+    }
+    records.modules.used_ids.sort_unstable();
+    records.modules.used_ids.dedup();
+
+    for chunk in compilation.chunk_by_ukey.values() {
+      let Some(id) = chunk.id(&compilation.chunk_ids_artifact) else {
+        continue;
+      };
+      if let Some(name) = chunk.name() {
+        records
+          .chunks
+          .by_name
+          .insert(name.to_string(), RecordId::from(id.as_str()));
+      }
+      for source in get_chunk_sources(chunk, compilation) {
+        records
+          .chunks
+          .by_source
+          .insert(source, RecordId::from(id.as_str()));
+      }
+      records.chunks.used_ids.push(RecordId::from(id.as_str()));
+    }
+    records.chunks.used_ids.sort_unstable();
+    records.chunks.used_ids.dedup();
+
+    records
+  }
+}
+
+impl Compilation {
+  /// Assign the module ids in records to the modules which have no id
+  pub(crate) fn revive_module_ids(&mut self) {
+    let Some(records) = &self.input_records else {
+      return;
+    };
+    let context = self.options.context.as_str();
+    let module_graph = self.get_module_graph();
+
+    let mut used_ids = HashSet::default();
+    let mut modules = vec![];
+    for module in module_graph.modules().values().filter(|m| m.need_id()) {
+      match ChunkGraph::get_module_id(&self.module_ids_artifact, module.identifier()) {
+        Some(id) => {
+          used_ids.insert(id.to_string());
+        }
+        None => modules.push(module.identifier()),
+      }
+    }
+    modules.sort_unstable();
+
+    let mut revived = vec![];
+    for module in modules {
+      let Some(id) = records
+        .modules
+        .by_identifier
+        .get(&make_paths_relative(context, &module))
+      else {
+        continue;
+      };
+      let id = id.to_string();
+      if used_ids.insert(id.clone()) {
+        revived.push((module, id));
+      }
+    }
+    for (module, id) in revived {
+      ChunkGraph::set_module_id(&mut self.module_ids_artifact, module, id.into());
+    }
+  }
+
+  /// Assign the chunk ids in records to the chunks which have no id, the chunks
+  /// are matched by name first, then by source.
+  pub(crate) fn revive_chunk_ids(&mut self) {
+    let Some(records) = &self.input_records else {
+      return;
+    };
+
+    let mut used_ids = HashSet::default();
+    let mut chunks = vec![];
+    for chunk in self.chunk_by_ukey.values() {
+      match chunk.id(&self.chunk_ids_artifact) {
+        Some(id) => {
+          used_ids.insert(id.to_string());
+        }
+        None => chunks.push(chunk),
+      }
+    }
+    chunks.sort_unstable_by_key(|chunk| chunk.ukey());
+
+    let mut revived = vec![];
+    let mut remain = vec![];
+    for chunk in chunks {
+      if let Some(id) = chunk
+        .name()
+        .and_then(|name| records.chunks.by_name.get(name))
+        && used_ids.insert(id.to_string())
+      {
+        revived.push((chunk.ukey(), id.to_string()));
+      } else {
+        remain.push(chunk);
+      }
+    }
+    for chunk in remain {
+      for source in get_chunk_sources(chunk, self) {
+        if let Some(id) = records.chunks.by_source.get(&source)
+          && used_ids.insert(id.to_string())
+        {
+          revived.push((chunk.ukey(), id.to_string()));
+          break;
+        }
+      }
+    }
+    for (chunk, id) in revived {
+      self
+        .chunk_by_ukey
+        .expect_get(&chunk)
+        .set_id(&mut self.chunk_ids_artifact, id);
+    }
+  }
+}
+
+impl Compiler {
+  /// Read the records from `records_input_path`, return None if the file not exists
+  pub(crate) async fn read_records(&self) -> Result<Option<Records>> {
+    let Some(path) = &self.options.records_input_path else {
+      return Ok(None);
+    };
+    let content = match self.intermediate_filesystem.read_file(path).await {
+      Ok(content) => content,
+      Err(rspack_fs::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+        return Ok(None)
+      }
+      Err(err) => return Err(err.into()),
+    };
+    serde_json::from_slice(&content)
+      .map(Some)
+      .map_err(|e| error!("failed to parse records {path}: {e}"))
+  }
+
+  /// Write the ids of compilation to `records_output_path`
+  pub(crate) async fn emit_records(&self) -> Result<()> {
+    let Some(path) = &self.options.records_output_path else {
+      return Ok(());
+    };
+    let records = Records::from_compilation(&self.compilation);
+    let content = serde_json::to_vec_pretty(&records)
+      .map_err(|e| error!("failed to serialize records: {e}"))?;
+    if let Some(dir) = path.parent() {
+      self.intermediate_filesystem.create_dir_all(dir).await?;
+    }
+    self.intermediate_filesystem.write(path, &content).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,322 @@
+use std::{
+  ops::ControlFlow,
+  path::{Path, PathBuf},
+  time::Duration,
+};
+
+use notify::{
+  Config, Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher as _,
+};
+use rspack_error::{error, Result};
+use rspack_fs::{FileMetadata, ReadableFileSystem};
+use rspack_paths::Utf8Path;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::{Compilation, Compiler};
+
+/// The poll interval used when the native watcher is not available
+const FALLBACK_POLL_INTERVAL: u64 = 1000;
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+  /// Wait for more changes before rebuilding, in milliseconds
+  pub aggregate_timeout: u64,
+  /// Glob patterns of the paths which should not trigger rebuilding
+  pub ignored: Vec<String>,
+  /// Check the changes by polling with the interval in milliseconds instead of native events
+  pub poll: Option<u64>,
+}
+
+impl Default for WatchOptions {
+  fn default() -> Self {
+    Self {
+      aggregate_timeout: 20,
+      ignored: vec![],
+      poll: None,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WatchKind {
+  File,
+  Context,
+  Missing,
+}
+
+/// Collect the paths to watch from the dependencies of compilation
+fn collect_watch_paths(compilation: &Compilation) -> HashMap<PathBuf, WatchKind> {
+  let mut paths = HashMap::default();
+  paths.extend(
+    compilation
+      .missing_dependencies()
+      .0
+      .map(|p| (p.to_path_buf(), WatchKind::Missing)),
+  );
+  paths.extend(
+    compilation
+      .context_dependencies()
+      .0
+      .map(|p| (p.to_path_buf(), WatchKind::Context)),
+  );
+  paths.extend(
+    compilation
+      .file_dependencies()
+      .0
+      .chain(compilation.build_dependencies().0)
+      .map(|p| (p.to_path_buf(), WatchKind::File)),
+  );
+  paths
+}
+
+/// Read the metadata by the input file system instead of the native one, it
+/// is None if the path does not exist
+fn read_metadata(fs: &dyn ReadableFileSystem, path: &Path) -> Option<FileMetadata> {
+  fs.metadata(Utf8Path::from_path(path)?).ok()
+}
+
+fn is_dir(fs: &dyn ReadableFileSystem, path: &Path) -> bool {
+  read_metadata(fs, path).is_some_and(|meta| meta.is_directory)
+}
+
+/// The native watcher with poll fallback
+struct FileWatcher {
+  inner: Box<dyn notify::Watcher + Send>,
+  is_poll: bool,
+  tx: UnboundedSender<notify::Result<Event>>,
+  /// The watched paths and the real targets in file system
+  watched: HashMap<PathBuf, (PathBuf, RecursiveMode)>,
+  ignored: Vec<glob::Pattern>,
+}
+
+impl std::fmt::Debug for FileWatcher {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("FileWatcher")
+      .field("is_poll", &self.is_poll)
+      .field("watched", &self.watched)
+      .finish()
+  }
+}
+
+fn create_poll_watcher(
+  tx: UnboundedSender<notify::Result<Event>>,
+  interval: u64,
+) -> Result<Box<dyn notify::Watcher + Send>> {
+  let watcher = PollWatcher::new(
+    move |res| {
+      let _ = tx.send(res);
+    },
+    Config::default().with_poll_interval(Duration::from_millis(interval)),
+  )
+  .map_err(|e| error!("create poll watcher failed: {e}"))?;
+  Ok(Box::new(watcher))
+}
+
+impl FileWatcher {
+  fn new(options: &WatchOptions) -> Result<(Self, UnboundedReceiver<notify::Result<Event>>)> {
+    let ignored = options
+      .ignored
+      .iter()
+      .map(|pattern| {
+        glob::Pattern::new(pattern).map_err(|e| error!("invalid ignored pattern {pattern}: {e}"))
+      })
+      .collect::<Result<Vec<_>>>()?;
+    let (tx, rx) = unbounded_channel();
+    let (inner, is_poll) = match options.poll {
+      Some(interval) => (create_poll_watcher(tx.clone(), interval)?, true),
+      None => {
+        let sender = tx.clone();
+        match RecommendedWatcher::new(
+          move |res| {
+            let _ = sender.send(res);
+          },
+          Config::default(),
+        ) {
+          Ok(watcher) => (Box::new(watcher) as Box<dyn notify::Watcher + Send>, false),
+          Err(_) => (
+            create_poll_watcher(tx.clone(), FALLBACK_POLL_INTERVAL)?,
+            true,
+          ),
+        }
+      }
+    };
+    Ok((
+      Self {
+        inner,
+        is_poll,
+        tx,
+        watched: Default::default(),
+        ignored,
+      },
+      rx,
+    ))
+  }
+
+  fn is_ignored(&self, path: &Path) -> bool {
+    self
+      .ignored
+      .iter()
+      .any(|pattern| pattern.matches_path(path))
+  }
+
+  /// Watch the new paths and unwatch the paths which are not used anymore
+  fn update(
+    &mut self,
+    paths: &HashMap<PathBuf, WatchKind>,
+    fs: &dyn ReadableFileSystem,
+  ) -> Result<()> {
+    let mut targets: HashMap<PathBuf, (PathBuf, RecursiveMode)> = HashMap::default();
+    for (path, kind) in paths {
+      if self.is_ignored(path) {
+        continue;
+      }
+      let target = match kind {
+        WatchKind::Context if is_dir(fs, path) => (path.clone(), RecursiveMode::Recursive),
+        // watch the parent directory of files, since editors may save a file by replacing it,
+        // and watch the nearest existing directory to know when the path is created
+        _ => {
+          let Some(dir) = path.ancestors().skip(1).find(|dir| is_dir(fs, dir)) else {
+            continue;
+          };
+          (dir.to_path_buf(), RecursiveMode::NonRecursive)
+        }
+      };
+      targets.insert(path.clone(), target);
+    }
+
+    let old_targets = self
+      .watched
+      .values()
+      .map(|(target, _)| target.clone())
+      .collect::<HashSet<_>>();
+    let new_targets = targets
+      .values()
+      .cloned()
+      .collect::<HashMap<PathBuf, RecursiveMode>>();
+    for target in old_targets.iter() {
+      if !new_targets.contains_key(target) {
+        let _ = self.inner.unwatch(target);
+      }
+    }
+    for (target, mode) in new_targets.iter() {
+      if old_targets.contains(target) {
+        continue;
+      }
+      if let Err(e) = self.inner.watch(target, *mode) {
+        match e.kind {
+          // the native watcher reaches the limit of system, fallback to poll
+          notify::ErrorKind::MaxFilesWatch if !self.is_poll => {
+            self.inner = create_poll_watcher(self.tx.clone(), FALLBACK_POLL_INTERVAL)?;
+            self.is_poll = true;
+            self.watched.clear();
+            return self.update(paths, fs);
+          }
+          // the path may be removed after compiling, it will be checked in the next rebuild
+          notify::ErrorKind::PathNotFound => {}
+          notify::ErrorKind::Io(ref err) if err.kind() == std::io::ErrorKind::NotFound => {}
+          _ => return Err(error!("watch {} failed: {e}", target.display())),
+        }
+      }
+    }
+    self.watched = targets;
+    Ok(())
+  }
+
+  /// Whether the changed path affects the compilation
+  fn is_affected(&self, changed: &Path) -> bool {
+    if self.is_ignored(changed) {
+      return false;
+    }
+    self.watched.contains_key(changed)
+      || self.watched.iter().any(|(path, (_, mode))| {
+        matches!(mode, RecursiveMode::Recursive) && changed.starts_with(path)
+      })
+  }
+}
+
+impl Compiler {
+  /// Build and rebuild the compilation when the dependencies are changed
+  ///
+  /// The `handler` is called with the result of each build, return `ControlFlow::Break`
+  /// to stop watching.
+  pub async fn watch<F>(&mut self, options: WatchOptions, mut handler: F) -> Result<()>
+  where
+    F: FnMut(&mut Compiler, Result<()>) -> ControlFlow<()>,
+  {
+    let (mut watcher, mut rx) = FileWatcher::new(&options)?;
+    let aggregate_timeout = Duration::from_millis(options.aggregate_timeout);
+
+    let result = self.build().await;
+    // hash the files before the first change, so the first touch is skipped
+    self.create_file_snapshot().await;
+    watcher.update(
+      &collect_watch_paths(&self.compilation),
+      self.input_filesystem.as_ref(),
+    )?;
+    if handler(self, result).is_break() {
+      return Ok(());
+    }
+
+    loop {
+      let mut changed_paths: HashSet<PathBuf> = HashSet::default();
+      // wait for the first change, then aggregate the changes in `aggregate_timeout`
+      while changed_paths.is_empty() {
+        let Some(event) = rx.recv().await else {
+          return Ok(());
+        };
+        collect_event_paths(event, &watcher, &mut changed_paths);
+      }
+      loop {
+        match tokio::time::timeout(aggregate_timeout, rx.recv()).await {
+          Ok(Some(event)) => collect_event_paths(event, &watcher, &mut changed_paths),
+          Ok(None) => return Ok(()),
+          Err(_) => break,
+        }
+      }
+
+      let (modified_files, removed_files): (Vec<_>, Vec<_>) = changed_paths
+        .into_iter()
+        .partition(|path| read_metadata(self.input_filesystem.as_ref(), path).is_some());
+      let result = self
+        .rebuild(
+          modified_files
+            .into_iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+          removed_files
+            .into_iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+        )
+        .await;
+      watcher.update(
+        &collect_watch_paths(&self.compilation),
+        self.input_filesystem.as_ref(),
+      )?;
+      if handler(self, result).is_break() {
+        return Ok(());
+      }
+    }
+  }
+}
+
+fn collect_event_paths(
+  event: notify::Result<Event>,
+  watcher: &FileWatcher,
+  changed_paths: &mut HashSet<PathBuf>,
+) {
+  let Ok(event) = event else {
+    return;
+  };
+  // the files are read by the compilation, skip the access events
+  if matches!(event.kind, EventKind::Access(_)) {
+    return;
+  }
+  changed_paths.extend(
+    event
+      .paths
+      .into_iter()
+      .filter(|path| watcher.is_affected(path)),
+  );
+}
@@ -16,7 +16,9 @@ use super::{DependencyCategory, DependencyId, DependencyType};
 use crate::create_exports_object_referenced;
 use crate::AsContextDependency;
 use crate::ExtendedReferencedExport;
+use crate::ForwardId;
 use crate::ImportAttributes;
+use crate::ImportPhase;
 use crate::ModuleLayer;
 use crate::RuntimeSpec;
 use crate::{ConnectionState, Context, ModuleGraph, UsedByExports};
@@ -63,6 +65,21 @@ pub trait Dependency:
     None
   }
 
+  fn get_phase(&self) -> ImportPhase {
+    ImportPhase::Evaluation
+  }
+
+  // the exports of the referenced module this dependency forwards to
+  fn forward_id(&self) -> ForwardId {
+    ForwardId::All
+  }
+
+  // the name under which this dependency re-exports the referenced module,
+  // only named re-exports can be built lazily by lazy barrel
+  fn lazy_export_name(&self) -> Option<&Atom> {
+    None
+  }
+
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
     None
   }
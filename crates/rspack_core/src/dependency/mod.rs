@@ -42,8 +42,8 @@ pub use static_exports_dependency::{StaticExportsDependency, StaticExportsSpec};
 use swc_core::ecma::atoms::Atom;
 
 use crate::{
-  ConnectionState, ModuleGraph, ModuleGraphConnection, ModuleIdentifier, ReferencedExport,
-  RuntimeSpec,
+  ConnectionState, InlinableValue, ModuleGraph, ModuleGraphConnection, ModuleIdentifier,
+  ReferencedExport, RuntimeSpec,
 };
 
 #[derive(Debug, Default)]
@@ -57,6 +57,7 @@ pub struct ExportSpec {
   pub hidden: Option<bool>,
   pub from: Option<ModuleGraphConnection>,
   pub from_export: Option<ModuleGraphConnection>,
+  pub inlinable: Option<InlinableValue>,
 }
 
 #[derive(Debug)]
@@ -182,3 +183,39 @@ impl ImportAttributes {
     self.0.insert(k, v)
   }
 }
+
+/// The phase of an import declaration, see https://github.com/tc39/proposal-source-phase-imports
+/// and https://github.com/tc39/proposal-defer-import-eval
+#[rspack_cacheable::cacheable]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImportPhase {
+  /// `import x from "./a.js"`
+  #[default]
+  Evaluation,
+  /// `import source x from "./a.wasm"`
+  Source,
+  /// `import defer * as ns from "./a.js"`
+  Defer,
+}
+
+impl ImportPhase {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ImportPhase::Evaluation => "evaluation",
+      ImportPhase::Source => "source",
+      ImportPhase::Defer => "defer",
+    }
+  }
+}
+
+/// The exports of the referenced module that a dependency forwards to, used by lazy barrel
+/// to decide which re-exports need to be built.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ForwardId {
+  /// `import "./a.js"`, no export is referenced
+  Empty,
+  /// `import { a } from "./a.js"`
+  Id(Atom),
+  /// `import * as a from "./a.js"`, `require("./a.js")` etc.
+  All,
+}
@@ -406,6 +406,34 @@ pub fn import_statement(
   (import_content, String::new())
 }
 
+/// Like [import_statement], but the imported module is only evaluated when the namespace object is
+/// accessed for the first time.
+pub fn deferred_import_statement(
+  compilation: &Compilation,
+  runtime_requirements: &mut RuntimeGlobals,
+  id: &DependencyId,
+  request: &str,
+) -> String {
+  if compilation
+    .get_module_graph()
+    .module_identifier_by_dependency_id(id)
+    .is_none()
+  {
+    return missing_module_statement(request);
+  };
+
+  let module_id_expr = module_id(compilation, id, request, false);
+
+  runtime_requirements.insert(RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT);
+
+  let import_var = compilation.get_import_var(id);
+
+  format!(
+    "/* ESM import defer */var {import_var} = /*#__PURE__*/{}({module_id_expr});\n",
+    RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT
+  )
+}
+
 pub fn module_namespace_promise(
   code_generatable_context: &mut TemplateContext,
   dep_id: &DependencyId,
@@ -880,6 +880,7 @@ impl ExportInfo {
     data.target_is_set = false;
     data.target.clear();
     data.terminal_binding = false;
+    data.inlinable = None;
   }
 
   pub fn name<'a>(&self, mg: &'a ModuleGraph) -> Option<&'a Atom> {
@@ -918,6 +919,28 @@ impl ExportInfo {
     self.as_export_info_mut(mg).terminal_binding = value;
   }
 
+  pub fn inlinable<'a>(&self, mg: &'a ModuleGraph) -> Option<&'a InlinableValue> {
+    self.as_export_info(mg).inlinable.as_ref()
+  }
+
+  pub fn set_inlinable(&self, mg: &mut ModuleGraph, value: Option<InlinableValue>) {
+    self.as_export_info_mut(mg).inlinable = value;
+  }
+
+  /// Get the inlinable value of this export, following re-exports to the declaring module.
+  pub fn get_inlinable_value<'a>(&self, mg: &'a ModuleGraph) -> Option<&'a InlinableValue> {
+    if let Some(value) = self.inlinable(mg) {
+      return Some(value);
+    }
+    let target = self.get_target(mg)?;
+    let [name] = target.export.as_deref()? else {
+      return None;
+    };
+    mg.get_exports_info(&target.module)
+      .get_read_only_export_info(mg, name)
+      .inlinable(mg)
+  }
+
   pub fn exports_info_owned(&self, mg: &ModuleGraph) -> bool {
     self.as_export_info(mg).exports_info_owned
   }
@@ -1453,6 +1476,7 @@ impl ExportInfo {
     self.get_used(mg, runtime).dyn_hash(hasher);
     data.provided.dyn_hash(hasher);
     data.terminal_binding.dyn_hash(hasher);
+    data.inlinable.dyn_hash(hasher);
     if let Some(exports_info) = data.exports_info
       && !visited.contains(&exports_info)
     {
@@ -1473,6 +1497,8 @@ pub struct ExportInfoData {
   provided: Option<ExportInfoProvided>,
   can_mangle_provide: Option<bool>,
   terminal_binding: bool,
+  /// the value of `export const a = <literal>`, see [InlinableValue]
+  inlinable: Option<InlinableValue>,
   id: ExportInfo,
   exports_info: Option<ExportsInfo>,
   exports_info_owned: bool,
@@ -1490,6 +1516,30 @@ pub enum ExportInfoProvided {
   Null,
 }
 
+/// The primitive value of an export declared as `export const a = <literal>`,
+/// imports of it can be replaced by the value itself.
+#[cacheable]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum InlinableValue {
+  Null,
+  Boolean(bool),
+  /// the source code of the number literal
+  Number(String),
+  /// the source code of the string literal, including the quotes
+  String(String),
+}
+
+impl InlinableValue {
+  pub fn render(&self) -> Cow<'static, str> {
+    match self {
+      InlinableValue::Null => Cow::Borrowed("null"),
+      InlinableValue::Boolean(true) => Cow::Borrowed("true"),
+      InlinableValue::Boolean(false) => Cow::Borrowed("false"),
+      InlinableValue::Number(code) | InlinableValue::String(code) => Cow::Owned(code.clone()),
+    }
+  }
+}
+
 #[derive(Debug, Hash, Clone, Copy)]
 pub enum ExportProvided {
   True,
@@ -1559,6 +1609,7 @@ impl ExportInfoData {
 
     let provided = init_from.and_then(|init_from| init_from.provided);
     let terminal_binding = init_from.is_some_and(|init_from| init_from.terminal_binding);
+    let inlinable = init_from.and_then(|init_from| init_from.inlinable.clone());
     let can_mangle_provide = init_from.and_then(|init_from| init_from.can_mangle_provide);
     let can_mangle_use = init_from.and_then(|init_from| init_from.can_mangle_use);
 
@@ -1600,6 +1651,7 @@ impl ExportInfoData {
       provided,
       can_mangle_provide,
       terminal_binding,
+      inlinable,
       target_is_set: init_from.map(|init| init.target_is_set).unwrap_or_default(),
       id: ExportInfo::new(),
       exports_info: None,
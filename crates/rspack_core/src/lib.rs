@@ -175,10 +175,13 @@ pub enum ModuleType {
   JsEsm,
   WasmSync,
   WasmAsync,
+  WasmSource,
   AssetInline,
   AssetResource,
   AssetSource,
+  AssetBytes,
   Asset,
+  CssStyleSheet,
   Runtime,
   Remote,
   Fallback,
@@ -208,7 +211,10 @@ impl ModuleType {
   }
 
   pub fn is_wasm_like(&self) -> bool {
-    matches!(self, ModuleType::WasmSync | ModuleType::WasmAsync)
+    matches!(
+      self,
+      ModuleType::WasmSync | ModuleType::WasmAsync | ModuleType::WasmSource
+    )
   }
 
   pub fn is_js_auto(&self) -> bool {
@@ -225,7 +231,7 @@ impl ModuleType {
 
   /// Webpack arbitrary determines the binary type from [NormalModule.binary](https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/NormalModule.js#L302)
   pub fn is_binary(&self) -> bool {
-    self.is_asset_like() || self.is_wasm_like()
+    self.is_asset_like() || self.is_wasm_like() || matches!(self, ModuleType::AssetBytes)
   }
 
   pub fn as_str(&self) -> &'static str {
@@ -242,11 +248,14 @@ impl ModuleType {
 
       ModuleType::WasmSync => "webassembly/sync",
       ModuleType::WasmAsync => "webassembly/async",
+      ModuleType::WasmSource => "webassembly/source",
 
       ModuleType::Asset => "asset",
       ModuleType::AssetSource => "asset/source",
       ModuleType::AssetResource => "asset/resource",
       ModuleType::AssetInline => "asset/inline",
+      ModuleType::AssetBytes => "asset/bytes",
+      ModuleType::CssStyleSheet => "css/style-sheet",
       ModuleType::Runtime => "runtime",
       ModuleType::Remote => "remote-module",
       ModuleType::Fallback => "fallback-module",
@@ -282,11 +291,14 @@ impl From<&str> for ModuleType {
 
       "webassembly/sync" => Self::WasmSync,
       "webassembly/async" => Self::WasmAsync,
+      "webassembly/source" => Self::WasmSource,
 
       "asset" => Self::Asset,
       "asset/resource" => Self::AssetResource,
       "asset/source" => Self::AssetSource,
       "asset/inline" => Self::AssetInline,
+      "asset/bytes" => Self::AssetBytes,
+      "css/style-sheet" => Self::CssStyleSheet,
 
       custom => Self::Custom(custom.into()),
     }
@@ -12,7 +12,7 @@ use dashmap::DashMap;
 use derive_more::Debug;
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsMap, AsOption, AsPreset, Skip},
+  with::{AsMap, AsOption, AsPortable, AsPreset, Skip},
 };
 use rspack_collections::{Identifiable, IdentifierSet};
 use rspack_error::{error, Diagnosable, Diagnostic, DiagnosticExt, NodeError, Result, Severity};
@@ -106,10 +106,13 @@ pub struct NormalModule {
   /// Context of this module
   context: Box<Context>,
   /// Request with loaders from config
+  #[cacheable(with=AsPortable)]
   request: String,
   /// Request intended by user (without loaders from config)
+  #[cacheable(with=AsPortable)]
   user_request: String,
   /// Request without resolving
+  #[cacheable(with=AsPortable)]
   raw_request: String,
   /// The resolved module type of a module
   module_type: ModuleType,
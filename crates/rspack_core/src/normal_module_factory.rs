@@ -1,6 +1,7 @@
 use std::sync::LazyLock;
 use std::{borrow::Cow, sync::Arc};
 
+use async_recursion::async_recursion;
 use regex::Regex;
 use rspack_cacheable::cacheable;
 use rspack_error::{error, Result};
@@ -16,11 +17,12 @@ use crate::{
   stringify_loaders_and_resource, AssetInlineGeneratorOptions, AssetResourceGeneratorOptions,
   BoxLoader, BoxModule, CompilerOptions, Context, CssAutoGeneratorOptions, CssAutoParserOptions,
   CssModuleGeneratorOptions, CssModuleParserOptions, Dependency, DependencyCategory,
-  DependencyRange, FuncUseCtx, GeneratorOptions, ModuleExt, ModuleFactory, ModuleFactoryCreateData,
-  ModuleFactoryResult, ModuleIdentifier, ModuleLayer, ModuleRuleEffect, ModuleRuleEnforce,
-  ModuleRuleUse, ModuleRuleUseLoader, ModuleType, NormalModule, ParserAndGenerator, ParserOptions,
-  RawModule, Resolve, ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, Resolver,
-  ResolverFactory, ResourceData, ResourceParsedData, RunnerContext, SharedPluginDriver,
+  DependencyRange, FuncUseCtx, GeneratorOptions, ImportPhase, ModuleExt, ModuleFactory,
+  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleLayer, ModuleRule,
+  ModuleRuleEffect, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType,
+  NormalModule, ParserAndGenerator, ParserOptions, RawModule, Resolve, ResolveArgs,
+  ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory, ResourceData,
+  ResourceParsedData, RunnerContext, SharedPluginDriver,
 };
 
 define_hook!(NormalModuleFactoryBeforeResolve: AsyncSeriesBail(data: &mut ModuleFactoryCreateData) -> bool);
@@ -145,6 +147,11 @@ impl NormalModuleFactory {
     let dependency_category = *dependency.category();
     let dependency_source_span = dependency.source_span();
     let dependency_optional = dependency.get_optional();
+    let attribute_type = dependency
+      .get_attributes()
+      .and_then(|attributes| attributes.get("type"))
+      .map(ToOwned::to_owned);
+    let dependency_phase = dependency.get_phase();
 
     let importer = data.issuer_identifier;
     let raw_request = dependency.request().to_owned();
@@ -519,8 +526,32 @@ impl NormalModuleFactory {
 
     let file_dependency = resource_data.resource_path.clone();
 
+    let attribute_module_type = self
+      .calculate_module_type_by_attributes(
+        attribute_type.as_deref(),
+        &resource_data,
+        !loaders.is_empty(),
+      )
+      .await?;
+    let resolved_module_type = match attribute_module_type {
+      Some(module_type) => {
+        let rule_module_type = resolved_module_rules
+          .iter()
+          .rev()
+          .find_map(|module_rule| module_rule.r#type)
+          .or(match_module_type);
+        Self::check_module_type_by_attributes(
+          module_type,
+          rule_module_type,
+          attribute_type.as_deref().unwrap_or_default(),
+          &resource_data,
+        )?;
+        module_type
+      }
+      None => self.calculate_module_type(match_module_type, &resolved_module_rules),
+    };
     let resolved_module_type =
-      self.calculate_module_type(match_module_type, &resolved_module_rules);
+      Self::calculate_module_type_by_phase(resolved_module_type, dependency_phase, &resource_data)?;
     let resolved_module_layer =
       self.calculate_module_layer(data.issuer_layer.as_ref(), &resolved_module_rules);
     if resolved_module_layer.is_some() && !self.options.experiments.layers {
@@ -822,6 +853,101 @@ impl NormalModuleFactory {
     resolved_module_type
   }
 
+  /// Select the module type by the `type` import attribute, see
+  /// https://html.spec.whatwg.org/multipage/webappapis.html#module-type-allowed
+  async fn calculate_module_type_by_attributes(
+    &self,
+    attribute_type: Option<&str>,
+    resource_data: &ResourceData,
+    has_loaders: bool,
+  ) -> Result<Option<ModuleType>> {
+    let Some(attribute_type) = attribute_type else {
+      return Ok(None);
+    };
+    let (module_type, expected_mimetype) = match attribute_type {
+      "json" => (ModuleType::Json, Some("application/json")),
+      "css" => (ModuleType::CssStyleSheet, Some("text/css")),
+      "text" => (ModuleType::AssetSource, None),
+      "bytes" => (ModuleType::AssetBytes, None),
+      _ => {
+        // other types are left to the rules with `with` conditions
+        if has_rule_with_attribute_type(&self.options.module.rules, attribute_type).await? {
+          return Ok(None);
+        }
+        return Err(error!(
+          r#"Unsupported import attribute type "{attribute_type}", expected one of "json", "css", "text" or "bytes""#
+        ));
+      }
+    };
+    // loaders are responsible for transforming the resource into the expected type
+    if let Some(expected_mimetype) = expected_mimetype
+      && !has_loaders
+    {
+      let mimetype = resource_data.mimetype.as_deref().or_else(|| {
+        resource_data
+          .resource_path
+          .as_ref()
+          .and_then(|path| mime_guess::from_path(path).first_raw())
+      });
+      if let Some(mimetype) = mimetype
+        && mimetype != expected_mimetype
+      {
+        return Err(error!(
+          r#"Module "{}" has the MIME type "{mimetype}", which does not match the import attribute type "{attribute_type}" (expected "{expected_mimetype}")"#,
+          resource_data.resource
+        ));
+      }
+    }
+    Ok(Some(module_type))
+  }
+
+  /// The module type set by rules or the match resource should agree with the module type
+  /// selected by the `type` import attribute. `text` and `bytes` imports get the raw contents
+  /// of any module, so only `json` and `css` imports are checked.
+  fn check_module_type_by_attributes(
+    attribute_module_type: ModuleType,
+    rule_module_type: Option<ModuleType>,
+    attribute_type: &str,
+    resource_data: &ResourceData,
+  ) -> Result<()> {
+    let Some(rule_module_type) = rule_module_type else {
+      return Ok(());
+    };
+    let matched = match attribute_module_type {
+      ModuleType::Json => matches!(rule_module_type, ModuleType::Json),
+      ModuleType::CssStyleSheet => {
+        rule_module_type.is_css_like() || matches!(rule_module_type, ModuleType::CssStyleSheet)
+      }
+      _ => true,
+    };
+    if !matched {
+      return Err(error!(
+        r#"Module "{}" has the module type "{rule_module_type}", which does not match the import attribute type "{attribute_type}" (expected "{attribute_module_type}")"#,
+        resource_data.resource
+      ));
+    }
+    Ok(())
+  }
+
+  /// Source phase imports get the source representation of the module instead of its evaluated
+  /// namespace, which is only defined for WebAssembly modules for now.
+  fn calculate_module_type_by_phase(
+    module_type: ModuleType,
+    phase: ImportPhase,
+    resource_data: &ResourceData,
+  ) -> Result<ModuleType> {
+    match (phase, module_type) {
+      (ImportPhase::Source, ModuleType::WasmAsync | ModuleType::WasmSource) => {
+        Ok(ModuleType::WasmSource)
+      }
+      (ImportPhase::Source, module_type) => Err(error!(
+        r#"Source phase imports are only supported for WebAssembly modules, but "{}" has the module type "{module_type}""#,
+        resource_data.resource
+      )),
+      (_, module_type) => Ok(module_type),
+    }
+  }
+
   fn calculate_module_layer(
     &self,
     issuer_layer: Option<&ModuleLayer>,
@@ -926,6 +1052,24 @@ pub struct NormalModuleCreateData {
   pub side_effects: Option<bool>,
 }
 
+/// Whether the `type` import attribute is matched by the `with` condition of any rule.
+#[async_recursion]
+async fn has_rule_with_attribute_type(rules: &[ModuleRule], attribute_type: &str) -> Result<bool> {
+  for rule in rules {
+    if let Some(matcher) = rule.with.as_ref().and_then(|with| with.get("type"))
+      && matcher.try_match(attribute_type.into()).await?
+    {
+      return Ok(true);
+    }
+    for rules in [&rule.rules, &rule.one_of].into_iter().flatten() {
+      if has_rule_with_attribute_type(rules, attribute_type).await? {
+        return Ok(true);
+      }
+    }
+  }
+  Ok(false)
+}
+
 #[test]
 fn match_webpack_ext() {
   assert!(MATCH_WEBPACK_EXT_REGEX.is_match("foo.webpack[type/javascript]"));
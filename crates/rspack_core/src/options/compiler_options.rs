@@ -1,3 +1,5 @@
+use rspack_paths::Utf8PathBuf;
+
 use crate::{
   CacheOptions, Context, Experiments, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
   Resolve, StatsOptions,
@@ -20,6 +22,10 @@ pub struct CompilerOptions {
   pub profile: bool,
   pub amd: Option<String>,
   pub bail: bool,
+  /// The file to read the records of module and chunk ids from
+  pub records_input_path: Option<Utf8PathBuf>,
+  /// The file to write the records of module and chunk ids to
+  pub records_output_path: Option<Utf8PathBuf>,
   pub __references: References,
 }
 
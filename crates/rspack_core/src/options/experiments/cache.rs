@@ -4,5 +4,5 @@ use crate::cache::persistent::PersistentCacheOptions;
 pub enum CacheOptions {
   Disabled,
   Memory,
-  Persistent(PersistentCacheOptions),
+  Persistent(Box<PersistentCacheOptions>),
 }
@@ -13,6 +13,8 @@ pub struct Experiments {
   pub incremental: IncrementalPasses,
   pub parallel_code_splitting: bool,
   pub top_level_await: bool,
+  pub lazy_barrel: bool,
+  pub inline_const: bool,
   pub rspack_future: RspackFuture,
   pub cache: ExperimentCacheOptions,
 }
@@ -257,6 +257,17 @@ bitflags! {
     // amd module support
     const AMD_DEFINE = 1 << 67;
     const AMD_OPTIONS = 1 << 68;
+
+    /**
+     * compile a wasm module from id and hash into a `WebAssembly.Module`
+     */
+    const COMPILE_WASM = 1 << 69;
+
+    /**
+     * create a namespace object which evaluates the module on first access
+     * Signature: (moduleId: Id) => Proxy
+     */
+    const MAKE_DEFERRED_NAMESPACE_OBJECT = 1 << 70;
   }
 }
 
@@ -309,6 +320,7 @@ impl RuntimeGlobals {
       R::GLOBAL => "__webpack_require__.g",
       R::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime",
       R::INSTANTIATE_WASM => "__webpack_require__.v",
+      R::COMPILE_WASM => "__webpack_require__.vs",
       R::ASYNC_MODULE => "__webpack_require__.a",
       R::BASE_URI => "__webpack_require__.b",
       R::STARTUP_ENTRYPOINT => "__webpack_require__.X",
@@ -324,6 +336,7 @@ impl RuntimeGlobals {
       R::EXPORTS => "__webpack_exports__",
       R::COMPAT_GET_DEFAULT_EXPORT => "__webpack_require__.n",
       R::CREATE_FAKE_NAMESPACE_OBJECT => "__webpack_require__.t",
+      R::MAKE_DEFERRED_NAMESPACE_OBJECT => "__webpack_require__.z",
       R::ESM_MODULE_DECORATOR => "__webpack_require__.hmd",
       R::NODE_MODULE_DECORATOR => "__webpack_require__.nmd",
       R::SYSTEM_CONTEXT => "__webpack_require__.y",
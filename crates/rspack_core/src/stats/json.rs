@@ -0,0 +1,187 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use rspack_error::{error, Result};
+use rspack_macros::rspack_version;
+use rspack_sources::BoxSource;
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use super::{ExtendedStatsOptions, Stats, StatsSourceTypeSize};
+use crate::RuntimeSpec;
+
+pub(super) fn serialize_display<T: Display, S: Serializer>(
+  value: &T,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  serializer.collect_str(value)
+}
+
+pub(super) fn serialize_optional_display<T: Display, S: Serializer>(
+  value: &Option<T>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  match value {
+    Some(value) => serializer.collect_str(value),
+    None => serializer.serialize_none(),
+  }
+}
+
+pub(super) fn serialize_optional_display_vec<T: Display, S: Serializer>(
+  value: &Option<Vec<T>>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  match value {
+    Some(value) => serializer.collect_seq(value.iter().map(|item| item.to_string())),
+    None => serializer.serialize_none(),
+  }
+}
+
+// the signature is required by `serialize_with`
+#[allow(clippy::ref_option_ref)]
+pub(super) fn serialize_optional_source<S: Serializer>(
+  value: &Option<&BoxSource>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  match value {
+    Some(source) => serializer.serialize_str(&source.source()),
+    None => serializer.serialize_none(),
+  }
+}
+
+/// Serialize the map with the displayed keys, the keys are sorted to keep the output stable
+pub(super) fn serialize_display_map<K: Display, V: Serialize, S: Serializer>(
+  value: &HashMap<K, V>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  let sorted = value
+    .iter()
+    .map(|(key, value)| (key.to_string(), value))
+    .collect::<BTreeMap<_, _>>();
+  serializer.collect_map(sorted)
+}
+
+pub(super) fn serialize_runtime<S: Serializer>(
+  value: &RuntimeSpec,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  let mut runtime = value.iter().map(|r| r.as_ref()).collect::<Vec<&str>>();
+  runtime.sort_unstable();
+  serializer.collect_seq(runtime)
+}
+
+pub(super) fn serialize_source_type_sizes<S: Serializer>(
+  value: &[StatsSourceTypeSize],
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  let sorted = value
+    .iter()
+    .map(|size| (size.source_type.to_string(), size.size))
+    .collect::<BTreeMap<_, _>>();
+  serializer.collect_map(sorted)
+}
+
+/// The version of webpack which rspack is compatible with, same as `webpackVersion`
+/// in the package.json of `@rspack/core`
+const WEBPACK_VERSION: &str = "5.75.0";
+
+fn to_value(value: impl Serialize) -> Result<Value> {
+  serde_json::to_value(value).map_err(|e| error!("failed to serialize stats: {e}"))
+}
+
+impl Stats<'_> {
+  /// Generate the webpack compatible stats json, the sections are picked by the options
+  pub fn to_json(&self, options: &ExtendedStatsOptions) -> Result<Value> {
+    let mut json = Map::new();
+
+    if options.version {
+      json.insert("version".into(), WEBPACK_VERSION.into());
+      json.insert("rspackVersion".into(), rspack_version!().into());
+    }
+
+    if options.hash
+      && let Some(hash) = self.get_hash()
+    {
+      json.insert("hash".into(), hash.into());
+    }
+
+    if options.timings
+      && let Some(time) = self.get_time()
+    {
+      json.insert("time".into(), (time as u64).into());
+    }
+
+    if options.built_at
+      && let Some(built_at) = self.get_built_at()
+    {
+      json.insert("builtAt".into(), (built_at as u64).into());
+    }
+
+    if options.public_path {
+      json.insert("publicPath".into(), self.get_public_path()?.into());
+    }
+
+    if options.output_path {
+      json.insert(
+        "outputPath".into(),
+        self.compilation.options.output.path.as_str().into(),
+      );
+    }
+
+    if options.assets {
+      let (assets, assets_by_chunk_name) = self.get_assets();
+      json.insert("assets".into(), to_value(assets)?);
+      let assets_by_chunk_name = assets_by_chunk_name
+        .into_iter()
+        .map(|item| (item.name, item.files))
+        .collect::<BTreeMap<_, _>>();
+      json.insert("assetsByChunkName".into(), to_value(assets_by_chunk_name)?);
+    }
+
+    if options.chunks {
+      let chunks = self.get_chunks(options, |chunks| to_value(chunks))??;
+      json.insert("chunks".into(), chunks);
+    }
+
+    if options.modules {
+      let modules = self.get_modules(options, |modules| to_value(modules))??;
+      json.insert("modules".into(), modules);
+    }
+
+    if self.should_show_entrypoints(options) {
+      let entrypoints = self
+        .get_entrypoints(options.chunk_group_auxiliary, options.chunk_group_children)
+        .into_iter()
+        .map(|group| (group.name.clone(), group))
+        .collect::<BTreeMap<_, _>>();
+      json.insert("entrypoints".into(), to_value(entrypoints)?);
+    }
+
+    if options.chunk_groups {
+      let named_chunk_groups = self
+        .get_named_chunk_groups(options.chunk_group_auxiliary, options.chunk_group_children)
+        .into_iter()
+        .map(|group| (group.name.clone(), group))
+        .collect::<BTreeMap<_, _>>();
+      json.insert("namedChunkGroups".into(), to_value(named_chunk_groups)?);
+    }
+
+    let errors = self.get_errors();
+    if options.errors_count {
+      json.insert("errorsCount".into(), errors.len().into());
+    }
+    if options.errors {
+      json.insert("errors".into(), to_value(errors)?);
+    }
+
+    let warnings = self.get_warnings();
+    if options.warnings_count {
+      json.insert("warningsCount".into(), warnings.len().into());
+    }
+    if options.warnings {
+      json.insert("warnings".into(), to_value(warnings)?);
+    }
+
+    Ok(Value::Object(json))
+  }
+}
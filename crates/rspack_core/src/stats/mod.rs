@@ -11,15 +11,19 @@ use rspack_error::emitter::{
 use rspack_error::Result;
 use rustc_hash::FxHashMap as HashMap;
 
+mod json;
+mod printer;
 mod utils;
 pub use utils::*;
+// `serialize_with` of `StatsModule::source` expands to `&Option<&BoxSource>`
+#[allow(clippy::ref_option_ref)]
 mod r#struct;
 pub use r#struct::*;
 
 use crate::{
   BoxModule, BoxRuntimeModule, Chunk, ChunkGraph, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey,
-  Compilation, ExecutedRuntimeModule, LogType, ModuleGraph, ModuleIdentifier, ProvidedExports,
-  SourceType, UsedExports,
+  Compilation, ExecutedRuntimeModule, LogType, ModuleGraph, ModuleIdentifier, PathData,
+  ProvidedExports, PublicPath, SourceType, UsedExports,
 };
 
 #[derive(Debug, Clone)]
@@ -680,6 +684,58 @@ impl Stats<'_> {
     self.compilation.get_hash()
   }
 
+  /// The milliseconds from the start of compilation to the end of sealing
+  pub fn get_time(&self) -> Option<u128> {
+    self
+      .compilation
+      .end_time
+      .map(|end_time| (end_time - self.compilation.start_time).as_millis())
+  }
+
+  /// The unix timestamp in milliseconds of the end of sealing
+  pub fn get_built_at(&self) -> Option<u128> {
+    let end_time = self.compilation.end_time?;
+    let built_at = std::time::SystemTime::now().checked_sub(end_time.elapsed())?;
+    built_at
+      .duration_since(std::time::UNIX_EPOCH)
+      .ok()
+      .map(|duration| duration.as_millis())
+  }
+
+  /// The rendered `output.publicPath`, `"auto"` is kept as is
+  pub fn get_public_path(&self) -> Result<String> {
+    match &self.compilation.options.output.public_path {
+      PublicPath::Filename(filename) => self.compilation.get_path(filename, PathData::default()),
+      PublicPath::Auto => Ok("auto".to_string()),
+    }
+  }
+
+  /// Whether to show the entrypoints. Like webpack, `"auto"` hides the entrypoints
+  /// when there are more than 5 entrypoints, or each entrypoint only has a single
+  /// chunk with a single file, unless the chunk groups are shown.
+  pub fn should_show_entrypoints(&self, options: &ExtendedStatsOptions) -> bool {
+    match &options.entrypoints {
+      EntrypointsStatsOption::Bool(value) => *value,
+      EntrypointsStatsOption::String(value) if value == "auto" && !options.chunk_groups => {
+        let entrypoints = &self.compilation.entrypoints;
+        if entrypoints.len() > 5 {
+          return false;
+        }
+        options.chunk_group_children
+          || !entrypoints.values().all(|ukey| {
+            let group = self.compilation.chunk_group_by_ukey.expect_get(ukey);
+            let [chunk] = group.chunks.as_slice() else {
+              return false;
+            };
+            let chunk = self.compilation.chunk_by_ukey.expect_get(chunk);
+            chunk.files().len() == 1
+              && (!options.chunk_group_auxiliary || chunk.auxiliary_files().is_empty())
+          })
+      }
+      EntrypointsStatsOption::String(_) => true,
+    }
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn get_module<'a>(
     &'a self,
@@ -0,0 +1,485 @@
+use itertools::Itertools;
+use rspack_error::Result;
+use rspack_macros::rspack_version;
+
+use super::{
+  ExtendedStatsOptions, Stats, StatsAsset, StatsChunk, StatsChunkGroup, StatsModule,
+  StatsUsedExports,
+};
+use crate::ModuleType;
+
+/// The max number of assets and modules to print, the rest will be collapsed
+const SPACE: usize = 15;
+
+#[derive(Debug, Clone, Copy)]
+struct Colors {
+  enabled: bool,
+}
+
+impl Colors {
+  fn paint(&self, code: &str, text: impl AsRef<str>) -> String {
+    if self.enabled {
+      format!("\u{1b}[{code}m{}\u{1b}[0m", text.as_ref())
+    } else {
+      text.as_ref().to_string()
+    }
+  }
+
+  fn bold(&self, text: impl AsRef<str>) -> String {
+    self.paint("1", text)
+  }
+
+  fn green(&self, text: impl AsRef<str>) -> String {
+    self.paint("1;32", text)
+  }
+
+  fn yellow(&self, text: impl AsRef<str>) -> String {
+    self.paint("1;33", text)
+  }
+
+  fn red(&self, text: impl AsRef<str>) -> String {
+    self.paint("1;31", text)
+  }
+}
+
+/// Format the size in bytes with 3 significant digits, same as `formatSize` of webpack
+fn format_size(size: f64) -> String {
+  if size <= 0.0 {
+    return "0 bytes".to_string();
+  }
+  let abbreviations = ["bytes", "KiB", "MiB", "GiB"];
+  let index = ((size.ln() / 1024f64.ln()).floor() as usize).min(abbreviations.len() - 1);
+  let value = size / 1024f64.powi(index as i32);
+  let integer_digits = (value.log10().floor() as i32 + 1).max(1);
+  let decimals = (3 - integer_digits).max(0) as usize;
+  let mut formatted = format!("{value:.decimals$}");
+  if formatted.contains('.') {
+    formatted = formatted
+      .trim_end_matches('0')
+      .trim_end_matches('.')
+      .to_string();
+  }
+  format!("{formatted} {}", abbreviations[index])
+}
+
+fn plural(count: usize, singular: &str, plural: &str) -> String {
+  if count == 1 {
+    format!("{count} {singular}")
+  } else {
+    format!("{count} {plural}")
+  }
+}
+
+fn print_assets(assets: &[StatsAsset], colors: Colors, lines: &mut Vec<String>) {
+  let (shown, hidden) = if assets.len() > SPACE {
+    assets.split_at(SPACE - 1)
+  } else {
+    (assets, &[][..])
+  };
+  for asset in shown {
+    let mut line = format!("asset {} ", colors.green(&asset.name));
+    if asset.info.is_over_size_limit == Some(true) {
+      line.push_str(&colors.yellow(format_size(asset.size)));
+      line.push_str(&colors.yellow(" [big]"));
+    } else {
+      line.push_str(&format_size(asset.size));
+    }
+    if asset.emitted {
+      line.push_str(&colors.green(" [emitted]"));
+    }
+    if asset.info.immutable == Some(true) {
+      line.push_str(" [immutable]");
+    }
+    if asset.info.minimized == Some(true) {
+      line.push_str(" [minimized]");
+    }
+    if !asset.chunk_names.is_empty() {
+      line.push_str(&format!(" (name: {})", asset.chunk_names.join(", ")));
+    }
+    if !asset.chunk_id_hints.is_empty() {
+      line.push_str(&format!(" (id hint: {})", asset.chunk_id_hints.join(", ")));
+    }
+    lines.push(line);
+  }
+  if !hidden.is_empty() {
+    let size = hidden.iter().map(|asset| asset.size).sum();
+    lines.push(format!(
+      "+ {} {}",
+      plural(hidden.len(), "asset", "assets"),
+      format_size(size)
+    ));
+  }
+}
+
+fn print_chunk_group(kind: &str, group: &StatsChunkGroup, colors: Colors, lines: &mut Vec<String>) {
+  let mut line = format!(
+    "{kind} {} {}",
+    colors.bold(&group.name),
+    format_size(group.assets_size as f64)
+  );
+  let auxiliary_assets = group.auxiliary_assets.as_deref().unwrap_or_default();
+  if let Some(auxiliary_size) = group.auxiliary_assets_size
+    && auxiliary_size > 0
+  {
+    line.push_str(&format!(" ({})", format_size(auxiliary_size as f64)));
+  }
+  line.push_str(" =");
+  for asset in &group.assets {
+    line.push(' ');
+    line.push_str(&colors.green(&asset.name));
+  }
+  if !auxiliary_assets.is_empty() {
+    line.push_str(&format!(
+      " {}",
+      plural(
+        auxiliary_assets.len(),
+        "auxiliary asset",
+        "auxiliary assets"
+      )
+    ));
+  }
+  lines.push(line);
+}
+
+fn print_sizes(size: f64, sizes: &[(String, f64)]) -> String {
+  if sizes.len() > 1 {
+    sizes
+      .iter()
+      .map(|(source_type, size)| format!("{} ({source_type})", format_size(*size)))
+      .join(" ")
+  } else {
+    format_size(size)
+  }
+}
+
+fn print_chunks(
+  chunks: &[StatsChunk],
+  options: &ExtendedStatsOptions,
+  colors: Colors,
+) -> Vec<String> {
+  let mut lines = vec![];
+  for chunk in chunks {
+    let mut line = "chunk".to_string();
+    if options.ids
+      && let Some(id) = &chunk.id
+    {
+      line.push_str(&format!(" {{{}}}", colors.yellow(id)));
+    }
+    if !chunk.runtime.is_empty() {
+      line.push_str(&format!(
+        " (runtime: {})",
+        chunk.runtime.iter().sorted().join(", ")
+      ));
+    }
+    for file in &chunk.files {
+      line.push(' ');
+      line.push_str(&colors.green(file));
+    }
+    if !chunk.names.is_empty() {
+      line.push_str(&format!(" ({})", chunk.names.join(", ")));
+    }
+    let sizes = chunk
+      .sizes
+      .iter()
+      .map(|(source_type, size)| (source_type.to_string(), *size))
+      .sorted_by(|a, b| a.0.cmp(&b.0))
+      .collect::<Vec<_>>();
+    line.push(' ');
+    line.push_str(&print_sizes(chunk.size, &sizes));
+    if chunk.entry {
+      line.push_str(&colors.yellow(" [entry]"));
+    } else if chunk.initial {
+      line.push_str(&colors.yellow(" [initial]"));
+    }
+    if chunk.rendered {
+      line.push_str(&colors.green(" [rendered]"));
+    }
+    lines.push(line);
+  }
+  lines
+}
+
+fn print_module(
+  module: &StatsModule,
+  options: &ExtendedStatsOptions,
+  colors: Colors,
+  indent: &str,
+  lines: &mut Vec<String>,
+) {
+  let mut line = format!(
+    "{indent}{}",
+    colors.bold(module.name.as_deref().unwrap_or_default())
+  );
+  if options.ids
+    && let Some(id) = module.id
+  {
+    line.push_str(&format!(" [{}]", colors.yellow(id)));
+  }
+  let sizes = module
+    .sizes
+    .iter()
+    .map(|size| (size.source_type.to_string(), size.size))
+    .sorted_by(|a, b| a.0.cmp(&b.0))
+    .collect::<Vec<_>>();
+  line.push(' ');
+  line.push_str(&print_sizes(module.size, &sizes));
+  if module.built {
+    line.push_str(&colors.green(" [built]"));
+  }
+  if module.code_generated {
+    line.push_str(&colors.green(" [code generated]"));
+  }
+  if module.failed == Some(true) {
+    line.push_str(&colors.red(" [failed]"));
+  }
+  if let Some(provided_exports) = &module.provided_exports {
+    if provided_exports.is_empty() {
+      line.push_str(" [no exports]");
+    } else {
+      line.push_str(&format!(
+        " [exports: {}]",
+        provided_exports.iter().join(", ")
+      ));
+    }
+  }
+  match &module.used_exports {
+    Some(StatsUsedExports::Bool(false)) => line.push_str(" [no exports used]"),
+    Some(StatsUsedExports::Vec(used_exports))
+      if module
+        .provided_exports
+        .as_ref()
+        .is_none_or(|provided| provided.len() != used_exports.len()) =>
+    {
+      if used_exports.is_empty() {
+        line.push_str(" [no exports used]");
+      } else {
+        line.push_str(&format!(
+          " [only some exports used: {}]",
+          used_exports.iter().join(", ")
+        ));
+      }
+    }
+    _ => {}
+  }
+  if options.depth
+    && let Some(depth) = module.depth
+  {
+    line.push_str(&format!(" [depth {depth}]"));
+  }
+  lines.push(line);
+
+  for reason in module.reasons.iter().flatten() {
+    let reason = [
+      reason.r#type.map(|r| r.to_string()),
+      reason.module_name.as_ref().map(|m| colors.bold(m)),
+      reason.user_request.map(|r| r.to_string()),
+      reason.loc.clone(),
+    ]
+    .into_iter()
+    .flatten()
+    .join(" ");
+    lines.push(format!("{indent}  {reason}"));
+  }
+  for bailout in module.optimization_bailout.iter().flat_map(|b| b.iter()) {
+    lines.push(format!("{indent}  {}", colors.yellow(bailout)));
+  }
+}
+
+/// Get the group of modules by path, like `./src/` of `./src/index.js`
+fn get_module_path_group(name: &str) -> Option<&str> {
+  let rest = name.strip_prefix("./")?;
+  let index = rest.find('/')?;
+  Some(&name[..index + 3])
+}
+
+fn print_modules(
+  modules: &[StatsModule],
+  options: &ExtendedStatsOptions,
+  colors: Colors,
+) -> Vec<String> {
+  let mut lines = vec![];
+  let (runtime_modules, modules): (Vec<_>, Vec<_>) = modules
+    .iter()
+    .filter(|module| module.orphan != Some(true))
+    .partition(|module| module.module_type == ModuleType::Runtime);
+
+  if !runtime_modules.is_empty() {
+    let size = runtime_modules.iter().map(|module| module.size).sum();
+    lines.push(format!(
+      "{} {} {}",
+      colors.bold("runtime modules"),
+      format_size(size),
+      plural(runtime_modules.len(), "module", "modules")
+    ));
+  }
+
+  if modules.len() <= SPACE {
+    for module in modules {
+      print_module(module, options, colors, "", &mut lines);
+    }
+    return lines;
+  }
+
+  // group the modules by path when there are too many modules
+  let groups = modules.into_iter().into_group_map_by(|module| {
+    module
+      .name
+      .as_deref()
+      .and_then(get_module_path_group)
+      .map(ToOwned::to_owned)
+  });
+  let mut space = SPACE;
+  for (path, group) in groups.into_iter().sorted_by(|a, b| a.0.cmp(&b.0)) {
+    let Some(path) = path.filter(|_| group.len() > 1) else {
+      for module in group {
+        print_module(module, options, colors, "", &mut lines);
+        space = space.saturating_sub(1);
+      }
+      continue;
+    };
+    let size = group.iter().map(|module| module.size).sum();
+    let mut line = format!(
+      "{} {}",
+      colors.bold(format!("modules by path {path}")),
+      format_size(size)
+    );
+    if group.len() <= space {
+      lines.push(line);
+      for module in &group {
+        print_module(module, options, colors, "  ", &mut lines);
+      }
+      space -= group.len();
+    } else {
+      line.push(' ');
+      line.push_str(&plural(group.len(), "module", "modules"));
+      lines.push(line);
+      space = space.saturating_sub(1);
+    }
+  }
+  lines
+}
+
+impl Stats<'_> {
+  /// Print the summary line like `Rspack 1.0.0 compiled with 1 error in 100 ms`, the
+  /// parts are picked by the options, same as the `compilation.summary` printer of webpack
+  fn print_summary(
+    &self,
+    options: &ExtendedStatsOptions,
+    errors: usize,
+    warnings: usize,
+    colors: Colors,
+  ) -> Option<String> {
+    let errors_message =
+      (options.errors_count && errors > 0).then(|| colors.red(plural(errors, "error", "errors")));
+    let warnings_message = (options.warnings_count && warnings > 0)
+      .then(|| colors.yellow(plural(warnings, "warning", "warnings")));
+    let succeeded = options.errors_count && options.warnings_count && errors == 0 && warnings == 0;
+    let time_message = options
+      .timings
+      .then(|| self.get_time())
+      .flatten()
+      .map(|time| format!(" in {time} ms"));
+    let hash_message = options
+      .hash
+      .then(|| self.get_hash())
+      .flatten()
+      .map(|hash| format!(" ({hash})"));
+
+    let status = match (&errors_message, &warnings_message) {
+      (Some(errors), Some(warnings)) => format!("compiled with {errors} and {warnings}"),
+      (Some(errors), None) => format!("compiled with {errors}"),
+      (None, Some(warnings)) => format!("compiled with {warnings}"),
+      (None, None) if succeeded => format!("compiled {}", colors.green("successfully")),
+      (None, None) => "compiled".to_string(),
+    };
+    if !options.version
+      && errors_message.is_none()
+      && warnings_message.is_none()
+      && !succeeded
+      && time_message.is_none()
+      && hash_message.is_none()
+    {
+      return None;
+    }
+
+    let subject = if options.version {
+      format!("Rspack {}", rspack_version!())
+    } else {
+      "Rspack".to_string()
+    };
+    Some(format!(
+      "{subject} {status}{}{}",
+      time_message.unwrap_or_default(),
+      hash_message.unwrap_or_default()
+    ))
+  }
+
+  /// Render the human-readable summary of stats, like `toString()` of webpack stats.
+  ///
+  /// The output is colored when `stats.colors` is enabled, the options are usually created
+  /// by [`ExtendedStatsOptions::from_preset`] with `for_to_string` enabled.
+  pub fn to_string(&self, options: &ExtendedStatsOptions) -> Result<String> {
+    let colors = Colors {
+      enabled: self.compilation.options.stats.colors,
+    };
+    let mut lines = vec![];
+
+    if options.assets {
+      let (assets, _) = self.get_assets();
+      print_assets(&assets, colors, &mut lines);
+    }
+
+    if self.should_show_entrypoints(options) {
+      for group in self
+        .get_entrypoints(options.chunk_group_auxiliary, options.chunk_group_children)
+        .iter()
+        .sorted_by(|a, b| a.name.cmp(&b.name))
+      {
+        print_chunk_group("Entrypoint", group, colors, &mut lines);
+      }
+    }
+
+    if options.chunk_groups {
+      for group in self
+        .get_named_chunk_groups(options.chunk_group_auxiliary, options.chunk_group_children)
+        .iter()
+        .filter(|group| !self.compilation.entrypoints.contains_key(&group.name))
+      {
+        print_chunk_group("chunk group", group, colors, &mut lines);
+      }
+    }
+
+    if options.chunks {
+      lines.extend(self.get_chunks(options, |chunks| print_chunks(&chunks, options, colors))?);
+    }
+
+    if options.modules {
+      lines.extend(self.get_modules(options, |modules| print_modules(&modules, options, colors))?);
+    }
+
+    let warnings = self.get_warnings();
+    if options.warnings {
+      for warning in &warnings {
+        lines.push(String::new());
+        lines.push(warning.message.trim_end().to_string());
+      }
+    }
+
+    let errors = self.get_errors();
+    if options.errors {
+      for error in &errors {
+        lines.push(String::new());
+        lines.push(error.message.trim_end().to_string());
+      }
+    }
+
+    if let Some(summary) = self.print_summary(options, errors.len(), warnings.len(), colors) {
+      if !lines.is_empty() && !lines.last().is_some_and(|line| line.is_empty()) {
+        lines.push(String::new());
+      }
+      lines.push(summary);
+    }
+
+    Ok(lines.join("\n"))
+  }
+}
@@ -5,16 +5,25 @@ use rspack_paths::Utf8PathBuf;
 use rspack_sources::BoxSource;
 use rspack_util::atom::Atom;
 use rustc_hash::FxHashMap as HashMap;
+use serde::{Serialize, Serializer};
 
+use super::json::{
+  serialize_display, serialize_display_map, serialize_optional_display,
+  serialize_optional_display_vec, serialize_optional_source, serialize_runtime,
+  serialize_source_type_sizes,
+};
 use crate::{ChunkGroupOrderKey, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType};
 
+#[derive(Debug, Clone)]
 pub enum EntrypointsStatsOption {
   Bool(bool),
   String(String),
 }
 
+#[derive(Debug, Clone)]
 pub struct ExtendedStatsOptions {
   pub assets: bool,
+  pub built_at: bool,
   pub cached_modules: bool,
   pub chunks: bool,
   pub chunk_group_auxiliary: bool,
@@ -25,75 +34,279 @@ pub struct ExtendedStatsOptions {
   pub depth: bool,
   pub entrypoints: EntrypointsStatsOption,
   pub errors: bool,
+  pub errors_count: bool,
   pub hash: bool,
   pub ids: bool,
   pub modules: bool,
   pub module_assets: bool,
   pub nested_modules: bool,
   pub optimization_bailout: bool,
+  pub output_path: bool,
   pub provided_exports: bool,
+  pub public_path: bool,
   pub reasons: bool,
   pub source: bool,
+  pub timings: bool,
   pub used_exports: bool,
+  pub version: bool,
   pub warnings: bool,
+  pub warnings_count: bool,
 }
 
-#[derive(Debug)]
+/// The named presets of stats options, same as the presets of webpack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsPreset {
+  None,
+  ErrorsOnly,
+  ErrorsWarnings,
+  Summary,
+  Minimal,
+  #[default]
+  Normal,
+  Detailed,
+  Verbose,
+}
+
+impl TryFrom<&str> for StatsPreset {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
+    match value {
+      "none" => Ok(Self::None),
+      "errors-only" => Ok(Self::ErrorsOnly),
+      "errors-warnings" => Ok(Self::ErrorsWarnings),
+      "summary" => Ok(Self::Summary),
+      "minimal" => Ok(Self::Minimal),
+      "normal" => Ok(Self::Normal),
+      "detailed" => Ok(Self::Detailed),
+      "verbose" => Ok(Self::Verbose),
+      _ => Err(rspack_error::error!("Unsupported stats preset: {value}")),
+    }
+  }
+}
+
+impl ExtendedStatsOptions {
+  /// Create the options of a preset, `for_to_string` disables the details which are
+  /// only useful in json, like `toString()` of webpack stats.
+  pub fn from_preset(preset: StatsPreset, for_to_string: bool) -> Self {
+    let disabled = Self {
+      assets: false,
+      built_at: false,
+      cached_modules: false,
+      chunks: false,
+      chunk_group_auxiliary: false,
+      chunk_group_children: false,
+      chunk_groups: false,
+      chunk_modules: false,
+      chunk_relations: false,
+      depth: false,
+      entrypoints: EntrypointsStatsOption::Bool(false),
+      errors: false,
+      errors_count: false,
+      hash: false,
+      ids: false,
+      modules: false,
+      module_assets: false,
+      nested_modules: false,
+      optimization_bailout: false,
+      output_path: false,
+      provided_exports: false,
+      public_path: false,
+      reasons: false,
+      source: false,
+      timings: false,
+      used_exports: false,
+      version: false,
+      warnings: false,
+      warnings_count: false,
+    };
+    let for_json = !for_to_string;
+    let normal = Self {
+      assets: true,
+      built_at: for_json,
+      cached_modules: for_json,
+      chunks: for_json,
+      chunk_group_auxiliary: for_json,
+      chunk_group_children: for_json,
+      chunk_groups: for_json,
+      chunk_modules: for_json,
+      chunk_relations: for_json,
+      entrypoints: if for_to_string {
+        EntrypointsStatsOption::String("auto".to_string())
+      } else {
+        EntrypointsStatsOption::Bool(true)
+      },
+      errors: true,
+      errors_count: true,
+      hash: for_json,
+      modules: true,
+      module_assets: for_json,
+      nested_modules: for_json,
+      output_path: for_json,
+      public_path: for_json,
+      reasons: for_json,
+      timings: true,
+      version: true,
+      warnings: true,
+      warnings_count: true,
+      ..disabled.clone()
+    };
+    let detailed = Self {
+      cached_modules: true,
+      chunks: true,
+      chunk_groups: true,
+      chunk_modules: false,
+      chunk_relations: true,
+      depth: true,
+      entrypoints: EntrypointsStatsOption::Bool(true),
+      ids: true,
+      optimization_bailout: true,
+      provided_exports: true,
+      used_exports: true,
+      ..normal.clone()
+    };
+    match preset {
+      StatsPreset::None => disabled,
+      StatsPreset::ErrorsOnly => Self {
+        errors: true,
+        errors_count: true,
+        ..disabled
+      },
+      StatsPreset::ErrorsWarnings => Self {
+        errors: true,
+        errors_count: true,
+        warnings: true,
+        warnings_count: true,
+        ..disabled
+      },
+      StatsPreset::Summary => Self {
+        errors_count: true,
+        version: true,
+        warnings_count: true,
+        ..disabled
+      },
+      StatsPreset::Minimal => Self {
+        assets: true,
+        errors: true,
+        errors_count: true,
+        modules: true,
+        timings: true,
+        version: true,
+        warnings: true,
+        warnings_count: true,
+        ..disabled
+      },
+      StatsPreset::Normal => normal,
+      StatsPreset::Detailed => detailed,
+      StatsPreset::Verbose => Self {
+        chunk_group_auxiliary: true,
+        chunk_group_children: true,
+        module_assets: true,
+        nested_modules: true,
+        reasons: true,
+        ..detailed
+      },
+    }
+  }
+}
+
+impl Default for ExtendedStatsOptions {
+  fn default() -> Self {
+    Self::from_preset(StatsPreset::Normal, false)
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsError<'s> {
   pub message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<&'s str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub loc: Option<String>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_optional_display"
+  )]
   pub file: Option<Utf8PathBuf>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_entry: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_initial: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub details: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub stack: Option<String>,
   pub module_trace: Vec<StatsModuleTrace>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsWarning<'s> {
   pub message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<&'s str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub loc: Option<String>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_optional_display"
+  )]
   pub file: Option<Utf8PathBuf>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_entry: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_initial: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub details: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub stack: Option<String>,
   pub module_trace: Vec<StatsModuleTrace>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleTrace {
   pub origin: StatsErrorModuleTraceModule,
   pub module: StatsErrorModuleTraceModule,
   pub dependencies: Vec<StatsErrorModuleTraceDependency>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsErrorModuleTraceModule {
   pub identifier: ModuleIdentifier,
   pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsErrorModuleTraceDependency {
   pub loc: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAsset {
   pub r#type: &'static str,
   pub name: String,
@@ -108,71 +321,119 @@ pub struct StatsAsset {
   pub auxiliary_chunks: Vec<Option<String>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetsByChunkName {
   pub name: String,
   pub files: Vec<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetInfo {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub minimized: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub development: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub hot_module_replacement: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub source_filename: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub copied: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub immutable: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub javascript_module: Option<bool>,
   pub chunk_hash: Vec<String>,
   pub content_hash: Vec<String>,
   pub full_hash: Vec<String>,
   pub related: Vec<StatsAssetInfoRelated>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub is_over_size_limit: Option<bool>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetInfoRelated {
   pub name: String,
   pub value: Vec<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModule<'s> {
   pub r#type: &'static str,
+  #[serde(serialize_with = "serialize_display")]
   pub module_type: ModuleType,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub layer: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub name_for_condition: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<&'s str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunks: Option<Vec<String>>, // has id after the call of chunkIds hook
   pub size: f64,
+  #[serde(serialize_with = "serialize_source_type_sizes")]
   pub sizes: Vec<StatsSourceTypeSize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub dependent: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_id: Option<&'s str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_path: Option<Vec<StatsModuleIssuer<'s>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub reasons: Option<Vec<StatsModuleReason<'s>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub assets: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule<'s>>>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_optional_source"
+  )]
   pub source: Option<&'s BoxSource>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub profile: Option<StatsModuleProfile>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub orphan: Option<bool>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_optional_display_vec"
+  )]
   pub provided_exports: Option<Vec<Atom>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub used_exports: Option<StatsUsedExports>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub optimization_bailout: Option<&'s [String]>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub depth: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub pre_order_index: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub post_order_index: Option<u32>,
   pub built: bool,
   pub code_generated: bool,
   pub build_time_executed: bool,
   pub cached: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub cacheable: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub optional: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub failed: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub errors: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub warnings: Option<u32>,
 }
 
@@ -183,98 +444,145 @@ pub enum StatsUsedExports {
   Null,
 }
 
-#[derive(Debug)]
+impl Serialize for StatsUsedExports {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      Self::Vec(exports) => serializer.collect_seq(exports.iter().map(|e| e.as_str())),
+      Self::Bool(b) => serializer.serialize_bool(*b),
+      Self::Null => serializer.serialize_none(),
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleProfile {
   pub factory: StatsMillisecond,
   pub building: StatsMillisecond,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsOriginRecord {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module: Option<ModuleIdentifier>,
   pub module_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
   pub module_name: String,
   pub loc: String,
   pub request: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunk<'a> {
   pub r#type: &'static str,
   pub files: Vec<String>,
   pub auxiliary_files: Vec<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<String>,
   pub entry: bool,
   pub initial: bool,
   pub names: Vec<String>,
   pub size: f64,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub parents: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub children: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub siblings: Option<Vec<String>>,
+  #[serde(serialize_with = "serialize_display_map")]
   pub children_by_order: HashMap<ChunkGroupOrderKey, Vec<String>>,
+  #[serde(serialize_with = "serialize_runtime")]
   pub runtime: RuntimeSpec,
+  #[serde(serialize_with = "serialize_display_map")]
   pub sizes: HashMap<SourceType, f64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub reason: Option<String>,
   pub rendered: bool,
   pub origins: Vec<StatsOriginRecord>,
   pub id_hints: Vec<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub hash: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroupAsset {
   pub name: String,
   pub size: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroup {
   pub name: String,
   pub chunks: Vec<String>,
   pub assets: Vec<StatsChunkGroupAsset>,
   pub assets_size: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub auxiliary_assets: Option<Vec<StatsChunkGroupAsset>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub auxiliary_assets_size: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub children: Option<StatsChunkGroupChildren>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub is_over_size_limit: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub child_assets: Option<StatschunkGroupChildAssets>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroupChildren {
   pub preload: Vec<StatsChunkGroup>,
   pub prefetch: Vec<StatsChunkGroup>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatschunkGroupChildAssets {
   pub preload: Vec<String>,
   pub prefetch: Vec<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleIssuer<'s> {
   pub identifier: ModuleIdentifier,
   pub name: Cow<'s, str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<&'s str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleReason<'s> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<&'s str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_chunks: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_id: Option<&'s str>,
 
   pub r#type: Option<&'static str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub user_request: Option<&'s str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub explanation: Option<&'static str>,
   pub active: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub loc: Option<String>,
 }
 
@@ -291,10 +599,22 @@ impl StatsMillisecond {
       subsec_millis,
     }
   }
+
+  pub fn as_millis(&self) -> u64 {
+    self.secs * 1000 + self.subsec_millis as u64
+  }
 }
 
-#[derive(Debug)]
+impl Serialize for StatsMillisecond {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(self.as_millis())
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsSourceTypeSize {
+  #[serde(serialize_with = "serialize_display")]
   pub source_type: SourceType,
   pub size: f64,
 }
@@ -3,24 +3,37 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
 use rspack_cacheable::{cacheable, with::AsPreset};
+use rspack_error::{error, Error};
 use smol_str::SmolStr;
-use xxhash_rust::xxh64::Xxh64;
+use xxhash_rust::{xxh3::Xxh3, xxh64::Xxh64};
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
   MD4,
+  Sha256,
+  Sha512,
+  /// The 128-bit variant of XXH3, named `xxh128` as `XXH128` in the xxHash library,
+  /// `xxh3-128` is accepted as an alias
+  Xxh128,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "md4" => Ok(HashFunction::MD4),
+      "sha256" => Ok(HashFunction::Sha256),
+      "sha512" => Ok(HashFunction::Sha512),
+      "xxh128" | "xxh3-128" => Ok(HashFunction::Xxh128),
+      _ => Err(error!(
+        "Unsupported hash function: {value}, expected one of \"xxhash64\", \"md4\", \"sha256\", \"sha512\" or \"xxh128\""
+      )),
     }
   }
 }
@@ -28,13 +41,26 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
+  /// The url safe base64 without padding
+  Base64Url,
+  /// The base62 with `0-9a-zA-Z`, which is safe for both urls and filenames,
+  /// padded with leading `0` to the same length for the same hash function
+  Base62,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base62" => Ok(HashDigest::Base62),
+      _ => Err(error!(
+        "Unsupported hash digest: {value}, expected one of \"hex\", \"base64\", \"base64url\" or \"base62\""
+      )),
     }
   }
 }
@@ -58,6 +84,9 @@ impl From<Option<String>> for HashSalt {
 pub enum RspackHash {
   Xxhash64(Box<Xxh64>),
   MD4(Box<md4::Md4>),
+  Sha256(Box<sha2::Sha256>),
+  Sha512(Box<sha2::Sha512>),
+  Xxh128(Box<Xxh3>),
 }
 
 impl fmt::Debug for RspackHash {
@@ -65,6 +94,9 @@ impl fmt::Debug for RspackHash {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::Sha256(_) => write!(f, "RspackHash(Sha256)"),
+      Self::Sha512(_) => write!(f, "RspackHash(Sha512)"),
+      Self::Xxh128(_) => write!(f, "RspackHash(Xxh128)"),
     }
   }
 }
@@ -74,6 +106,9 @@ impl RspackHash {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(Xxh64::new(0))),
       HashFunction::MD4 => Self::MD4(Box::new(md4::Md4::new())),
+      HashFunction::Sha256 => Self::Sha256(Box::new(sha2::Sha256::new())),
+      HashFunction::Sha512 => Self::Sha512(Box::new(sha2::Sha512::new())),
+      HashFunction::Xxh128 => Self::Xxh128(Box::new(Xxh3::new())),
     }
   }
 
@@ -89,6 +124,9 @@ impl RspackHash {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_be_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha256(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha512(hash) => hash.finalize().to_vec(),
+      RspackHash::Xxh128(hasher) => hasher.digest128().to_be_bytes().to_vec(),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -98,19 +136,11 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha512(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Xxh128(hasher) => hasher.digest(),
     }
   }
 
@@ -118,8 +148,42 @@ impl Hasher for RspackHash {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::Sha256(hasher) => hasher.update(bytes),
+      RspackHash::Sha512(hasher) => hasher.update(bytes),
+      RspackHash::Xxh128(hasher) => hasher.update(bytes),
+    }
+  }
+}
+
+/// The most significant 8 bytes of the hash
+fn msb_u64(hash: &[u8]) -> u64 {
+  let mut bytes = [0u8; 8];
+  bytes.copy_from_slice(&hash[..8]);
+  u64::from_be_bytes(bytes)
+}
+
+const BASE62_ALPHABET: &[u8; 62] =
+  b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Encode the bytes as a big-endian number in base62, padded with leading `0`
+/// to the max length of the number, so that the prefixes are always comparable
+fn encode_base62(bytes: &[u8]) -> String {
+  let len = (bytes.len() as f64 * 8.0 / 62f64.log2()).ceil() as usize;
+  let mut digits: Vec<u8> = vec![];
+  let mut number = bytes.to_vec();
+  while number.iter().any(|b| *b != 0) {
+    // divide the number by 62 and collect the remainder
+    let mut remainder = 0u32;
+    for byte in number.iter_mut() {
+      let value = (remainder << 8) | *byte as u32;
+      *byte = (value / 62) as u8;
+      remainder = value % 62;
     }
+    digits.push(BASE62_ALPHABET[remainder as usize]);
   }
+  digits.resize(len.max(1), BASE62_ALPHABET[0]);
+  digits.reverse();
+  String::from_utf8(digits).expect("base62 digits should be ascii")
 }
 
 #[cacheable]
@@ -141,6 +205,9 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
+      HashDigest::Base62 => encode_base62(&inner).into(),
     };
     Self { encoded }
   }
@@ -166,3 +233,103 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn digest(function: &str, digest: &str) -> RspackHashDigest {
+    let hasher = RspackHash::new(&function.try_into().expect("should be a hash function"));
+    hasher.digest(&digest.try_into().expect("should be a hash digest"))
+  }
+
+  #[test]
+  fn known_answers_of_empty_input() {
+    let cases = [
+      (
+        "xxhash64",
+        [
+          "ef46db3751d8e999",
+          "70bbN1HY6Zk=",
+          "70bbN1HY6Zk",
+          "kxFd6fTBnIJ",
+        ],
+      ),
+      (
+        "md4",
+        [
+          "31d6cfe0d16ae931b73c59d7e0c089c0",
+          "MdbP4NFq6TG3PFnX4MCJwA==",
+          "MdbP4NFq6TG3PFnX4MCJwA",
+          "1w2NbNtAfH2clchxKFrKyk",
+        ],
+      ),
+      (
+        "sha256",
+        [
+          "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+          "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=",
+          "47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU",
+          "RZwTDmWjELXeEmMEb0eIIegKayGGUPNsuJweEPhlXi5",
+        ],
+      ),
+      (
+        "sha512",
+        [
+          "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+          "z4PhNX7vuL3xVChQ1m2AB9Yg5AULVxXcg/SpIdNs6c5H0NE8XYXysP+DGNKHfuwvY7kxvUdBeoGlODJ6+SfaPg==",
+          "z4PhNX7vuL3xVChQ1m2AB9Yg5AULVxXcg_SpIdNs6c5H0NE8XYXysP-DGNKHfuwvY7kxvUdBeoGlODJ6-SfaPg",
+          "Mbd0ed9hesVnFLmcCHWAnf5U4yWcA7QNaLJNYRFar705haHtYjV2iPTWTtKDBGHmVCJyhUaBYdy4sYa0L6AYkm",
+        ],
+      ),
+      (
+        "xxh128",
+        [
+          "99aa06d3014798d86001c324468d497f",
+          "maoG0wFHmNhgAcMkRo1Jfw==",
+          "maoG0wFHmNhgAcMkRo1Jfw",
+          "4FXwLhiySFQK6r9eKTaTWT",
+        ],
+      ),
+    ];
+    for (function, answers) in cases {
+      for (name, answer) in ["hex", "base64", "base64url", "base62"]
+        .into_iter()
+        .zip(answers)
+      {
+        assert_eq!(
+          digest(function, name).encoded(),
+          answer,
+          "{function} with {name}"
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn base62_padding() {
+    assert_eq!(encode_base62(&[0, 0, 1]), "00001");
+    assert_eq!(encode_base62(&[0; 16]), "0".repeat(22));
+    assert_eq!(encode_base62(&[255; 16]).len(), 22);
+    assert_eq!(encode_base62(&[]), "0");
+  }
+
+  #[test]
+  fn rendered_truncation() {
+    let digest = digest("md4", "base62");
+    assert_eq!(digest.rendered(4), "1w2N");
+    assert_eq!(digest.rendered(0), "");
+    assert_eq!(digest.rendered(100), digest.encoded());
+  }
+
+  #[test]
+  fn xxh3_128_alias() {
+    assert_eq!(digest("xxh3-128", "hex"), digest("xxh128", "hex"));
+  }
+
+  #[test]
+  fn unknown_names() {
+    assert!(HashFunction::try_from("xxh3").is_err());
+    assert!(HashDigest::try_from("base32").is_err());
+  }
+}
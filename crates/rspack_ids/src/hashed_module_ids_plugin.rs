@@ -0,0 +1,101 @@
+use std::hash::Hasher;
+
+use rspack_core::{
+  compare_modules_by_pre_order_index_or_identifier, ApplyContext, ChunkGraph, Compilation,
+  CompilationModuleIds, CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::id_helpers::{get_full_module_name, get_used_module_ids_and_modules};
+
+#[derive(Debug, Clone)]
+pub struct HashedModuleIdsPluginOptions {
+  /// The context directory for creating names, default to `options.context`
+  pub context: Option<String>,
+  pub hash_function: HashFunction,
+  pub hash_digest: HashDigest,
+  /// The prefix length of the hash digest to use
+  pub hash_digest_length: usize,
+}
+
+impl Default for HashedModuleIdsPluginOptions {
+  fn default() -> Self {
+    Self {
+      context: None,
+      hash_function: HashFunction::MD4,
+      hash_digest: HashDigest::Hex,
+      hash_digest_length: 4,
+    }
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct HashedModuleIdsPlugin {
+  options: HashedModuleIdsPluginOptions,
+}
+
+impl HashedModuleIdsPlugin {
+  pub fn new(options: HashedModuleIdsPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationModuleIds for HashedModuleIdsPlugin)]
+fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let (mut used_ids, mut modules) = get_used_module_ids_and_modules(compilation, None);
+
+  let mut module_ids = std::mem::take(&mut compilation.module_ids_artifact);
+  let context = self
+    .options
+    .context
+    .as_deref()
+    .unwrap_or(compilation.options.context.as_ref());
+  let module_graph = compilation.get_module_graph();
+
+  modules
+    .sort_unstable_by(|a, b| compare_modules_by_pre_order_index_or_identifier(&module_graph, a, b));
+
+  for module in modules
+    .into_iter()
+    .filter_map(|i| module_graph.module_by_identifier(&i))
+  {
+    let ident = get_full_module_name(module, context);
+    let mut hasher = RspackHash::new(&self.options.hash_function);
+    hasher.write(ident.as_bytes());
+    let digest = hasher.digest(&self.options.hash_digest);
+    let hash_id = digest.encoded();
+    // use a longer prefix of the digest when the id has been used
+    let mut len = self.options.hash_digest_length;
+    while len < hash_id.len() && used_ids.contains(&hash_id[..len]) {
+      len += 1;
+    }
+    let module_id = digest.rendered(len).to_string();
+    ChunkGraph::set_module_id(
+      &mut module_ids,
+      module.identifier(),
+      module_id.clone().into(),
+    );
+    used_ids.insert(module_id);
+  }
+
+  compilation.module_ids_artifact = module_ids;
+  Ok(())
+}
+
+impl Plugin for HashedModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HashedModuleIdsPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(module_ids::new(self));
+    Ok(())
+  }
+}
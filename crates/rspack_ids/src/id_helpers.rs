@@ -33,12 +33,10 @@ pub fn get_used_module_ids_and_modules(
   let mut modules = vec![];
   let mut used_ids = FxHashSet::default();
 
-  // TODO: currently we don't have logic of compilation.usedModuleIds
-  //   if (compilation.usedModuleIds) {
-  //     for (const id of compilation.usedModuleIds) {
-  //         usedIds.add(id + "");
-  //     }
-  //   }
+  // the ids in records should not be assigned to other modules
+  if let Some(records) = &compilation.input_records {
+    used_ids.extend(records.modules.used_ids.iter().map(ToString::to_string));
+  }
 
   compilation
     .get_module_graph()
@@ -319,6 +317,10 @@ pub fn request_to_id(request: &str) -> String {
 
 pub fn get_used_chunk_ids(compilation: &Compilation) -> FxHashSet<String> {
   let mut used_ids = FxHashSet::default();
+  // the ids in records should not be assigned to other chunks
+  if let Some(records) = &compilation.input_records {
+    used_ids.extend(records.chunks.used_ids.iter().map(ToString::to_string));
+  }
   for chunk in compilation.chunk_by_ukey.values() {
     if let Some(id) = chunk.id(&compilation.chunk_ids_artifact) {
       used_ids.insert(id.to_string());
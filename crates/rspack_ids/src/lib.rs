@@ -14,5 +14,9 @@ mod natural_module_ids_plugin;
 pub use natural_module_ids_plugin::NaturalModuleIdsPlugin;
 mod natural_chunk_ids_plugin;
 pub use natural_chunk_ids_plugin::NaturalChunkIdsPlugin;
+mod hashed_module_ids_plugin;
+pub use hashed_module_ids_plugin::*;
 mod occurrence_chunk_ids_plugin;
 pub use occurrence_chunk_ids_plugin::*;
+mod occurrence_module_ids_plugin;
+pub use occurrence_module_ids_plugin::*;
@@ -0,0 +1,160 @@
+use itertools::Itertools;
+use rspack_collections::IdentifierMap;
+use rspack_core::{
+  ApplyContext, Compilation, CompilationModuleIds, CompilerOptions, ModuleIdentifier, Plugin,
+  PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::id_helpers::{
+  assign_ascending_module_ids, compare_modules_by_pre_order_index_or_identifier,
+  get_used_module_ids_and_modules,
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct OccurrenceModuleIdsPluginOptions {
+  pub prioritise_initial: bool,
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct OccurrenceModuleIdsPlugin {
+  prioritise_initial: bool,
+}
+
+impl OccurrenceModuleIdsPlugin {
+  pub fn new(option: OccurrenceModuleIdsPluginOptions) -> Self {
+    Self::new_inner(option.prioritise_initial)
+  }
+}
+
+#[plugin_hook(CompilationModuleIds for OccurrenceModuleIdsPlugin)]
+fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let (used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+
+  let mut module_ids = std::mem::take(&mut compilation.module_ids_artifact);
+
+  let chunk_graph = &compilation.chunk_graph;
+  let module_graph = compilation.get_module_graph();
+
+  let mut initial_chunk_chunk_map: IdentifierMap<usize> = IdentifierMap::default();
+  let mut entry_count_map: IdentifierMap<usize> = IdentifierMap::default();
+  for module in &modules {
+    let mut initial = 0;
+    let mut entry = 0;
+    for chunk_ukey in chunk_graph.get_module_chunks(*module) {
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      if chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+        initial += 1;
+      }
+      if chunk_graph.is_entry_module_in_chunk(module, chunk_ukey) {
+        entry += 1;
+      }
+    }
+    initial_chunk_chunk_map.insert(*module, initial);
+    entry_count_map.insert(*module, entry);
+  }
+
+  // the number of initial chunks which contain the modules referencing this module
+  let count_occurs_in_entry = |module: &ModuleIdentifier| {
+    let mut sum = 0;
+    for (origin_module, connections) in
+      module_graph.get_incoming_connections_by_origin_module(module)
+    {
+      let Some(origin_module) = origin_module else {
+        continue;
+      };
+      if !connections
+        .iter()
+        .any(|c| c.is_target_active(&module_graph, None))
+      {
+        continue;
+      }
+      sum += initial_chunk_chunk_map
+        .get(&origin_module)
+        .copied()
+        .unwrap_or_default();
+    }
+    sum
+  };
+
+  // the number of chunks which contain the references of this module, each
+  // active connection counts as one occurrence of the module id
+  let count_occurs = |module: &ModuleIdentifier| {
+    let mut sum = 0;
+    for (origin_module, connections) in
+      module_graph.get_incoming_connections_by_origin_module(module)
+    {
+      let Some(origin_module) = origin_module else {
+        continue;
+      };
+      let chunk_modules = chunk_graph.get_number_of_module_chunks(origin_module);
+      let occurs = connections
+        .iter()
+        .filter(|c| c.is_target_active(&module_graph, None))
+        .count();
+      sum += occurs * chunk_modules;
+    }
+    sum
+  };
+
+  let mut occurs_in_initial_chunks_map: IdentifierMap<usize> = IdentifierMap::default();
+  if self.prioritise_initial {
+    for module in &modules {
+      let result =
+        count_occurs_in_entry(module) + initial_chunk_chunk_map[module] + entry_count_map[module];
+      occurs_in_initial_chunks_map.insert(*module, result);
+    }
+  }
+
+  let mut occurs_in_all_chunks_map: IdentifierMap<usize> = IdentifierMap::default();
+  for module in &modules {
+    let result = count_occurs(module)
+      + chunk_graph.get_number_of_module_chunks(*module)
+      + entry_count_map[module];
+    occurs_in_all_chunks_map.insert(*module, result);
+  }
+
+  let modules_in_occurrence_order = modules
+    .iter()
+    .filter_map(|i| module_graph.module_by_identifier(i))
+    .sorted_unstable_by(|a, b| {
+      if self.prioritise_initial {
+        let a_entry_occurs = occurs_in_initial_chunks_map[&a.identifier()];
+        let b_entry_occurs = occurs_in_initial_chunks_map[&b.identifier()];
+        if a_entry_occurs != b_entry_occurs {
+          return b_entry_occurs.cmp(&a_entry_occurs);
+        }
+      }
+
+      let a_occurs = occurs_in_all_chunks_map[&a.identifier()];
+      let b_occurs = occurs_in_all_chunks_map[&b.identifier()];
+      if a_occurs != b_occurs {
+        return b_occurs.cmp(&a_occurs);
+      }
+
+      compare_modules_by_pre_order_index_or_identifier(&module_graph, a, b)
+    })
+    .collect::<Vec<_>>();
+
+  assign_ascending_module_ids(&used_ids, modules_in_occurrence_order, &mut module_ids);
+  compilation.module_ids_artifact = module_ids;
+
+  Ok(())
+}
+
+impl Plugin for OccurrenceModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.OccurrenceModuleIdsPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(module_ids::new(self));
+    Ok(())
+  }
+}
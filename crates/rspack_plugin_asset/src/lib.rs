@@ -41,6 +41,8 @@ const DEFAULT_ENCODING: &str = "base64";
 enum DataUrlOptions {
   Inline(bool),
   Source,
+  Bytes,
+  StyleSheet,
   Auto(Option<AssetParserDataUrl>),
 }
 
@@ -53,6 +55,10 @@ const ASSET_RESOURCE: bool = false;
 #[derive(Debug, Clone)]
 enum CanonicalizedDataUrlOption {
   Source,
+  /// Export the content as an `Uint8Array`
+  Bytes,
+  /// Export the content as a constructed `CSSStyleSheet`
+  StyleSheet,
   Asset(IsInline),
 }
 
@@ -61,6 +67,19 @@ impl CanonicalizedDataUrlOption {
     matches!(self, CanonicalizedDataUrlOption::Source)
   }
 
+  fn is_bytes(&self) -> bool {
+    matches!(self, CanonicalizedDataUrlOption::Bytes)
+  }
+
+  fn is_style_sheet(&self) -> bool {
+    matches!(self, CanonicalizedDataUrlOption::StyleSheet)
+  }
+
+  /// Whether the content is embedded in the javascript module.
+  fn is_embedded(&self) -> bool {
+    !self.is_resource()
+  }
+
   fn is_inline(&self) -> bool {
     matches!(self, CanonicalizedDataUrlOption::Asset(ASSET_INLINE))
   }
@@ -111,6 +130,22 @@ impl AssetParserAndGenerator {
     }
   }
 
+  pub fn with_bytes() -> Self {
+    Self {
+      emit: false,
+      data_url: DataUrlOptions::Bytes,
+      parsed_asset_config: None,
+    }
+  }
+
+  pub fn with_style_sheet() -> Self {
+    Self {
+      emit: false,
+      data_url: DataUrlOptions::StyleSheet,
+      parsed_asset_config: None,
+    }
+  }
+
   fn decode_data_uri_content(encoding: &str, content: &str, source: &BoxSource) -> Vec<u8> {
     if encoding == "base64"
       && let Some(cleaned) = rspack_base64::clean_base64(content)
@@ -330,7 +365,7 @@ const DEFAULT_MAX_SIZE: f64 = 8096.0;
 impl ParserAndGenerator for AssetParserAndGenerator {
   fn source_types(&self) -> &[SourceType] {
     if let Some(config) = self.parsed_asset_config.as_ref() {
-      if config.is_source() || config.is_inline() || !self.emit {
+      if config.is_embedded() || !self.emit {
         ASSET_SOURCE_MODULE_SOURCE_TYPE_LIST
       } else {
         ASSET_MODULE_SOURCE_TYPE_LIST
@@ -354,6 +389,10 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         let parsed_size = self.parsed_asset_config.as_ref().map(|config| {
           match config {
             CanonicalizedDataUrlOption::Source => original_source_size,
+            // base64 encoding and the decoding wrapper
+            CanonicalizedDataUrlOption::Bytes => original_source_size * 1.34 + 180.0,
+            // the `CSSStyleSheet` constructing wrapper
+            CanonicalizedDataUrlOption::StyleSheet => original_source_size + 80.0,
             CanonicalizedDataUrlOption::Asset(meta) => {
               match *meta {
                 ASSET_INLINE => {
@@ -398,6 +437,8 @@ impl ParserAndGenerator for AssetParserAndGenerator {
 
     self.parsed_asset_config = match &self.data_url {
       DataUrlOptions::Source => Some(CanonicalizedDataUrlOption::Source),
+      DataUrlOptions::Bytes => Some(CanonicalizedDataUrlOption::Bytes),
+      DataUrlOptions::StyleSheet => Some(CanonicalizedDataUrlOption::StyleSheet),
       DataUrlOptions::Inline(val) => Some(CanonicalizedDataUrlOption::Asset(*val)),
       DataUrlOptions::Auto(option) => {
         let limit_size = parse_context
@@ -555,6 +596,16 @@ impl ParserAndGenerator for AssetParserAndGenerator {
           asset_path
         } else if parsed_asset_config.is_source() {
           format!(r"{:?}", source.source())
+        } else if parsed_asset_config.is_bytes() {
+          format!(
+            r#"(function (b) {{ return typeof Buffer === "function" ? new Uint8Array(Buffer.from(b, "base64")) : Uint8Array.from(atob(b), function (c) {{ return c.charCodeAt(0); }}); }})("{}")"#,
+            rspack_base64::encode_to_string(source.buffer())
+          )
+        } else if parsed_asset_config.is_style_sheet() {
+          format!(
+            r"(function (s) {{ var sheet = new CSSStyleSheet(); sheet.replaceSync(s); return sheet; }})({:?})",
+            source.source()
+          )
         } else {
           unreachable!()
         };
@@ -610,10 +661,8 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         }
       }
       SourceType::Asset => {
-        if parsed_asset_config.is_source() || parsed_asset_config.is_inline() {
-          Err(error!(
-            "Inline or Source asset does not have source type `asset`"
-          ))
+        if parsed_asset_config.is_embedded() {
+          Err(error!("Embedded asset does not have source type `asset`"))
         } else {
           Ok(RawBufferSource::from(source.buffer().to_vec()).boxed())
         }
@@ -791,6 +840,16 @@ impl Plugin for AssetPlugin {
       Box::new(move |_, _| Box::new(AssetParserAndGenerator::with_source())),
     );
 
+    ctx.context.register_parser_and_generator_builder(
+      rspack_core::ModuleType::AssetBytes,
+      Box::new(move |_, _| Box::new(AssetParserAndGenerator::with_bytes())),
+    );
+
+    ctx.context.register_parser_and_generator_builder(
+      rspack_core::ModuleType::CssStyleSheet,
+      Box::new(move |_, _| Box::new(AssetParserAndGenerator::with_style_sheet())),
+    );
+
     Ok(())
   }
 }
@@ -1,3 +1,4 @@
+use itertools::Itertools;
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
   with::{AsPreset, AsVec},
@@ -7,7 +8,7 @@ use rspack_core::{
   DependencyCategory, DependencyId, DependencyTemplate, DependencyType, ExportNameOrSpec,
   ExportSpec, ExportsOfExportsSpec, ExportsSpec, InitFragmentExt, InitFragmentKey,
   InitFragmentStage, ModuleGraph, NormalInitFragment, RuntimeGlobals, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource, UsedName,
+  TemplateReplaceSource, UsedName, NAMESPACE_OBJECT_EXPORT,
 };
 use swc_core::atoms::Atom;
 
@@ -56,6 +57,8 @@ pub struct CommonJsExportsDependency {
   id: DependencyId,
   range: (u32, u32),
   value_range: Option<(u32, u32)>,
+  // range of a statement level assignment with a pure value, which is removed when the export is unused
+  removable_range: Option<(u32, u32)>,
   base: ExportsBase,
   #[cacheable(with=AsVec<AsPreset>)]
   names: Vec<Atom>,
@@ -65,6 +68,7 @@ impl CommonJsExportsDependency {
   pub fn new(
     range: (u32, u32),
     value_range: Option<(u32, u32)>,
+    removable_range: Option<(u32, u32)>,
     base: ExportsBase,
     names: Vec<Atom>,
   ) -> Self {
@@ -72,6 +76,7 @@ impl CommonJsExportsDependency {
       id: DependencyId::new(),
       range,
       value_range,
+      removable_range,
       base,
       names,
     }
@@ -124,6 +129,7 @@ impl DependencyTemplate for CommonJsExportsDependency {
       runtime,
       init_fragments,
       runtime_requirements,
+      concatenation_scope,
       ..
     } = code_generatable_context;
 
@@ -139,7 +145,9 @@ impl DependencyTemplate for CommonJsExportsDependency {
     let exports_argument = module.get_exports_argument();
     let module_argument = module.get_module_argument();
 
-    let base = if self.base.is_exports() {
+    let base = if concatenation_scope.is_some() {
+      NAMESPACE_OBJECT_EXPORT.to_string()
+    } else if self.base.is_exports() {
       runtime_requirements.insert(RuntimeGlobals::EXPORTS);
       exports_argument.to_string()
     } else if self.base.is_module_exports() {
@@ -170,6 +178,13 @@ impl DependencyTemplate for CommonJsExportsDependency {
           ),
           None,
         )
+      } else if let Some(removable_range) = self.removable_range {
+        source.replace(
+          removable_range.0,
+          removable_range.1,
+          &format!("/* unused export {} */", self.names.iter().join(".")),
+          None,
+        );
       } else {
         init_fragments.push(
           NormalInitFragment::new(
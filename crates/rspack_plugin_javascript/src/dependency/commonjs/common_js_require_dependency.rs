@@ -1,12 +1,16 @@
-use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsOption, AsPreset, AsVec, Skip},
+};
 use rspack_core::{
-  module_id, Compilation, DependencyLocation, DependencyRange, FactorizeInfo, RuntimeSpec,
-  SharedSourceMap,
+  create_exports_object_referenced, module_id, Compilation, DependencyLocation, DependencyRange,
+  ExtendedReferencedExport, FactorizeInfo, ModuleGraph, RuntimeSpec, SharedSourceMap,
 };
 use rspack_core::{AsContextDependency, Dependency, DependencyCategory};
 use rspack_core::{DependencyId, DependencyTemplate};
 use rspack_core::{DependencyType, ModuleDependency};
 use rspack_core::{TemplateContext, TemplateReplaceSource};
+use swc_core::ecma::atoms::Atom;
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -16,6 +20,9 @@ pub struct CommonJsRequireDependency {
   optional: bool,
   range: DependencyRange,
   range_expr: Option<DependencyRange>,
+  // properties read by `const { a, b } = require("./m")`
+  #[cacheable(with=AsOption<AsVec<AsPreset>>)]
+  referenced_properties: Option<Vec<Atom>>,
   #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
   factorize_info: FactorizeInfo,
@@ -26,6 +33,7 @@ impl CommonJsRequireDependency {
     request: String,
     range: DependencyRange,
     range_expr: Option<DependencyRange>,
+    referenced_properties: Option<Vec<Atom>>,
     optional: bool,
     source_map: Option<SharedSourceMap>,
   ) -> Self {
@@ -35,6 +43,7 @@ impl CommonJsRequireDependency {
       optional,
       range,
       range_expr,
+      referenced_properties,
       source_map,
       factorize_info: Default::default(),
     }
@@ -63,6 +72,21 @@ impl Dependency for CommonJsRequireDependency {
     self.range_expr.as_ref()
   }
 
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    if let Some(referenced_properties) = &self.referenced_properties {
+      referenced_properties
+        .iter()
+        .map(|name| ExtendedReferencedExport::Array(vec![name.clone()]))
+        .collect()
+    } else {
+      create_exports_object_referenced()
+    }
+  }
+
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::True
   }
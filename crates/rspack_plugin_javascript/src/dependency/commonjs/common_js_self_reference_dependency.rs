@@ -6,6 +6,7 @@ use rspack_core::{
   property_access, AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId,
   DependencyTemplate, DependencyType, ExtendedReferencedExport, FactorizeInfo, ModuleDependency,
   ModuleGraph, RuntimeGlobals, RuntimeSpec, TemplateContext, TemplateReplaceSource, UsedName,
+  NAMESPACE_OBJECT_EXPORT,
 };
 use swc_core::atoms::Atom;
 
@@ -106,6 +107,7 @@ impl DependencyTemplate for CommonJsSelfReferenceDependency {
       module,
       runtime,
       runtime_requirements,
+      concatenation_scope,
       ..
     } = code_generatable_context;
     let module_graph = compilation.get_module_graph();
@@ -125,7 +127,9 @@ impl DependencyTemplate for CommonJsSelfReferenceDependency {
     let exports_argument = module.get_exports_argument();
     let module_argument = module.get_module_argument();
 
-    let base = if self.base.is_exports() {
+    let base = if concatenation_scope.is_some() {
+      NAMESPACE_OBJECT_EXPORT.to_string()
+    } else if self.base.is_exports() {
       runtime_requirements.insert(RuntimeGlobals::EXPORTS);
       exports_argument.to_string()
     } else if self.base.is_module_exports() {
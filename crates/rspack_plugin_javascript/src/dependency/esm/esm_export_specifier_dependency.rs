@@ -6,8 +6,9 @@ use rspack_collections::IdentifierSet;
 use rspack_core::{
   AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyCategory,
   DependencyId, DependencyLocation, DependencyRange, DependencyTemplate, DependencyType,
-  ESMExportInitFragment, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
-  RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource, UsedName,
+  ESMExportInitFragment, ExportNameOrSpec, ExportSpec, ExportsOfExportsSpec, ExportsSpec,
+  InlinableValue, ModuleGraph, RuntimeSpec, SharedSourceMap, TemplateContext,
+  TemplateReplaceSource, UsedName,
 };
 use swc_core::ecma::atoms::Atom;
 
@@ -23,18 +24,21 @@ pub struct ESMExportSpecifierDependency {
   pub name: Atom,
   #[cacheable(with=AsPreset)]
   pub value: Atom, // id
+  inlinable: Option<InlinableValue>,
 }
 
 impl ESMExportSpecifierDependency {
   pub fn new(
     name: Atom,
     value: Atom,
+    inlinable: Option<InlinableValue>,
     range: DependencyRange,
     source_map: Option<SharedSourceMap>,
   ) -> Self {
     Self {
       name,
       value,
+      inlinable,
       range,
       source_map,
       id: DependencyId::new(),
@@ -61,8 +65,17 @@ impl Dependency for ESMExportSpecifierDependency {
   }
 
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
+    let export = if let Some(inlinable) = &self.inlinable {
+      ExportNameOrSpec::ExportSpec(ExportSpec {
+        name: self.name.clone(),
+        inlinable: Some(inlinable.clone()),
+        ..Default::default()
+      })
+    } else {
+      ExportNameOrSpec::String(self.name.clone())
+    };
     Some(ExportsSpec {
-      exports: ExportsOfExportsSpec::Array(vec![ExportNameOrSpec::String(self.name.clone())]),
+      exports: ExportsOfExportsSpec::Array(vec![export]),
       priority: Some(1),
       can_mangle: None,
       terminal_binding: Some(true),
@@ -10,12 +10,13 @@ use rspack_core::DependencyLocation;
 use rspack_core::DependencyRange;
 use rspack_core::SharedSourceMap;
 use rspack_core::{
-  filter_runtime, import_statement, merge_runtime, AsContextDependency,
-  AwaitDependenciesInitFragment, BuildMetaDefaultObject, ConditionalInitFragment, ConnectionState,
-  Dependency, DependencyCategory, DependencyCondition, DependencyId, DependencyTemplate,
-  DependencyType, ErrorSpan, ExportInfoProvided, ExportsType, ExtendedReferencedExport,
-  ImportAttributes, InitFragmentExt, InitFragmentKey, InitFragmentStage, ModuleDependency,
-  ProvidedExports, RuntimeCondition, TemplateContext, TemplateReplaceSource,
+  deferred_import_statement, filter_runtime, get_exports_type, import_statement, merge_runtime,
+  AsContextDependency, AwaitDependenciesInitFragment, BuildMetaDefaultObject,
+  ConditionalInitFragment, ConnectionState, Dependency, DependencyCategory, DependencyCondition,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, ExportInfoProvided, ExportsType,
+  ExtendedReferencedExport, ForwardId, ImportAttributes, ImportPhase, InitFragmentExt,
+  InitFragmentKey, InitFragmentStage, Module, ModuleDependency, ProvidedExports, RuntimeCondition,
+  TemplateContext, TemplateReplaceSource,
 };
 use rspack_core::{Compilation, FactorizeInfo};
 use rspack_core::{ModuleGraph, RuntimeSpec};
@@ -24,7 +25,7 @@ use rspack_error::DiagnosticExt;
 use rspack_error::{Diagnostic, TraceableError};
 use swc_core::ecma::atoms::Atom;
 
-use super::create_resource_identifier_for_esm_dependency;
+use super::create_resource_identifier_for_esm_import_dependency;
 
 // TODO: find a better way to implement this for performance
 // Align with https://github.com/webpack/webpack/blob/51f0f0aeac072f989f8d40247f6c23a1995c5c37/lib/dependencies/HarmonyImportDependency.js#L361-L365
@@ -74,6 +75,7 @@ pub struct ESMImportSideEffectDependency {
   pub dependency_type: DependencyType,
   pub export_all: bool,
   attributes: Option<ImportAttributes>,
+  phase: ImportPhase,
   resource_identifier: String,
   #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
@@ -90,10 +92,11 @@ impl ESMImportSideEffectDependency {
     dependency_type: DependencyType,
     export_all: bool,
     attributes: Option<ImportAttributes>,
+    phase: ImportPhase,
     source_map: Option<SharedSourceMap>,
   ) -> Self {
     let resource_identifier =
-      create_resource_identifier_for_esm_dependency(&request, attributes.as_ref());
+      create_resource_identifier_for_esm_import_dependency(&request, attributes.as_ref(), phase);
     Self {
       id: DependencyId::new(),
       source_order,
@@ -103,6 +106,7 @@ impl ESMImportSideEffectDependency {
       dependency_type,
       export_all,
       attributes,
+      phase,
       resource_identifier,
       source_map,
       factorize_info: Default::default(),
@@ -144,14 +148,26 @@ pub fn esm_import_dependency_apply<T: ModuleDependency>(
     RuntimeCondition::Boolean(true)
   };
 
-  let content: (String, String) = import_statement(
-    *module,
-    compilation,
-    runtime_requirements,
-    module_dependency.id(),
-    module_dependency.request(),
-    false,
-  );
+  let content: (String, String) = if is_deferred_import(module_dependency, *module, compilation) {
+    (
+      deferred_import_statement(
+        compilation,
+        runtime_requirements,
+        module_dependency.id(),
+        module_dependency.request(),
+      ),
+      String::new(),
+    )
+  } else {
+    import_statement(
+      *module,
+      compilation,
+      runtime_requirements,
+      module_dependency.id(),
+      module_dependency.request(),
+      false,
+    )
+  };
   let TemplateContext {
     init_fragments,
     compilation,
@@ -228,6 +244,39 @@ pub fn esm_import_dependency_apply<T: ModuleDependency>(
   }
 }
 
+/// Whether the imported module of an `import defer` can be evaluated lazily. Async modules and
+/// modules imported eagerly by the same module are evaluated anyway, and the interop of
+/// non-ESM modules needs to access the exports right away.
+fn is_deferred_import<T: ModuleDependency>(
+  module_dependency: &T,
+  module: &dyn Module,
+  compilation: &Compilation,
+) -> bool {
+  if module_dependency.get_phase() != ImportPhase::Defer {
+    return false;
+  }
+  let module_graph = compilation.get_module_graph();
+  let Some(ref_module) = module_graph.module_identifier_by_dependency_id(module_dependency.id())
+  else {
+    return false;
+  };
+  if ModuleGraph::is_async(compilation, ref_module)
+    || matches!(
+      get_exports_type(&module_graph, module_dependency.id(), &module.identifier()),
+      ExportsType::Dynamic
+    )
+  {
+    return false;
+  }
+  !module.get_dependencies().iter().any(|dep_id| {
+    module_graph.dependency_by_id(dep_id).is_some_and(|dep| {
+      dep.category() == &DependencyCategory::Esm
+        && dep.get_phase() == ImportPhase::Evaluation
+        && module_graph.module_identifier_by_dependency_id(dep_id) == Some(ref_module)
+    })
+  })
+}
+
 pub fn esm_import_dependency_get_linking_error<T: ModuleDependency>(
   module_dependency: &T,
   ids: &[Atom],
@@ -421,6 +470,14 @@ impl Dependency for ESMImportSideEffectDependency {
     self.attributes.as_ref()
   }
 
+  fn get_phase(&self) -> ImportPhase {
+    self.phase
+  }
+
+  fn forward_id(&self) -> ForwardId {
+    ForwardId::Empty
+  }
+
   fn get_module_evaluation_side_effects_state(
     &self,
     module_graph: &ModuleGraph,
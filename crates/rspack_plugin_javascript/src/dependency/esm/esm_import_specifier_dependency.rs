@@ -8,17 +8,18 @@ use rspack_core::{
   get_exports_type, AsContextDependency, Compilation, ConnectionState, Dependency,
   DependencyCategory, DependencyCondition, DependencyId, DependencyLocation, DependencyRange,
   DependencyTemplate, DependencyType, ExportPresenceMode, ExportsType, ExtendedReferencedExport,
-  FactorizeInfo, ImportAttributes, JavascriptParserOptions, ModuleDependency, ModuleGraph,
-  ReferencedExport, RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource,
-  UsedByExports,
+  FactorizeInfo, ForwardId, ImportAttributes, ImportPhase, InlinableValue, JavascriptParserOptions,
+  ModuleDependency, ModuleGraph, ReferencedExport, RuntimeSpec, SharedSourceMap, TemplateContext,
+  TemplateReplaceSource, UsedByExports,
 };
 use rspack_core::{property_access, ModuleReferenceOptions};
 use rspack_error::Diagnostic;
+use rspack_util::ext::DynHash;
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::ecma::atoms::Atom;
 
 use super::esm_import_dependency::esm_import_dependency_get_linking_error;
-use super::{create_resource_identifier_for_esm_dependency, esm_import_dependency_apply};
+use super::{create_resource_identifier_for_esm_import_dependency, esm_import_dependency_apply};
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -42,6 +43,7 @@ pub struct ESMImportSpecifierDependency {
   resource_identifier: String,
   export_presence_mode: ExportPresenceMode,
   attributes: Option<ImportAttributes>,
+  phase: ImportPhase,
   #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
   pub namespace_object_as_context: bool,
@@ -63,10 +65,11 @@ impl ESMImportSpecifierDependency {
     export_presence_mode: ExportPresenceMode,
     referenced_properties_in_destructuring: Option<HashSet<Atom>>,
     attributes: Option<ImportAttributes>,
+    phase: ImportPhase,
     source_map: Option<SharedSourceMap>,
   ) -> Self {
     let resource_identifier =
-      create_resource_identifier_for_esm_dependency(&request, attributes.as_ref());
+      create_resource_identifier_for_esm_import_dependency(&request, attributes.as_ref(), phase);
     Self {
       id: DependencyId::new(),
       request,
@@ -83,6 +86,7 @@ impl ESMImportSpecifierDependency {
       namespace_object_as_context: false,
       referenced_properties_in_destructuring,
       attributes,
+      phase,
       resource_identifier,
       source_map,
       factorize_info: Default::default(),
@@ -120,6 +124,21 @@ impl ESMImportSpecifierDependency {
     }
   }
 
+  /// Get the constant value to render in place of the imported binding, only set when
+  /// the exporting module declares it with `experiments.inlineConst` enabled.
+  pub fn get_inlinable_value<'a>(&self, mg: &'a ModuleGraph) -> Option<&'a InlinableValue> {
+    if self.call {
+      return None;
+    }
+    let [id] = self.get_ids(mg) else {
+      return None;
+    };
+    let module = mg.get_module_by_dependency_id(&self.id)?;
+    mg.get_exports_info(&module.identifier())
+      .get_read_only_export_info(mg, id)
+      .get_inlinable_value(mg)
+  }
+
   pub fn create_export_presence_mode(options: &JavascriptParserOptions) -> ExportPresenceMode {
     options
       .import_exports_presence
@@ -146,6 +165,22 @@ impl DependencyTemplate for ESMImportSpecifierDependency {
       ..
     } = code_generatable_context;
     let module_graph = compilation.get_module_graph();
+    // The inlined value doesn't reference the imported module, render it even if the
+    // connection is inactive
+    if let Some(value) = self.get_inlinable_value(&module_graph) {
+      let ids = self.get_ids(&module_graph);
+      let value = format!(
+        "/* inlined export {} */ {}",
+        property_access(ids, 0),
+        value.render()
+      );
+      if self.shorthand {
+        source.insert(self.range.end, &format!(": {value}"), None);
+      } else {
+        source.replace(self.range.start, self.range.end, &value, None)
+      }
+      return;
+    }
     // Only available when module factorization is successful.
     let reference_mgm = module_graph.module_graph_module_by_dependency_id(&self.id);
     let connection = module_graph.connection_by_dependency_id(&self.id);
@@ -235,10 +270,13 @@ impl DependencyTemplate for ESMImportSpecifierDependency {
 
   fn update_hash(
     &self,
-    _hasher: &mut dyn std::hash::Hasher,
-    _compilation: &Compilation,
+    hasher: &mut dyn std::hash::Hasher,
+    compilation: &Compilation,
     _runtime: Option<&RuntimeSpec>,
   ) {
+    self
+      .get_inlinable_value(&compilation.get_module_graph())
+      .dyn_hash(hasher);
   }
 }
 
@@ -264,6 +302,17 @@ impl Dependency for ESMImportSpecifierDependency {
     self.attributes.as_ref()
   }
 
+  fn get_phase(&self) -> ImportPhase {
+    self.phase
+  }
+
+  fn forward_id(&self) -> ForwardId {
+    match self.ids.first() {
+      Some(id) => ForwardId::Id(id.clone()),
+      None => ForwardId::All,
+    }
+  }
+
   fn set_used_by_exports(&mut self, used_by_exports: Option<UsedByExports>) {
     self.used_by_exports = used_by_exports;
   }
@@ -317,6 +366,10 @@ impl Dependency for ESMImportSpecifierDependency {
     module_graph: &ModuleGraph,
     _runtime: Option<&RuntimeSpec>,
   ) -> Vec<ExtendedReferencedExport> {
+    // inlined values don't read the export at runtime
+    if self.get_inlinable_value(module_graph).is_some() {
+      return vec![];
+    }
     let mut ids = self.get_ids(module_graph);
     // namespace import
     if ids.is_empty() {
@@ -12,6 +12,7 @@ mod provide_dependency;
 
 use rspack_core::DependencyCategory;
 use rspack_core::ImportAttributes;
+use rspack_core::ImportPhase;
 use rspack_util::json_stringify;
 
 pub use self::esm_compatibility_dependency::ESMCompatibilityDependency;
@@ -38,3 +39,16 @@ pub fn create_resource_identifier_for_esm_dependency(
   }
   ident
 }
+
+pub fn create_resource_identifier_for_esm_import_dependency(
+  request: &str,
+  attributes: Option<&ImportAttributes>,
+  phase: ImportPhase,
+) -> String {
+  let mut ident = create_resource_identifier_for_esm_dependency(request, attributes);
+  if phase != ImportPhase::Evaluation {
+    ident += "|";
+    ident += phase.as_str();
+  }
+  ident
+}
@@ -10,7 +10,7 @@ use rspack_core::{
   render_init_fragments, AsyncDependenciesBlockIdentifier, BuildMetaExportsType, ChunkGraph,
   Compilation, DependenciesBlock, DependencyId, DependencyRange, GenerateContext, Module,
   ModuleGraph, ModuleType, ParseContext, ParseResult, ParserAndGenerator, SideEffectsBailoutItem,
-  SourceType, TemplateContext, TemplateReplaceSource,
+  SourceType, TemplateContext, TemplateReplaceSource, NAMESPACE_OBJECT_EXPORT,
 };
 use rspack_error::miette::Diagnostic;
 use rspack_error::{DiagnosticExt, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
@@ -21,7 +21,7 @@ use swc_core::ecma::ast;
 use swc_core::ecma::parser::{lexer::Lexer, EsSyntax, Syntax};
 use swc_node_comments::SwcComments;
 
-use crate::dependency::ESMCompatibilityDependency;
+use crate::dependency::{CommonJsExportRequireDependency, ESMCompatibilityDependency};
 use crate::visitors::{scan_dependencies, swc_visitor::resolver};
 use crate::visitors::{semicolon, ScanDependenciesResult};
 use crate::{BoxJavascriptParserPlugin, SideEffectsFlagPluginVisitor, SyntaxContextInfo};
@@ -303,6 +303,14 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         .get_blocks()
         .iter()
         .for_each(|block_id| self.source_block(compilation, block_id, &mut source, &mut context));
+
+      // CommonJS exports are rendered as properties of the namespace object when concatenated
+      if let Some(scope) = &mut context.concatenation_scope
+        && module.build_meta().exports_type != BuildMetaExportsType::Namespace
+      {
+        scope.register_namespace_export(NAMESPACE_OBJECT_EXPORT);
+        source.insert(0, &format!("var {NAMESPACE_OBJECT_EXPORT} = {{}};\n"), None);
+      }
       generate_context.concatenation_scope = context.concatenation_scope.take();
       render_init_fragments(source.boxed(), init_fragments, generate_context)
     } else {
@@ -316,26 +324,36 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
   fn get_concatenation_bailout_reason(
     &self,
     module: &dyn rspack_core::Module,
-    _mg: &ModuleGraph,
+    mg: &ModuleGraph,
     _cg: &ChunkGraph,
   ) -> Option<Cow<'static, str>> {
-    // Only ES modules are valid for optimization
-    if module.build_meta().exports_type != BuildMetaExportsType::Namespace {
-      return Some("Module is not an ECMAScript module".into());
-    }
-
-    if let Some(deps) = module.get_presentational_dependencies() {
-      if !deps.iter().any(|dep| {
-        // https://github.com/webpack/webpack/blob/b9fb99c63ca433b24233e0bbc9ce336b47872c08/lib/javascript/JavascriptGenerator.js#L65-L74
-        dep
-          .as_any()
-          .downcast_ref::<ESMCompatibilityDependency>()
-          .is_some()
-      }) {
-        return Some("Module is not an ECMAScript module".into());
+    match module.build_meta().exports_type {
+      BuildMetaExportsType::Namespace => {
+        if let Some(deps) = module.get_presentational_dependencies() {
+          if !deps.iter().any(|dep| {
+            // https://github.com/webpack/webpack/blob/b9fb99c63ca433b24233e0bbc9ce336b47872c08/lib/javascript/JavascriptGenerator.js#L65-L74
+            dep
+              .as_any()
+              .downcast_ref::<ESMCompatibilityDependency>()
+              .is_some()
+          }) {
+            return Some("Module is not an ECMAScript module".into());
+          }
+        } else {
+          return Some("Module is not an ECMAScript module".into());
+        }
       }
-    } else {
-      return Some("Module is not an ECMAScript module".into());
+      // CommonJS modules whose exports are statically analyzable, `module`, `exports`
+      // and top level `this` accessed as a whole make the exports type `Unset`
+      BuildMetaExportsType::Default | BuildMetaExportsType::Flagged => {
+        if module.get_dependencies().iter().any(|dependency_id| {
+          mg.dependency_by_id(dependency_id)
+            .is_some_and(|dependency| dependency.is::<CommonJsExportRequireDependency>())
+        }) {
+          return Some("Module re-exports a CommonJS module".into());
+        }
+      }
+      _ => return Some("Module is not an ECMAScript module".into()),
     }
 
     if let Some(bailout) = module.build_info().module_concatenation_bailout.as_deref() {
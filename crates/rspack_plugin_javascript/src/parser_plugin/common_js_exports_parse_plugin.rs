@@ -3,7 +3,7 @@ use rspack_core::{
   RuntimeRequirementsDependency, SpanExt,
 };
 use swc_core::atoms::Atom;
-use swc_core::common::Spanned;
+use swc_core::common::{Mark, Spanned, SyntaxContext};
 use swc_core::ecma::ast::{
   AssignExpr, AssignTarget, CallExpr, PropOrSpread, SimpleAssignTarget, UnaryExpr,
 };
@@ -13,6 +13,7 @@ use swc_core::ecma::ast::{Expr, Lit, Prop, PropName, ThisExpr, UnaryOp};
 use super::JavascriptParserPlugin;
 use crate::dependency::{CommonJsExportRequireDependency, CommonJsExportsDependency};
 use crate::dependency::{CommonJsSelfReferenceDependency, ExportsBase, ModuleDecoratorDependency};
+use crate::is_pure_expression;
 use crate::utils::eval::{self, BasicEvaluatedExpression};
 use crate::visitors::expr_like::ExprLike;
 use crate::visitors::{
@@ -256,7 +257,17 @@ impl JavascriptParser<'_> {
   }
 }
 
-pub struct CommonJsExportsParserPlugin;
+pub struct CommonJsExportsParserPlugin {
+  unresolved_context: SyntaxContext,
+}
+
+impl CommonJsExportsParserPlugin {
+  pub fn new(unresolved_mark: Mark) -> Self {
+    Self {
+      unresolved_context: SyntaxContext::empty().apply_mark(unresolved_mark),
+    }
+  }
+}
 
 impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
   fn identifier(
@@ -429,15 +440,28 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
       // exports.a = 1;
       // module.exports.a = 1;
       // this.a = 1;
+      let dependencies_len =
+        parser.dependencies.len() + parser.presentational_dependencies.len() + parser.blocks.len();
+      parser.walk_expression(&assign_expr.right);
+      // `exports.a = function () {};` can be removed entirely if `a` is unused,
+      // as long as the value has no side effects and contains no dependencies to render
+      let removable_range = (remaining[0] != "__esModule"
+        && parser.is_statement_level_expression(assign_expr.span())
+        && dependencies_len
+          == parser.dependencies.len()
+            + parser.presentational_dependencies.len()
+            + parser.blocks.len()
+        && is_pure_expression(&assign_expr.right, self.unresolved_context, parser.comments))
+      .then(|| (assign_expr.span.real_lo(), assign_expr.span.real_hi()));
       parser
         .dependencies
         .push(Box::new(CommonJsExportsDependency::new(
           (left_expr.span().real_lo(), left_expr.span().real_hi()),
           None,
+          removable_range,
           base,
           remaining.to_owned(),
         )));
-      parser.walk_expression(&assign_expr.right);
       Some(true)
     };
 
@@ -531,6 +555,7 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
           .push(Box::new(CommonJsExportsDependency::new(
             (call_expr.span.real_lo(), call_expr.span.real_hi()),
             Some((arg2.span().real_lo(), arg2.span().real_hi())),
+            None,
             base,
             vec![str.value.clone()],
           )));
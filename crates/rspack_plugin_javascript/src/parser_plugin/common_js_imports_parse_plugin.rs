@@ -5,6 +5,7 @@ use rspack_core::{ContextNameSpaceObject, ContextOptions};
 use rspack_error::{DiagnosticExt, Severity};
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{CallExpr, Expr, ExprOrSpread, Ident, MemberExpr, NewExpr, UnaryExpr};
+use swc_core::ecma::atoms::Atom;
 
 use super::JavascriptParserPlugin;
 use crate::dependency::local_module_dependency::LocalModuleDependency;
@@ -232,10 +233,14 @@ impl CommonJsImportsParserPlugin {
   ) -> Option<bool> {
     param.is_string().then(|| {
       let range_expr: DependencyRange = param.range().into();
+      let referenced_properties = parser
+        .destructuring_assignment_properties_for(&span)
+        .map(|properties| properties.into_iter().map(Atom::from).collect());
       let dep = CommonJsRequireDependency::new(
         param.string().to_string(),
         range_expr,
         Some(span.into()),
+        referenced_properties,
         parser.in_try,
         Some(parser.source_map.clone()),
       );
@@ -1,8 +1,12 @@
 use itertools::Itertools;
-use rspack_core::{BoxDependency, ConstDependency, DependencyRange, DependencyType, SpanExt};
+use rspack_core::{
+  BoxDependency, ConstDependency, DependencyRange, DependencyType, ImportPhase, InlinableValue,
+  SpanExt,
+};
 use swc_core::atoms::Atom;
 use swc_core::common::comments::CommentKind;
 use swc_core::common::Spanned;
+use swc_core::ecma::ast::{Decl, Expr, Lit, VarDeclKind};
 
 use super::esm_import_dependency_parser_plugin::{ESMSpecifierData, ESM_SPECIFIER_TAG};
 use super::{
@@ -16,10 +20,59 @@ use crate::dependency::{
 };
 use crate::utils::object_properties::get_attributes;
 use crate::visitors::{
-  ExportDefaultDeclaration, ExportDefaultExpression, ExportImport, ExportLocal, JavascriptParser,
-  TagInfoData,
+  ExportDefaultDeclaration, ExportDefaultExpression, ExportImport, ExportLocal,
+  ExportNamedDeclaration, JavascriptParser, TagInfoData,
 };
 
+// longer strings are not inlined, they would bloat every module importing them
+const INLINABLE_STRING_MAX_LENGTH: usize = 6;
+
+fn get_inlinable_value(statement: ExportLocal, local_id: &Atom) -> Option<InlinableValue> {
+  let ExportLocal::Named(ExportNamedDeclaration::Decl(export)) = statement else {
+    return None;
+  };
+  let Decl::Var(var) = &export.decl else {
+    return None;
+  };
+  if var.kind != VarDeclKind::Const {
+    return None;
+  }
+  let init = var
+    .decls
+    .iter()
+    .find(|declarator| {
+      declarator
+        .name
+        .as_ident()
+        .is_some_and(|ident| &ident.id.sym == local_id)
+    })?
+    .init
+    .as_deref()?;
+  match init {
+    Expr::Lit(Lit::Null(_)) => Some(InlinableValue::Null),
+    Expr::Lit(Lit::Bool(bool)) => Some(InlinableValue::Boolean(bool.value)),
+    Expr::Lit(Lit::Num(num)) => Some(InlinableValue::Number(
+      num
+        .raw
+        .as_ref()
+        .map(|raw| raw.to_string())
+        .unwrap_or_else(|| num.value.to_string()),
+    )),
+    Expr::Lit(Lit::Str(str)) if str.value.chars().count() <= INLINABLE_STRING_MAX_LENGTH => {
+      Some(InlinableValue::String(
+        str
+          .raw
+          .as_ref()
+          .map(|raw| raw.to_string())
+          .unwrap_or_else(|| {
+            serde_json::to_string(str.value.as_str()).expect("should serialize string")
+          }),
+      ))
+    }
+    _ => None,
+  }
+}
+
 pub struct ESMExportDependencyParserPlugin;
 
 impl JavascriptParserPlugin for ESMExportDependencyParserPlugin {
@@ -51,6 +104,7 @@ impl JavascriptParserPlugin for ESMExportDependencyParserPlugin {
       DependencyType::EsmExport,
       matches!(statement, ExportImport::All(_)),
       statement.get_with_obj().map(get_attributes),
+      ImportPhase::Evaluation,
       Some(parser.source_map.clone()),
     );
     parser.dependencies.push(Box::new(side_effect_dep));
@@ -90,9 +144,15 @@ impl JavascriptParserPlugin for ESMExportDependencyParserPlugin {
         Some(parser.source_map.clone()),
       )) as BoxDependency
     } else {
+      let inlinable = if parser.compiler_options.experiments.inline_const {
+        get_inlinable_value(statement, local_id)
+      } else {
+        None
+      };
       Box::new(ESMExportSpecifierDependency::new(
         export_name.clone(),
         local_id.clone(),
+        inlinable,
         statement.span().into(),
         Some(parser.source_map.clone()),
       ))
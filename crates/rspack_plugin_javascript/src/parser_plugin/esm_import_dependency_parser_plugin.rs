@@ -1,10 +1,12 @@
-use rspack_core::{ConstDependency, Dependency, DependencyType, ImportAttributes, SpanExt};
+use rspack_core::{
+  ConstDependency, Dependency, DependencyType, ImportAttributes, ImportPhase, SpanExt,
+};
 use swc_core::atoms::Atom;
 use swc_core::common::{Span, Spanned};
+use swc_core::ecma::ast::{self, Expr, Ident, ImportDecl};
 use swc_core::ecma::ast::{
   AssignExpr, AssignOp, AssignTarget, AssignTargetPat, Callee, MemberExpr, OptChainBase,
 };
-use swc_core::ecma::ast::{Expr, Ident, ImportDecl};
 
 use super::{InnerGraphPlugin, JavascriptParserPlugin};
 use crate::dependency::{ESMImportSideEffectDependency, ESMImportSpecifierDependency};
@@ -47,6 +49,14 @@ fn get_non_optional_member_chain_from_member(member: &MemberExpr, mut count: i32
   get_non_optional_member_chain_from_expr(&member.obj, count)
 }
 
+fn get_import_phase(import_decl: &ImportDecl) -> ImportPhase {
+  match import_decl.phase {
+    ast::ImportPhase::Evaluation => ImportPhase::Evaluation,
+    ast::ImportPhase::Source => ImportPhase::Source,
+    ast::ImportPhase::Defer => ImportPhase::Defer,
+  }
+}
+
 pub struct ESMImportDependencyParserPlugin;
 
 pub const ESM_SPECIFIER_TAG: &str = "_identifier__esm_specifier_tag__";
@@ -58,6 +68,7 @@ pub struct ESMSpecifierData {
   pub ids: Vec<Atom>,
   pub source_order: i32,
   pub attributes: Option<ImportAttributes>,
+  pub phase: ImportPhase,
 }
 
 impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
@@ -69,6 +80,11 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
   ) -> Option<bool> {
     parser.last_esm_import_order += 1;
     let attributes = import_decl.with.as_ref().map(|obj| get_attributes(obj));
+    let phase = get_import_phase(import_decl);
+    if phase == ImportPhase::Defer {
+      // the deferred module must stay a separate module to be evaluated lazily
+      parser.build_info.module_concatenation_bailout = Some("import defer".into());
+    }
     let dependency = ESMImportSideEffectDependency::new(
       source.into(),
       parser.last_esm_import_order,
@@ -77,6 +93,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       DependencyType::EsmImport,
       false,
       attributes,
+      phase,
       Some(parser.source_map.clone()),
     );
     parser.dependencies.push(Box::new(dependency));
@@ -114,6 +131,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
         ids: id.map(|id| vec![id.clone()]).unwrap_or_default(),
         source_order: parser.last_esm_import_order,
         attributes: statement.with.as_ref().map(|obj| get_attributes(obj)),
+        phase: get_import_phase(statement),
       }),
     );
     Some(true)
@@ -145,6 +163,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       ESMImportSpecifierDependency::create_export_presence_mode(parser.javascript_options),
       parser.properties_in_destructuring.remove(&ident.sym),
       settings.attributes,
+      settings.phase,
       Some(parser.source_map.clone()),
     );
     let dep_id = *dep.id();
@@ -212,6 +231,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       ESMImportSpecifierDependency::create_export_presence_mode(parser.javascript_options),
       None,
       settings.attributes,
+      settings.phase,
       Some(parser.source_map.clone()),
     );
     let dep_id = *dep.id();
@@ -276,6 +296,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       ESMImportSpecifierDependency::create_export_presence_mode(parser.javascript_options),
       None,
       settings.attributes,
+      settings.phase,
       Some(parser.source_map.clone()),
     );
     let dep_id = *dep.id();
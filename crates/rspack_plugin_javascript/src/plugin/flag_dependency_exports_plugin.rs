@@ -5,8 +5,8 @@ use rspack_collections::{IdentifierMap, IdentifierSet};
 use rspack_core::{
   incremental::IncrementalPasses, ApplyContext, BuildMetaExportsType, Compilation,
   CompilationFinishModules, CompilerOptions, DependenciesBlock, DependencyId, ExportInfoProvided,
-  ExportNameOrSpec, ExportsInfo, ExportsOfExportsSpec, ExportsSpec, Logger, ModuleGraph,
-  ModuleGraphConnection, ModuleIdentifier, Plugin, PluginContext,
+  ExportNameOrSpec, ExportSpec, ExportsInfo, ExportsOfExportsSpec, ExportsSpec, Logger,
+  ModuleGraph, ModuleGraphConnection, ModuleIdentifier, Plugin, PluginContext,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
@@ -264,6 +264,16 @@ impl<'a> FlagDependencyExportsState<'a> {
         self.changed = true;
       }
 
+      if let ExportNameOrSpec::ExportSpec(ExportSpec {
+        inlinable: Some(inlinable),
+        ..
+      }) = export_name_or_spec
+        && export_info.inlinable(self.mg) != Some(inlinable)
+      {
+        export_info.set_inlinable(self.mg, Some(inlinable.clone()));
+        self.changed = true;
+      }
+
       if let Some(exports) = exports {
         let nested_exports_info = export_info.create_nested_exports_info(self.mg);
         self.merge_exports(
@@ -10,10 +10,10 @@ use rspack_core::concatenated_module::{
   is_esm_dep_like, ConcatenatedInnerModule, ConcatenatedModule, RootModuleContext,
 };
 use rspack_core::{
-  filter_runtime, merge_runtime, ApplyContext, Compilation, CompilationOptimizeChunkModules,
-  CompilerOptions, ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger, Module,
-  ModuleExt, ModuleGraph, ModuleGraphModule, ModuleIdentifier, Plugin, PluginContext,
-  ProvidedExports, RuntimeCondition, RuntimeSpec, SourceType,
+  filter_runtime, merge_runtime, ApplyContext, BuildMetaExportsType, Compilation,
+  CompilationOptimizeChunkModules, CompilerOptions, ExportInfoProvided, ExtendedReferencedExport,
+  LibIdentOptions, Logger, Module, ModuleExt, ModuleGraph, ModuleGraphModule, ModuleIdentifier,
+  Plugin, PluginContext, ProvidedExports, RuntimeCondition, RuntimeSpec, SourceType,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
@@ -763,6 +763,14 @@ impl ModuleConcatenationPlugin {
           return (false, false, module_id, bailout_reason);
         }
 
+        // CommonJS modules can only be concatenated into an ECMAScript module
+        if m.expect("should have module").build_meta().exports_type
+          != BuildMetaExportsType::Namespace
+        {
+          can_be_root = false;
+          bailout_reason.push("Module is not an ECMAScript module".into());
+        }
+
         let exports_info = module_graph.get_exports_info(&module_id);
         let relevant_exports = exports_info.get_relevant_exports(&module_graph, None);
         let unknown_exports = relevant_exports
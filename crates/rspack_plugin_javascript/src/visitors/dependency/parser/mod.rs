@@ -321,7 +321,9 @@ impl<'parser> JavascriptParser<'parser> {
     if module_type.is_js_auto() || module_type.is_js_dynamic() {
       plugins.push(Box::new(parser_plugin::CommonJsImportsParserPlugin));
       plugins.push(Box::new(parser_plugin::CommonJsPlugin));
-      plugins.push(Box::new(parser_plugin::CommonJsExportsParserPlugin));
+      plugins.push(Box::new(parser_plugin::CommonJsExportsParserPlugin::new(
+        unresolved_mark,
+      )));
       if compiler_options.node.is_some() {
         plugins.push(Box::new(parser_plugin::NodeStuffPlugin));
       }
@@ -145,6 +145,7 @@ impl Module for LazyCompilationProxyModule {
       self.client.clone(),
       DependencyRange::new(0, 0),
       None,
+      None,
       false,
       None,
     );
@@ -0,0 +1,37 @@
+// create a namespace object for `import defer`, the module is evaluated on the first access
+// to its exports, while symbols and "then" are answered without evaluating it
+__webpack_require__.z = function(moduleId) {
+	var ns;
+	var init = function() {
+		if(!ns) ns = __webpack_require__(moduleId);
+		return ns;
+	};
+	var isLazy = function(key) { return typeof key === 'symbol' || key === 'then'; };
+	var target = Object.create(null);
+	if(typeof Symbol !== 'undefined' && Symbol.toStringTag) {
+		Object.defineProperty(target, Symbol.toStringTag, { value: 'Deferred Module' });
+	}
+	return new Proxy(target, {
+		get: function(target, key) {
+			if(isLazy(key)) return target[key];
+			return init()[key];
+		},
+		has: function(target, key) {
+			if(isLazy(key)) return key in target;
+			return key in init();
+		},
+		ownKeys: function(target) {
+			return Reflect.ownKeys(init()).concat(Reflect.ownKeys(target).filter(function(key) { return !(key in ns); }));
+		},
+		getOwnPropertyDescriptor: function(target, key) {
+			if(isLazy(key)) return Reflect.getOwnPropertyDescriptor(target, key);
+			var descriptor = Reflect.getOwnPropertyDescriptor(init(), key);
+			if(descriptor) descriptor.configurable = true;
+			return descriptor;
+		},
+		set: function() { return false; },
+		defineProperty: function() { return false; },
+		deleteProperty: function() { return false; },
+		setPrototypeOf: function() { return false; }
+	});
+};
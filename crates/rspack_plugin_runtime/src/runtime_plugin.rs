@@ -26,9 +26,10 @@ use crate::{
     ESMModuleDecoratorRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
     GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
     GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HasOwnPropertyRuntimeModule,
-    LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule,
-    NonceRuntimeModule, OnChunkLoadedRuntimeModule, PublicPathRuntimeModule,
-    RelativeUrlRuntimeModule, RuntimeIdRuntimeModule, SystemContextRuntimeModule,
+    LoadScriptRuntimeModule, MakeDeferredNamespaceObjectRuntimeModule,
+    MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NonceRuntimeModule,
+    OnChunkLoadedRuntimeModule, PublicPathRuntimeModule, RelativeUrlRuntimeModule,
+    RuntimeIdRuntimeModule, SystemContextRuntimeModule,
   },
   RuntimePluginHooks,
 };
@@ -51,6 +52,7 @@ static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
     RuntimeGlobals::GET_FULL_HASH,
     RuntimeGlobals::GLOBAL,
     RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+    RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT,
     RuntimeGlobals::MODULE_CACHE,
     RuntimeGlobals::MODULE_FACTORIES,
     RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY,
@@ -63,6 +65,7 @@ static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
     RuntimeGlobals::ASYNC_MODULE,
     // RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
+    RuntimeGlobals::COMPILE_WASM,
     RuntimeGlobals::SHARE_SCOPE_MAP,
     RuntimeGlobals::INITIALIZE_SHARING,
     RuntimeGlobals::LOAD_SCRIPT,
@@ -107,6 +110,10 @@ static TREE_DEPENDENCIES: LazyLock<Vec<(RuntimeGlobals, Vec<RuntimeGlobals>)>> =
           RuntimeGlobals::REQUIRE,
         ],
       ),
+      (
+        RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT,
+        vec![RuntimeGlobals::REQUIRE],
+      ),
       (
         RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
         vec![RuntimeGlobals::HAS_OWN_PROPERTY],
@@ -470,6 +477,12 @@ fn runtime_requirements_in_tree(
           MakeNamespaceObjectRuntimeModule::default().boxed(),
         )?;
       }
+      RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT => {
+        compilation.add_runtime_module(
+          chunk_ukey,
+          MakeDeferredNamespaceObjectRuntimeModule::default().boxed(),
+        )?;
+      }
       RuntimeGlobals::COMPAT_GET_DEFAULT_EXPORT => {
         compilation.add_runtime_module(
           chunk_ukey,
@@ -27,13 +27,16 @@ fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if runtime_requirements
+    .intersects(RuntimeGlobals::INSTANTIATE_WASM | RuntimeGlobals::COMPILE_WASM)
+  {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
       chunk_ukey,
       AsyncWasmLoadingRuntimeModule::new(
         format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
         true,
+        *runtime_requirements,
         *chunk_ukey,
       )
       .boxed(),
@@ -81,7 +84,9 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if runtime_requirements
+    .intersects(RuntimeGlobals::INSTANTIATE_WASM | RuntimeGlobals::COMPILE_WASM)
+  {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
 
     let import_enabled = compilation.options.output.module
@@ -101,6 +106,7 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
           include_str!("runtime/read_file_compile_async_wasm.js").to_string()
         },
         false,
+        *runtime_requirements,
         *chunk_ukey,
       )
       .boxed(),
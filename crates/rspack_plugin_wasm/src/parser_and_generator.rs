@@ -304,6 +304,114 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
+/// Parser and generator of `import source` of WebAssembly modules, which export the compiled
+/// `WebAssembly.Module` as the default export instead of an instance.
+#[cacheable]
+#[derive(Debug)]
+pub struct WasmSourceParserAndGenerator {
+  #[cacheable(with=Unsupported)]
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+#[cacheable_dyn]
+impl ParserAndGenerator for WasmSourceParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.has_top_level_await = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut diagnostic = vec![];
+    if let Some(Err(err)) = Parser::new(0)
+      .parse_all(&source.buffer())
+      .find(|payload| payload.is_err())
+    {
+      diagnostic.push(Diagnostic::error(
+        "Wasm Parse Error".into(),
+        err.to_string(),
+      ));
+    }
+
+    Ok(
+      ParseResult {
+        dependencies: vec![Box::new(StaticExportsDependency::new(
+          StaticExportsSpec::Array(vec![Atom::from("default")]),
+          false,
+        ))],
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        code_generation_dependencies: vec![],
+        source,
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: Option<&SourceType>) -> f64 {
+    match source_type.unwrap_or(&SourceType::Wasm) {
+      SourceType::JavaScript => 40.0,
+      SourceType::Wasm => module.source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_source(source);
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in WasmSourceParserAndGenerator::generate");
+    let wasm_path_with_info =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info);
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+        runtime_requirements.insert(RuntimeGlobals::COMPILE_WASM);
+
+        Ok(
+          RawStringSource::from(format!(
+            "module.exports = {}(module.id, {}).then(function(wasmModule) {{ exports[\"default\"] = wasmModule; return exports; }});",
+            RuntimeGlobals::COMPILE_WASM,
+            serde_json::to_string(&hash).expect("should be ok"),
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<Cow<'static, str>> {
+    Some("Module Concatenation is not implemented for WasmSourceParserAndGenerator".into())
+  }
+}
+
 fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
@@ -3,7 +3,7 @@ use rspack_collections::Identifier;
 use rspack_core::rspack_sources::{BoxSource, RawStringSource, SourceExt};
 use rspack_core::{
   get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
 };
 use rspack_util::infallible::ResultInfallibleExt as _;
 use rspack_util::itoa;
@@ -14,6 +14,7 @@ pub struct AsyncWasmLoadingRuntimeModule {
   id: Identifier,
   generate_load_binary_code: String,
   supports_streaming: bool,
+  runtime_requirements: RuntimeGlobals,
   chunk: ChunkUkey,
 }
 
@@ -21,12 +22,14 @@ impl AsyncWasmLoadingRuntimeModule {
   pub fn new(
     generate_load_binary_code: String,
     supports_streaming: bool,
+    runtime_requirements: RuntimeGlobals,
     chunk: ChunkUkey,
   ) -> Self {
     Self::with_default(
       Identifier::from("webpack/runtime/async_wasm_loading"),
       generate_load_binary_code,
       supports_streaming,
+      runtime_requirements,
       chunk,
     )
   }
@@ -60,19 +63,28 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
           .runtime(chunk.runtime().as_str()),
       )
       .always_ok();
-    Ok(
-      RawStringSource::from(get_async_wasm_loading(
-        &self
-          .generate_load_binary_code
-          .cow_replace("$PATH", &format!("\"{}\"", path))
-          .cow_replace(
-            "$IMPORT_META_NAME",
-            compilation.options.output.import_meta_name.as_str(),
-          ),
-        self.supports_streaming,
-      ))
-      .boxed(),
-    )
+    let req = self
+      .generate_load_binary_code
+      .cow_replace("$PATH", &format!("\"{}\"", path))
+      .cow_replace(
+        "$IMPORT_META_NAME",
+        compilation.options.output.import_meta_name.as_str(),
+      )
+      .into_owned();
+    let mut source = String::new();
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::INSTANTIATE_WASM)
+    {
+      source += &get_async_wasm_loading(&req, self.supports_streaming);
+    }
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::COMPILE_WASM)
+    {
+      source += &get_async_wasm_compiling(&req, self.supports_streaming);
+    }
+    Ok(RawStringSource::from(source).boxed())
   }
 
   fn stage(&self) -> RuntimeModuleStage {
@@ -129,3 +141,52 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     )
   }
 }
+
+fn get_async_wasm_compiling(req: &str, supports_streaming: bool) -> String {
+  let fallback_code = r#"
+          .then(function(x) { return x.arrayBuffer();})
+          .then(function(bytes) { return WebAssembly.compile(bytes);});
+"#;
+
+  let streaming_code = r#"
+      return req.then(function(res) {
+        if (typeof WebAssembly.compileStreaming === "function") {
+          return WebAssembly.compileStreaming(res)
+            .then(
+              function(module) { return module;},
+              function(e) {
+                if(res.headers.get("Content-Type") !== "application/wasm") {
+                  console.warn("`WebAssembly.compileStreaming` failed because your server does not serve wasm with `application/wasm` MIME type. Falling back to `WebAssembly.compile` which is slower. Original error:\n", e);
+                  return fallback();
+                }
+                throw e;
+              }
+            );
+        }
+        return fallback();
+      });
+"#;
+
+  if supports_streaming {
+    format!(
+      r#"
+    __webpack_require__.vs = function(wasmModuleId, wasmModuleHash) {{
+      var req = {req};
+      var fallback = function() {{
+        return req{fallback_code}
+      }}
+      {streaming_code}
+    }};
+"#
+    )
+  } else {
+    let req = req.trim_end_matches(';');
+    format!(
+      r#"
+    __webpack_require__.vs = function(wasmModuleId, wasmModuleHash) {{
+      return {req}{fallback_code}
+    }};
+      "#
+    )
+  }
+}
@@ -10,7 +10,7 @@ use rspack_core::{
 use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName, WasmSourceParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
@@ -54,7 +54,12 @@ async fn render_manifest(
 
   let files = ordered_modules
     .par_iter()
-    .filter(|m| *m.module_type() == ModuleType::WasmAsync)
+    .filter(|m| {
+      matches!(
+        m.module_type(),
+        ModuleType::WasmAsync | ModuleType::WasmSource
+      )
+    })
     .map(|m| {
       let code_gen_result = compilation
         .code_generation_results
@@ -118,6 +123,19 @@ impl Plugin for AsyncWasmPlugin {
       }),
     );
 
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::WasmSource,
+      Box::new(move |_, _| {
+        Box::new({
+          WasmSourceParserAndGenerator {
+            module_id_to_filename: module_id_to_filename_without_ext.clone(),
+          }
+        }) as Box<dyn ParserAndGenerator>
+      }),
+    );
+
     Ok(())
   }
 }
@@ -0,0 +1,126 @@
+use std::{
+  hash::{BuildHasher, Hasher},
+  io::ErrorKind,
+};
+
+use rspack_fs::{Error, Result};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxBuildHasher;
+
+use super::{BlobMeta, BlobStore};
+
+/// Blob store based on local directory
+///
+/// Each blob is saved as a file in `root`, it is useful for offline testing and
+/// sharing cache through a mounted directory.
+#[derive(Debug)]
+pub struct LocalBlobStore {
+  root: Utf8PathBuf,
+}
+
+impl LocalBlobStore {
+  pub fn new(root: Utf8PathBuf) -> Self {
+    Self { root }
+  }
+
+  fn get_path(&self, key: &str) -> Utf8PathBuf {
+    self.root.join(key)
+  }
+}
+
+fn ignore_not_found<T>(res: std::io::Result<T>) -> Result<Option<T>> {
+  match res {
+    Ok(value) => Ok(Some(value)),
+    Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+    Err(e) => Err(Error::from(e)),
+  }
+}
+
+#[async_trait::async_trait]
+impl BlobStore for LocalBlobStore {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    ignore_not_found(tokio::fs::read(self.get_path(key)).await)
+  }
+
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()> {
+    let path = self.get_path(key);
+    if let Some(parent) = path.parent() {
+      tokio::fs::create_dir_all(parent).await?;
+    }
+    // write to a temporary file first to avoid other readers get a partial blob
+    let temp_path = Utf8PathBuf::from(format!("{path}.writing"));
+    tokio::fs::write(&temp_path, value).await?;
+    tokio::fs::rename(&temp_path, &path).await?;
+    Ok(())
+  }
+
+  async fn rename(&self, from: &str, to: &str) -> Result<()> {
+    let to = self.get_path(to);
+    if let Some(parent) = to.parent() {
+      tokio::fs::create_dir_all(parent).await?;
+    }
+    ignore_not_found(tokio::fs::rename(self.get_path(from), &to).await)?;
+    Ok(())
+  }
+
+  async fn remove(&self, key: &str) -> Result<()> {
+    ignore_not_found(tokio::fs::remove_file(self.get_path(key)).await)?;
+    Ok(())
+  }
+
+  async fn stat(&self, key: &str) -> Result<Option<BlobMeta>> {
+    let Some(meta) = ignore_not_found(tokio::fs::metadata(self.get_path(key)).await)? else {
+      return Ok(None);
+    };
+    if !meta.is_file() {
+      return Ok(None);
+    }
+    // hash the content instead of using mtime, so the blobs copied by other
+    // tools are still valid
+    let Some(content) = self.get(key).await? else {
+      return Ok(None);
+    };
+    let mut hasher = FxBuildHasher.build_hasher();
+    hasher.write(&content);
+    Ok(Some(BlobMeta {
+      size: meta.len(),
+      hash: format!("{:016x}", hasher.finish()),
+    }))
+  }
+
+  async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+    let mut keys = vec![];
+    let mut stack = vec![self.get_path(prefix)];
+    while let Some(dir) = stack.pop() {
+      let Some(mut entries) = ignore_not_found(tokio::fs::read_dir(&dir).await)? else {
+        continue;
+      };
+      while let Some(entry) = entries.next_entry().await? {
+        let Ok(path) = Utf8PathBuf::from_path_buf(entry.path()) else {
+          continue;
+        };
+        if entry.file_type().await?.is_dir() {
+          stack.push(path);
+        } else if let Some(key) = to_key(&self.root, &path) {
+          keys.push(key);
+        }
+      }
+    }
+    Ok(keys)
+  }
+}
+
+fn to_key(root: &Utf8Path, path: &Utf8Path) -> Option<String> {
+  let relative = path.strip_prefix(root).ok()?;
+  // skip the temporary files of unfinished writing
+  if relative.as_str().ends_with(".writing") {
+    return None;
+  }
+  Some(
+    relative
+      .components()
+      .map(|c| c.as_str())
+      .collect::<Vec<_>>()
+      .join("/"),
+  )
+}
@@ -0,0 +1,36 @@
+mod local;
+
+pub use local::LocalBlobStore;
+use rspack_fs::Result;
+
+/// The metadata of a blob
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobMeta {
+  pub size: u64,
+  /// The hash of blob content, it is used to validate packs
+  ///
+  /// It should be changed when the blob is replaced and be kept when the blob
+  /// is renamed, such as a content hash saved with the blob or the ETag of S3.
+  pub hash: String,
+}
+
+/// A key-value store for the files of pack storage
+///
+/// Keys are `/` separated relative paths of pack storage files, such as
+/// `<version>/<scope>/scope_meta`, so the blob store can be backed by a local
+/// directory as well as a remote service like HTTP or S3.
+#[async_trait::async_trait]
+pub trait BlobStore: std::fmt::Debug + Sync + Send {
+  /// Get the content of blob, return None if the blob not exists
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+  /// Create or replace the blob
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()>;
+  /// Move the blob to another key, do nothing if the blob not exists
+  async fn rename(&self, from: &str, to: &str) -> Result<()>;
+  /// Remove the blob, do nothing if the blob not exists
+  async fn remove(&self, key: &str) -> Result<()>;
+  /// Get the metadata of blob, return None if the blob not exists
+  async fn stat(&self, key: &str) -> Result<Option<BlobMeta>>;
+  /// List the keys of all blobs under `prefix`, an empty prefix means all of blobs
+  async fn list(&self, prefix: &str) -> Result<Vec<String>>;
+}
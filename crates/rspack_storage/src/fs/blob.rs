@@ -0,0 +1,300 @@
+use std::{io::ErrorKind, sync::Arc};
+
+use rspack_fs::{FileMetadata, MemoryReadStream, WriteStream};
+use rspack_paths::{Utf8Component, Utf8Path};
+use rustc_hash::FxHashSet as HashSet;
+
+use super::{FSError, FSOperation, FSResult, FileSystem, Reader, Writer};
+use crate::BlobStore;
+
+/// File system based on blob store
+///
+/// Directories are not stored in blob store, a directory exists when there are
+/// blobs under it, so the pack storage can read and write packs through any blob store.
+#[derive(Debug)]
+pub struct BlobFileSystem(pub Arc<dyn BlobStore>);
+
+fn to_key(path: &Utf8Path) -> String {
+  path
+    .components()
+    .filter_map(|c| match c {
+      Utf8Component::Normal(s) => Some(s),
+      _ => None,
+    })
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+fn not_found(path: &Utf8Path, opt: FSOperation) -> FSError {
+  FSError::from_fs_error(
+    path,
+    opt,
+    rspack_fs::Error::Io(std::io::Error::new(ErrorKind::NotFound, "file not exists")),
+  )
+}
+
+/// Write stream of blob
+///
+/// The contents are buffered in memory and uploaded when the stream is flushed,
+/// so readers never get a partial blob. Flushing again without new contents
+/// does not upload the blob twice.
+#[derive(Debug)]
+struct BlobWriteStream {
+  key: String,
+  contents: Vec<u8>,
+  store: Arc<dyn BlobStore>,
+  committed: bool,
+}
+
+#[async_trait::async_trait]
+impl WriteStream for BlobWriteStream {
+  async fn write(&mut self, buf: &[u8]) -> rspack_fs::Result<usize> {
+    self.contents.extend_from_slice(buf);
+    self.committed = false;
+    Ok(buf.len())
+  }
+  async fn write_all(&mut self, buf: &[u8]) -> rspack_fs::Result<()> {
+    self.contents.extend_from_slice(buf);
+    self.committed = false;
+    Ok(())
+  }
+  async fn flush(&mut self) -> rspack_fs::Result<()> {
+    if !self.committed {
+      self.store.put(&self.key, self.contents.clone()).await?;
+      self.committed = true;
+    }
+    Ok(())
+  }
+  async fn close(&mut self) -> rspack_fs::Result<()> {
+    self.flush().await
+  }
+}
+
+#[async_trait::async_trait]
+impl FileSystem for BlobFileSystem {
+  async fn exists(&self, path: &Utf8Path) -> FSResult<bool> {
+    match self.metadata(path).await {
+      Ok(_) => Ok(true),
+      Err(e) => {
+        if e.is_not_found() {
+          Ok(false)
+        } else {
+          Err(e)
+        }
+      }
+    }
+  }
+
+  async fn remove_dir(&self, path: &Utf8Path) -> FSResult<()> {
+    let keys = self
+      .0
+      .list(&to_key(path))
+      .await
+      .map_err(|e| FSError::from_fs_error(path, FSOperation::Remove, e))?;
+    for key in keys {
+      self
+        .0
+        .remove(&key)
+        .await
+        .map_err(|e| FSError::from_fs_error(path, FSOperation::Remove, e))?;
+    }
+    Ok(())
+  }
+
+  async fn ensure_dir(&self, _path: &Utf8Path) -> FSResult<()> {
+    Ok(())
+  }
+
+  async fn write_file(&self, path: &Utf8Path) -> FSResult<Writer> {
+    Ok(Writer {
+      path: path.to_path_buf(),
+      stream: Box::new(BlobWriteStream {
+        key: to_key(path),
+        contents: vec![],
+        store: self.0.clone(),
+        committed: false,
+      }),
+    })
+  }
+
+  async fn read_file(&self, path: &Utf8Path) -> FSResult<Reader> {
+    let contents = self
+      .0
+      .get(&to_key(path))
+      .await
+      .map_err(|e| FSError::from_fs_error(path, FSOperation::Read, e))?
+      .ok_or_else(|| not_found(path, FSOperation::Read))?;
+    Ok(Reader {
+      path: path.to_path_buf(),
+      stream: Box::new(MemoryReadStream::new(contents)),
+    })
+  }
+
+  async fn read_dir(&self, path: &Utf8Path) -> FSResult<HashSet<String>> {
+    let prefix = to_key(path);
+    let keys = self
+      .0
+      .list(&prefix)
+      .await
+      .map_err(|e| FSError::from_fs_error(path, FSOperation::Read, e))?;
+    Ok(
+      keys
+        .iter()
+        .filter_map(|key| {
+          let rest = if prefix.is_empty() {
+            key.as_str()
+          } else {
+            key.strip_prefix(&prefix)?.strip_prefix('/')?
+          };
+          rest.split('/').next().map(|name| name.to_string())
+        })
+        .collect(),
+    )
+  }
+
+  async fn fingerprint(&self, path: &Utf8Path) -> FSResult<String> {
+    let meta = self
+      .0
+      .stat(&to_key(path))
+      .await
+      .map_err(|e| FSError::from_fs_error(path, FSOperation::Stat, e))?
+      .ok_or_else(|| not_found(path, FSOperation::Stat))?;
+    Ok(format!("{:x}-{}", meta.size, meta.hash))
+  }
+
+  async fn metadata(&self, path: &Utf8Path) -> FSResult<FileMetadata> {
+    let key = to_key(path);
+    let meta = self
+      .0
+      .stat(&key)
+      .await
+      .map_err(|e| FSError::from_fs_error(path, FSOperation::Stat, e))?;
+    if let Some(meta) = meta {
+      // blobs have no times, packs are validated by `fingerprint`
+      return Ok(FileMetadata {
+        is_file: true,
+        is_directory: false,
+        is_symlink: false,
+        atime_ms: 0,
+        mtime_ms: 0,
+        ctime_ms: 0,
+        size: meta.size,
+      });
+    }
+    let is_directory = !self
+      .0
+      .list(&key)
+      .await
+      .map_err(|e| FSError::from_fs_error(path, FSOperation::Stat, e))?
+      .is_empty();
+    if !is_directory {
+      return Err(not_found(path, FSOperation::Stat));
+    }
+    Ok(FileMetadata {
+      is_file: false,
+      is_directory: true,
+      is_symlink: false,
+      atime_ms: 0,
+      mtime_ms: 0,
+      ctime_ms: 0,
+      size: 0,
+    })
+  }
+
+  async fn remove_file(&self, path: &Utf8Path) -> FSResult<()> {
+    self
+      .0
+      .remove(&to_key(path))
+      .await
+      .map_err(|e| FSError::from_fs_error(path, FSOperation::Remove, e))
+  }
+
+  async fn move_file(&self, from: &Utf8Path, to: &Utf8Path) -> FSResult<()> {
+    self
+      .0
+      .rename(&to_key(from), &to_key(to))
+      .await
+      .map_err(|e| FSError::from_fs_error(from, FSOperation::Move, e))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+
+  use super::{BlobFileSystem, FSResult};
+  use crate::{FileSystem, LocalBlobStore};
+
+  fn get_path(p: &str) -> Utf8PathBuf {
+    Utf8PathBuf::from(p)
+  }
+
+  async fn test_blob_fs(fs: &BlobFileSystem) -> FSResult<()> {
+    fs.remove_dir(&get_path("/parent")).await?;
+    assert!(!fs.exists(&get_path("/parent")).await?);
+
+    let mut writer = fs.write_file(&get_path("/parent/from/file.txt")).await?;
+    writer.write_line("hello").await?;
+    writer.write(b" world").await?;
+    writer.flush().await?;
+    assert!(fs.exists(&get_path("/parent/from/file.txt")).await?);
+    assert!(fs.metadata(&get_path("/parent/from")).await?.is_directory);
+    assert!(
+      fs.metadata(&get_path("/parent/from/file.txt"))
+        .await?
+        .is_file
+    );
+    assert!(fs.read_dir(&get_path("/parent")).await?.contains("from"));
+
+    let mut reader = fs.read_file(&get_path("/parent/from/file.txt")).await?;
+    assert_eq!(reader.read_line().await?, "hello");
+    assert_eq!(reader.read(b" world".len()).await?, b" world");
+
+    let fingerprint = fs.fingerprint(&get_path("/parent/from/file.txt")).await?;
+    fs.move_file(
+      &get_path("/parent/from/file.txt"),
+      &get_path("/parent/to/file.txt"),
+    )
+    .await?;
+    assert_eq!(
+      fs.fingerprint(&get_path("/parent/to/file.txt")).await?,
+      fingerprint
+    );
+    assert!(!fs.exists(&get_path("/parent/from/file.txt")).await?);
+    assert!(!fs.exists(&get_path("/parent/from")).await?);
+    assert!(fs.exists(&get_path("/parent/to/file.txt")).await?);
+
+    let mut writer = fs.write_file(&get_path("/parent/to/file.txt")).await?;
+    writer.write_line("changed").await?;
+    writer.flush().await?;
+    assert_ne!(
+      fs.fingerprint(&get_path("/parent/to/file.txt")).await?,
+      fingerprint
+    );
+
+    fs.remove_file(&get_path("/parent/to/file.txt")).await?;
+    assert!(!fs.exists(&get_path("/parent/to/file.txt")).await?);
+
+    match fs.metadata(&get_path("/parent/not_exist.txt")).await {
+      Ok(_) => panic!("should error"),
+      Err(e) => assert!(e.is_not_found()),
+    };
+
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn should_blob_fs_work() {
+    let root = std::env::temp_dir()
+      .join("rspack_test/storage/blob_fs")
+      .assert_utf8();
+    let fs = BlobFileSystem(Arc::new(LocalBlobStore::new(root)));
+
+    let _ = test_blob_fs(&fs).await.map_err(|e| {
+      panic!("{}", e);
+    });
+  }
+}
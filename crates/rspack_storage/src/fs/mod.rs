@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
+mod blob;
 mod error;
+pub use blob::BlobFileSystem;
 pub use error::{BatchFSError, FSError, FSOperation};
 use rspack_fs::{FileMetadata, IntermediateFileSystem, ReadStream, WriteStream};
 use rspack_paths::{Utf8Path, Utf8PathBuf};
@@ -17,6 +19,14 @@ pub trait FileSystem: std::fmt::Debug + Sync + Send {
   async fn read_file(&self, path: &Utf8Path) -> FSResult<Reader>;
   async fn read_dir(&self, path: &Utf8Path) -> FSResult<HashSet<String>>;
   async fn metadata(&self, path: &Utf8Path) -> FSResult<FileMetadata>;
+  /// Get a string which is changed when the content of file is changed and
+  /// kept when the file is moved, it is used to validate packs
+  ///
+  /// Local file systems keep the mtime when moving files, so the size and mtime are used by default.
+  async fn fingerprint(&self, path: &Utf8Path) -> FSResult<String> {
+    let meta = self.metadata(path).await?;
+    Ok(format!("{:x}-{:x}", meta.size, meta.mtime_ms))
+  }
   async fn remove_file(&self, path: &Utf8Path) -> FSResult<()>;
   async fn move_file(&self, from: &Utf8Path, to: &Utf8Path) -> FSResult<()>;
 }
@@ -1,12 +1,19 @@
+mod blob;
 mod error;
 mod fs;
 mod pack;
 
 use std::sync::Arc;
 
+pub use blob::{BlobMeta, BlobStore, LocalBlobStore};
 pub use error::Result;
-pub use fs::{BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, Reader, Writer};
-pub use pack::{PackStorage, PackStorageOptions};
+pub use fs::{
+  BlobFileSystem, BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, Reader, Writer,
+};
+pub use pack::{
+  PackInfo, PackStorage, PackStorageInspector, PackStorageLimits, PackStorageOptions, ScopeInfo,
+  VersionInfo,
+};
 use tokio::sync::oneshot::Receiver;
 
 type ItemKey = Vec<u8>;
@@ -16,6 +23,16 @@ type ItemPairs = Vec<(Arc<ItemKey>, Arc<ItemValue>)>;
 #[async_trait::async_trait]
 pub trait Storage: std::fmt::Debug + Sync + Send {
   async fn load(&self, scope: &'static str) -> Result<Vec<(Arc<Vec<u8>>, Arc<Vec<u8>>)>>;
+  /// Get the value of `key` in scope, the default implementation loads the whole scope
+  async fn get(&self, scope: &'static str, key: &[u8]) -> Result<Option<Arc<Vec<u8>>>> {
+    Ok(
+      self
+        .load(scope)
+        .await?
+        .into_iter()
+        .find_map(|(k, v)| (k.as_slice() == key).then_some(v)),
+    )
+  }
   fn set(&self, scope: &'static str, key: Vec<u8>, value: Vec<u8>);
   fn remove(&self, scope: &'static str, key: &[u8]);
   fn trigger_save(&self) -> Result<Receiver<Result<()>>>;
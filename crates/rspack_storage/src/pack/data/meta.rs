@@ -25,6 +25,8 @@ pub enum RootMetaFrom {
 pub struct RootMeta {
   pub expire_time: u64,
   pub scopes: HashSet<String>,
+  /// The total size of packs in all scopes
+  pub size: usize,
   pub from: RootMetaFrom,
 }
 
@@ -33,6 +35,7 @@ impl RootMeta {
     Self {
       scopes,
       expire_time: current_time() + expire,
+      size: 0,
       from: RootMetaFrom::New,
     }
   }
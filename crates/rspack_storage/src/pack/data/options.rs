@@ -6,9 +6,36 @@ pub struct PackOptions {
   pub pack_size: usize,
 }
 
+/// The expected item count of each bucket when choosing bucket size adaptively
+const ADAPTIVE_BUCKET_ITEMS: usize = 500;
+
+impl PackOptions {
+  /// Return the options with the bucket size which fits the item count and is not
+  /// larger than `max_bucket_size`, None means the bucket size is not changed.
+  pub fn adapt(&self, item_count: usize, max_bucket_size: usize) -> Option<Self> {
+    let bucket_size = item_count
+      .div_ceil(ADAPTIVE_BUCKET_ITEMS)
+      .clamp(1, max_bucket_size.max(1));
+    self.with_bucket_size(bucket_size, max_bucket_size)
+  }
+
+  /// Return the options with an existing bucket size, None means the bucket size
+  /// is not allowed or not changed.
+  pub fn with_bucket_size(&self, bucket_size: usize, max_bucket_size: usize) -> Option<Self> {
+    if bucket_size == self.bucket_size || bucket_size == 0 || bucket_size > max_bucket_size {
+      return None;
+    }
+    Some(Self {
+      bucket_size,
+      pack_size: self.pack_size,
+    })
+  }
+}
+
 #[derive(Debug)]
 pub struct RootOptions {
   pub root: Utf8PathBuf,
+  pub temp_root: Utf8PathBuf,
   pub expire: u64,
   pub clean: bool,
 }
@@ -156,6 +156,33 @@ impl PackScope {
       .collect_vec()
   }
 
+  /// Replace the options of an empty scope, the buckets are recreated by the new bucket size
+  pub fn reset_options(&mut self, options: Arc<PackOptions>) {
+    self.options = options;
+    self.meta = ScopeMetaState::Value(ScopeMeta::new(&self.path, &self.options));
+    self.packs =
+      ScopePacksState::Value((0..self.options.bucket_size).map(|_| vec![]).collect_vec());
+  }
+
+  /// Remove the packs by `(bucket, index)`, the files of them will be removed when writing packs
+  pub fn evict_packs(&mut self, evicted: &HashSet<(usize, usize)>) {
+    let metas = &mut self.meta.expect_value_mut().packs;
+    let packs = self.packs.expect_value_mut();
+    for (bucket, (bucket_metas, bucket_packs)) in metas.iter_mut().zip(packs.iter_mut()).enumerate()
+    {
+      let keep = (0..bucket_packs.len())
+        .map(|index| !evicted.contains(&(bucket, index)))
+        .collect_vec();
+      for (pack, _) in bucket_packs.iter().zip(&keep).filter(|(_, keep)| !**keep) {
+        self.removed.insert(pack.path.clone());
+      }
+      let mut keep_meta = keep.iter();
+      bucket_metas.retain(|_| *keep_meta.next().expect("should have pack"));
+      let mut keep_pack = keep.iter();
+      bucket_packs.retain(|_| *keep_pack.next().expect("should have pack"));
+    }
+  }
+
   pub fn clear(&mut self) {
     self.meta = ScopeMetaState::Value(ScopeMeta::new(&self.path, &self.options));
     self.packs =
@@ -0,0 +1,308 @@
+use std::{path::PathBuf, sync::Arc};
+
+use rspack_paths::{AssertUtf8, Utf8PathBuf};
+
+use super::{
+  data::{current_time, PackOptions, PackScope, RootMeta, RootOptions, ScopeMeta},
+  manager::ScopeManager,
+  strategy::{RootStrategy, ScopeReadStrategy, SplitPackStrategy},
+};
+use crate::{
+  error::{Error, ErrorType, Result},
+  FileSystem,
+};
+
+/// The information of a version directory
+#[derive(Debug)]
+pub struct VersionInfo {
+  pub version: String,
+  /// None means the root meta is missing, the directory is not a valid version
+  pub expire_time: Option<u64>,
+  pub scopes: Vec<ScopeInfo>,
+}
+
+impl VersionInfo {
+  pub fn is_expired(&self) -> bool {
+    self
+      .expire_time
+      .is_none_or(|expire_time| current_time() > expire_time)
+  }
+
+  pub fn size(&self) -> usize {
+    self.scopes.iter().map(|scope| scope.size()).sum()
+  }
+}
+
+/// The information of a scope, read from the scope meta
+#[derive(Debug)]
+pub struct ScopeInfo {
+  pub name: String,
+  pub bucket_size: usize,
+  pub pack_size: usize,
+  pub generation: usize,
+  pub packs: Vec<PackInfo>,
+}
+
+impl ScopeInfo {
+  pub fn size(&self) -> usize {
+    self.packs.iter().map(|pack| pack.size).sum()
+  }
+
+  /// The packs which are smaller than half of the pack size
+  pub fn fragmented_packs(&self) -> usize {
+    self
+      .packs
+      .iter()
+      .filter(|pack| pack.size * 2 < self.pack_size)
+      .count()
+  }
+}
+
+#[derive(Debug)]
+pub struct PackInfo {
+  pub bucket: usize,
+  pub name: String,
+  pub hash: String,
+  pub size: usize,
+  pub generation: usize,
+}
+
+/// Inspect and maintain the directory of `PackStorage`
+///
+/// The directory contains a sub directory for each version, and each version
+/// contains the root meta and the directories of scopes.
+#[derive(Debug)]
+pub struct PackStorageInspector {
+  root: Utf8PathBuf,
+  temp_root: Utf8PathBuf,
+  fs: Arc<dyn FileSystem>,
+}
+
+/// The scope of storage is a static str, intern the scope names which are read
+/// from the root meta, so each name is only allocated once.
+fn intern_scope_name(name: &str) -> &'static str {
+  ustr::ustr(name).as_str()
+}
+
+impl PackStorageInspector {
+  pub fn new(root: PathBuf, temp_root: PathBuf, fs: Arc<dyn FileSystem>) -> Self {
+    Self {
+      root: root.assert_utf8(),
+      temp_root: temp_root.assert_utf8(),
+      fs,
+    }
+  }
+
+  fn create_strategy(
+    &self,
+    version: &str,
+    fresh_generation: Option<usize>,
+  ) -> Arc<SplitPackStrategy> {
+    Arc::new(SplitPackStrategy::new(
+      self.root.join(version),
+      self.temp_root.join(version),
+      self.fs.clone(),
+      fresh_generation,
+      None,
+    ))
+  }
+
+  async fn create_manager(
+    &self,
+    version: &str,
+    fresh_generation: Option<usize>,
+  ) -> Result<Option<(ScopeManager, RootMeta)>> {
+    let strategy = self.create_strategy(version, fresh_generation);
+    let Some(root_meta) = strategy.read_root_meta().await? else {
+      return Ok(None);
+    };
+    // all of scopes in a storage use the same pack size, and the bucket sizes
+    // may be different if they are chosen by the item count
+    let mut pack_options = PackOptions {
+      bucket_size: 0,
+      pack_size: 0,
+    };
+    for name in &root_meta.scopes {
+      if let Some(scope) = self.scope(version, name).await? {
+        pack_options.bucket_size = pack_options.bucket_size.max(scope.bucket_size);
+        pack_options.pack_size = scope.pack_size;
+      }
+    }
+    let strategy = (*strategy)
+      .clone()
+      .with_max_bucket_size(Some(pack_options.bucket_size));
+    let manager = ScopeManager::new(
+      Arc::new(RootOptions {
+        root: self.root.clone(),
+        temp_root: self.temp_root.clone(),
+        expire: root_meta.expire_time.saturating_sub(current_time()),
+        clean: false,
+      }),
+      Arc::new(pack_options),
+      Arc::new(strategy),
+    );
+    Ok(Some((manager, root_meta)))
+  }
+
+  /// List all of versions in the storage directory
+  pub async fn versions(&self) -> Result<Vec<VersionInfo>> {
+    if !self.fs.exists(&self.root).await? {
+      return Ok(vec![]);
+    }
+    let mut versions = vec![];
+    for version in self.fs.read_dir(&self.root).await? {
+      // skip hidden dirs and lock files
+      if version.starts_with(".") || version.contains(".lock") {
+        continue;
+      }
+      if !self
+        .fs
+        .metadata(&self.root.join(&version))
+        .await?
+        .is_directory
+      {
+        continue;
+      }
+      let strategy = self.create_strategy(&version, None);
+      let Some(root_meta) = strategy.read_root_meta().await? else {
+        versions.push(VersionInfo {
+          version,
+          expire_time: None,
+          scopes: vec![],
+        });
+        continue;
+      };
+      let mut scopes = vec![];
+      for name in &root_meta.scopes {
+        if let Some(scope) = self.scope(&version, name).await? {
+          scopes.push(scope);
+        }
+      }
+      scopes.sort_by(|a, b| a.name.cmp(&b.name));
+      versions.push(VersionInfo {
+        version,
+        expire_time: Some(root_meta.expire_time),
+        scopes,
+      });
+    }
+    versions.sort_by(|a, b| a.version.cmp(&b.version));
+    Ok(versions)
+  }
+
+  /// Read the information of scope, return None if the scope not exists
+  pub async fn scope(&self, version: &str, name: &str) -> Result<Option<ScopeInfo>> {
+    let strategy = self.create_strategy(version, None);
+    let path = strategy.get_path(name);
+    if !self.fs.exists(&ScopeMeta::get_path(&path)).await? {
+      return Ok(None);
+    }
+    let mut scope = PackScope::new(
+      intern_scope_name(name),
+      path,
+      Arc::new(PackOptions {
+        bucket_size: 0,
+        pack_size: 0,
+      }),
+    );
+    strategy.ensure_meta(&mut scope).await?;
+    let meta = scope.meta.expect_value();
+    Ok(Some(ScopeInfo {
+      name: name.to_string(),
+      bucket_size: meta.bucket_size,
+      pack_size: meta.pack_size,
+      generation: meta.generation,
+      packs: meta
+        .packs
+        .iter()
+        .enumerate()
+        .flat_map(|(bucket, pack_metas)| {
+          pack_metas.iter().map(move |pack_meta| PackInfo {
+            bucket,
+            name: pack_meta.name.clone(),
+            hash: pack_meta.hash.clone(),
+            size: pack_meta.size,
+            generation: pack_meta.generation,
+          })
+        })
+        .collect(),
+    }))
+  }
+
+  /// Validate all of scopes in the version
+  ///
+  /// Return the item count of valid scopes and the error of invalid scopes.
+  pub async fn validate(&self, version: &str) -> Result<Vec<(String, Result<usize>)>> {
+    let Some((manager, root_meta)) = self.create_manager(version, None).await? else {
+      return Ok(vec![]);
+    };
+    let mut names = root_meta.scopes.into_iter().collect::<Vec<_>>();
+    names.sort();
+    let mut res = vec![];
+    for name in names {
+      let validated = manager
+        .load(intern_scope_name(&name))
+        .await
+        .map(|items| items.len());
+      res.push((name, validated));
+    }
+    Ok(res)
+  }
+
+  /// Remove invalid and expired versions, then remove the oldest versions until
+  /// the total size is not larger than `max_size`.
+  ///
+  /// The `active` version is used by the current compiler, it is never removed.
+  /// Return the removed versions.
+  pub async fn prune(&self, active: Option<&str>, max_size: Option<usize>) -> Result<Vec<String>> {
+    let is_active = |version: &VersionInfo| active == Some(version.version.as_str());
+    let (expired, mut remain): (Vec<_>, Vec<_>) = self
+      .versions()
+      .await?
+      .into_iter()
+      .partition(|version| version.is_expired() && !is_active(version));
+    let mut removed = expired
+      .into_iter()
+      .map(|version| version.version)
+      .collect::<Vec<_>>();
+
+    if let Some(max_size) = max_size {
+      // the version with smaller expire time is saved earlier
+      remain.sort_by_key(|version| std::cmp::Reverse(version.expire_time));
+      let mut total_size = remain.iter().map(|version| version.size()).sum::<usize>();
+      remain.retain(|version| !is_active(version));
+      while total_size > max_size {
+        let Some(version) = remain.pop() else {
+          break;
+        };
+        total_size -= version.size();
+        removed.push(version.version);
+      }
+    }
+
+    for version in &removed {
+      self.fs.remove_dir(&self.root.join(version)).await?;
+      self.fs.remove_dir(&self.temp_root.join(version)).await?;
+    }
+    Ok(removed)
+  }
+
+  /// Merge the fragmented packs of all scopes in the version
+  ///
+  /// All of packs will be regenerated, so the packs are filled up to the pack size.
+  pub async fn compact(&self, version: &str) -> Result<()> {
+    // mark all of packs as fresh, so they will be optimized when saving
+    let Some((manager, root_meta)) = self.create_manager(version, Some(usize::MAX)).await? else {
+      return Ok(());
+    };
+    for name in &root_meta.scopes {
+      manager.load(intern_scope_name(name)).await?;
+    }
+    manager.save(Default::default())?.await.map_err(|e| {
+      Error::from_reason(
+        Some(ErrorType::Save),
+        None,
+        format!("the result of compacting `{version}` is lost: {e}"),
+      )
+    })?
+  }
+}
@@ -22,6 +22,10 @@ pub struct ScopeManager {
   pub root_options: Arc<RootOptions>,
   pub pack_options: Arc<PackOptions>,
   pub strategy: Arc<dyn ScopeStrategy>,
+  /// The max total size of packs in all versions, the least recently saved
+  /// versions will be removed when it is exceeded, and then the oldest packs
+  /// of the current version.
+  pub max_size: Option<usize>,
   pub scopes: Arc<Mutex<ScopeMap>>,
   pub root_meta: Arc<Mutex<RootMetaState>>,
   pub queue: TaskQueue,
@@ -37,12 +41,18 @@ impl ScopeManager {
       root_options,
       pack_options,
       strategy,
+      max_size: None,
       scopes: Default::default(),
       queue: TaskQueue::new(),
       root_meta: Default::default(),
     }
   }
 
+  pub fn with_max_size(mut self, max_size: Option<usize>) -> Self {
+    self.max_size = max_size;
+    self
+  }
+
   pub fn save(&self, updates: ScopeUpdates) -> Result<Receiver<Result<()>>> {
     let pack_options = self.pack_options.clone();
     let strategy = self.strategy.clone();
@@ -77,6 +87,7 @@ impl ScopeManager {
     let scopes = self.scopes.clone();
     let root_meta = self.root_meta.clone();
     let root_options = self.root_options.clone();
+    let max_size = self.max_size;
     let (tx, rx) = oneshot::channel();
     self.queue.add_task(Box::pin(async move {
       let mut scopes_lock = scopes.lock().await;
@@ -87,7 +98,14 @@ impl ScopeManager {
         .clone()
         .expect("should have root meta");
       let old_scopes = std::mem::take(&mut *scopes_lock);
-      let res = save_scopes(old_scopes, &root_meta, strategy.as_ref(), &root_options).await;
+      let res = save_scopes(
+        old_scopes,
+        &root_meta,
+        strategy.as_ref(),
+        &root_options,
+        max_size,
+      )
+      .await;
       let _ = match res {
         Ok(new_scopes) => {
           let _ = std::mem::replace(&mut *scopes_lock, new_scopes);
@@ -252,6 +270,7 @@ async fn save_scopes(
   root_meta: &RootMeta,
   strategy: &dyn ScopeStrategy,
   root_options: &RootOptions,
+  max_size: Option<usize>,
 ) -> Result<ScopeMap> {
   scopes.retain(|_, scope| scope.loaded());
 
@@ -266,6 +285,10 @@ async fn save_scopes(
   .into_iter()
   .collect::<Result<Vec<_>>>()?;
 
+  if let Some(max_size) = max_size {
+    evict_generations(&mut scopes, max_size);
+  }
+
   let changed = join_all(scopes.values_mut().map(|scope| async move {
     let mut res = WriteScopeResult::default();
     if scope.loaded() {
@@ -284,14 +307,88 @@ async fn save_scopes(
     acc
   });
 
+  let root_meta = RootMeta {
+    size: scopes
+      .values()
+      .map(|scope| {
+        scope
+          .meta
+          .expect_value()
+          .packs
+          .iter()
+          .flatten()
+          .map(|pack_meta| pack_meta.size)
+          .sum::<usize>()
+      })
+      .sum(),
+    ..root_meta.clone()
+  };
+  let root_meta = &root_meta;
   strategy.write_root_meta(root_meta).await?;
   strategy.merge_changed(changed).await?;
   strategy.after_all(&mut scopes).await?;
-  strategy.clean(root_meta, &scopes, root_options).await?;
+  strategy
+    .clean(root_meta, &scopes, root_options, max_size)
+    .await?;
 
   Ok(scopes.into_iter().collect())
 }
 
+/// Remove the oldest packs of the current version until the total size of packs
+/// is not larger than `max_size`, the packs written by this save are always kept
+fn evict_generations(scopes: &mut ScopeMap, max_size: usize) {
+  let mut total_size = scopes
+    .values()
+    .flat_map(|scope| scope.meta.expect_value().packs.iter().flatten())
+    .map(|pack_meta| pack_meta.size)
+    .sum::<usize>();
+  if total_size <= max_size {
+    return;
+  }
+
+  // the generations of scopes are counted separately, so packs are compared by
+  // how many saves ago they were written
+  let mut candidates = scopes
+    .iter()
+    .flat_map(|(name, scope)| {
+      let meta = scope.meta.expect_value();
+      meta
+        .packs
+        .iter()
+        .enumerate()
+        .flat_map(move |(bucket, pack_metas)| {
+          pack_metas
+            .iter()
+            .enumerate()
+            .filter(|(_, pack_meta)| pack_meta.generation < meta.generation)
+            .map(move |(index, pack_meta)| {
+              (
+                meta.generation - pack_meta.generation,
+                name.clone(),
+                (bucket, index),
+                pack_meta.size,
+              )
+            })
+        })
+    })
+    .collect::<Vec<_>>();
+  candidates.sort_by(|a, b| b.0.cmp(&a.0));
+
+  let mut evicted = HashMap::<String, HashSet<(usize, usize)>>::default();
+  for (_, name, position, size) in candidates {
+    if total_size <= max_size {
+      break;
+    }
+    total_size -= size;
+    evicted.entry(name).or_default().insert(position);
+  }
+  for (name, positions) in evicted {
+    if let Some(scope) = scopes.get_mut(&name) {
+      scope.evict_packs(&positions);
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use std::sync::Arc;
@@ -340,6 +437,7 @@ mod tests {
     let root_options = Arc::new(RootOptions {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
+      temp_root: temp.parent().expect("should get parent").to_path_buf(),
       clean: true,
     });
     let pack_options = Arc::new(PackOptions {
@@ -396,6 +494,7 @@ mod tests {
     let root_options = Arc::new(RootOptions {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
+      temp_root: temp.parent().expect("should get parent").to_path_buf(),
       clean: true,
     });
     let pack_options = Arc::new(PackOptions {
@@ -480,6 +579,7 @@ mod tests {
     let root_options = Arc::new(RootOptions {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
+      temp_root: temp.parent().expect("should get parent").to_path_buf(),
       clean: true,
     });
     let pack_options = Arc::new(PackOptions {
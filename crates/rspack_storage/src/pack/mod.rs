@@ -1,4 +1,5 @@
 mod data;
+mod inspect;
 mod manager;
 mod strategy;
 
@@ -8,6 +9,7 @@ use std::{
 };
 
 use data::{PackOptions, RootOptions};
+pub use inspect::{PackInfo, PackStorageInspector, ScopeInfo, VersionInfo};
 use manager::ScopeManager;
 use rspack_paths::AssertUtf8;
 use rustc_hash::FxHashMap as HashMap;
@@ -36,12 +38,28 @@ pub struct PackStorageOptions {
   pub release_generation: Option<usize>,
 }
 
+/// The optional limits of `PackStorage`, all of them are disabled by default
+#[derive(Debug, Default, Clone)]
+pub struct PackStorageLimits {
+  /// Choose the bucket size of each scope by its item count, `bucket_size` is the max bucket size
+  pub adaptive_bucket: bool,
+  /// The max total size of packs in all versions, the least recently saved
+  /// versions will be removed when it is exceeded, and then the oldest packs
+  /// of the current version.
+  pub max_size: Option<usize>,
+}
+
 impl PackStorage {
   pub fn new(options: PackStorageOptions) -> Self {
+    Self::with_limits(options, PackStorageLimits::default())
+  }
+
+  pub fn with_limits(options: PackStorageOptions, limits: PackStorageLimits) -> Self {
     Self {
       manager: ScopeManager::new(
         Arc::new(RootOptions {
           root: options.root.clone().assert_utf8(),
+          temp_root: options.temp_root.clone().assert_utf8(),
           expire: options.expire,
           clean: options.clean,
         }),
@@ -49,14 +67,18 @@ impl PackStorage {
           bucket_size: options.bucket_size,
           pack_size: options.pack_size,
         }),
-        Arc::new(SplitPackStrategy::new(
-          options.root.join(&options.version).assert_utf8(),
-          options.temp_root.join(&options.version).assert_utf8(),
-          options.fs,
-          options.fresh_generation,
-          options.release_generation,
-        )),
-      ),
+        Arc::new(
+          SplitPackStrategy::new(
+            options.root.join(&options.version).assert_utf8(),
+            options.temp_root.join(&options.version).assert_utf8(),
+            options.fs,
+            options.fresh_generation,
+            options.release_generation,
+          )
+          .with_max_bucket_size(limits.adaptive_bucket.then_some(options.bucket_size)),
+        ),
+      )
+      .with_max_size(limits.max_size),
       updates: Default::default(),
     }
   }
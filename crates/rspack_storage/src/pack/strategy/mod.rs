@@ -43,6 +43,7 @@ pub trait RootStrategy {
     root_meta: &RootMeta,
     scopes: &HashMap<String, PackScope>,
     root_options: &RootOptions,
+    max_size: Option<usize>,
   ) -> Result<()>;
 }
 
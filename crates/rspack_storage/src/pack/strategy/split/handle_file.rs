@@ -341,9 +341,22 @@ pub async fn remove_unused_scopes(
   .map(|_| ())
 }
 
+/// Remove the directory of version and its temporary directory, the lock files
+/// of version are in the version directory and removed together
+async fn remove_version(
+  dir: &Utf8Path,
+  temp_dir: &Utf8Path,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<()> {
+  fs.remove_dir(dir).await?;
+  fs.remove_dir(temp_dir).await?;
+  Ok(())
+}
+
 async fn try_remove_version(
   version: &str,
   dir: &Utf8Path,
+  temp_dir: &Utf8Path,
   fs: Arc<dyn FileSystem>,
 ) -> BatchHandleFileResult<()> {
   // do not remove hidden dirs and lock files
@@ -359,7 +372,7 @@ async fn try_remove_version(
   // remove unknown directories
   let meta = RootMeta::get_path(dir);
   if !fs.exists(&meta).await? {
-    fs.remove_dir(dir).await?;
+    remove_version(dir, temp_dir, fs).await?;
     return Ok(());
   }
 
@@ -375,7 +388,7 @@ async fn try_remove_version(
   let current = current_time();
 
   if current > expire_time {
-    fs.remove_dir(dir).await?;
+    remove_version(dir, temp_dir, fs).await?;
     Ok(())
   } else {
     Ok(())
@@ -394,8 +407,9 @@ pub async fn remove_expired_versions(
       None
     } else {
       let fs = fs.clone();
+      let temp_dir = root_options.temp_root.join(&version);
       Some(tokio::spawn(async move {
-        try_remove_version(&version, &version_dir, fs).await
+        try_remove_version(&version, &version_dir, &temp_dir, fs).await
       }))
     }
   });
@@ -409,3 +423,101 @@ pub async fn remove_expired_versions(
   )
   .map(|_| ())
 }
+
+async fn read_version_meta(
+  version: &str,
+  dir: &Utf8Path,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<Option<(u64, usize)>> {
+  // skip hidden dirs and lock files
+  if version.starts_with(".") || version.contains(".lock") {
+    return Ok(None);
+  }
+  if !(fs.metadata(dir).await?.is_directory) {
+    return Ok(None);
+  }
+  let meta = RootMeta::get_path(dir);
+  if !fs.exists(&meta).await? {
+    return Ok(None);
+  }
+  let mut reader = fs.read_file(&meta).await?;
+  let expire_time = reader.read_line().await?.parse::<u64>().map_err(|e| {
+    FSError::from_message(
+      &meta,
+      FSOperation::Read,
+      format!("parse option meta failed: {}", e),
+    )
+  })?;
+  // skip scopes line
+  reader.read_line().await?;
+  let size = reader
+    .read_line()
+    .await
+    .ok()
+    .and_then(|line| line.parse::<usize>().ok())
+    .unwrap_or_default();
+  Ok(Some((expire_time, size)))
+}
+
+pub async fn remove_oversized_versions(
+  root: &Utf8Path,
+  root_meta: &RootMeta,
+  root_options: &RootOptions,
+  max_size: Option<usize>,
+  fs: Arc<dyn FileSystem>,
+) -> BatchHandleFileResult<()> {
+  let Some(max_size) = max_size else {
+    return Ok(());
+  };
+  let dirs = fs.read_dir(&root_options.root).await?;
+  let tasks = dirs.into_iter().filter_map(|version| {
+    let version_dir = root_options.root.join(&version);
+    if version_dir == root {
+      None
+    } else {
+      let fs = fs.clone();
+      let temp_dir = root_options.temp_root.join(&version);
+      Some(tokio::spawn(async move {
+        read_version_meta(&version, &version_dir, fs)
+          .await
+          .map(|meta| meta.map(|(expire_time, size)| (version_dir, temp_dir, expire_time, size)))
+      }))
+    }
+  });
+  let mut versions = BatchFSError::try_from_joined_result(
+    "read versions failed",
+    join_all(tasks)
+      .await
+      .into_iter()
+      .collect::<Result<Vec<_>, JoinError>>(),
+  )?
+  .into_iter()
+  .flatten()
+  .collect::<Vec<_>>();
+
+  // the current version is always kept, and the version saved recently has a larger expire time
+  versions.sort_by(|a, b| b.2.cmp(&a.2));
+  let mut total_size = root_meta.size;
+  let tasks = versions
+    .into_iter()
+    .filter_map(|(version_dir, temp_dir, _, size)| {
+      total_size += size;
+      if total_size > max_size {
+        let fs = fs.clone();
+        Some(tokio::spawn(async move {
+          remove_version(&version_dir, &temp_dir, fs).await
+        }))
+      } else {
+        None
+      }
+    });
+
+  BatchFSError::try_from_joined_result(
+    "remove oversized versions failed",
+    join_all(tasks)
+      .await
+      .into_iter()
+      .collect::<Result<Vec<_>, JoinError>>(),
+  )
+  .map(|_| ())
+}
@@ -9,8 +9,8 @@ mod write_scope;
 use std::{hash::Hasher, sync::Arc};
 
 use handle_file::{
-  recovery_move_lock, recovery_remove_lock, remove_expired_versions, remove_unused_scope_files,
-  remove_unused_scopes,
+  recovery_move_lock, recovery_remove_lock, remove_expired_versions, remove_oversized_versions,
+  remove_unused_scope_files, remove_unused_scopes,
 };
 use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
@@ -34,6 +34,9 @@ pub struct SplitPackStrategy {
   pub temp_root: Arc<Utf8PathBuf>,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
+  /// Choose the bucket size of scopes by the item count, and the bucket size
+  /// of existing scopes is kept if it is not larger than this value.
+  pub max_bucket_size: Option<usize>,
 }
 
 impl SplitPackStrategy {
@@ -50,9 +53,15 @@ impl SplitPackStrategy {
       temp_root: Arc::new(temp_root),
       fresh_generation,
       release_generation,
+      max_bucket_size: None,
     }
   }
 
+  pub fn with_max_bucket_size(mut self, max_bucket_size: Option<usize>) -> Self {
+    self.max_bucket_size = max_bucket_size;
+    self
+  }
+
   pub async fn get_pack_hash(
     &self,
     path: &Utf8Path,
@@ -63,9 +72,8 @@ impl SplitPackStrategy {
     let file_name = get_name(keys, contents);
     hasher.write(file_name.as_bytes());
 
-    let meta = self.fs.metadata(path).await?;
-    hasher.write_u64(meta.size);
-    hasher.write_u64(meta.mtime_ms);
+    let fingerprint = self.fs.fingerprint(path).await?;
+    hasher.write(fingerprint.as_bytes());
 
     Ok(format!("{:016x}", hasher.finish()))
   }
@@ -98,10 +106,18 @@ impl RootStrategy for SplitPackStrategy {
       .split(',')
       .map(|s| s.to_string())
       .collect::<HashSet<_>>();
+    // the size line is missing in the root meta of old versions
+    let size = reader
+      .read_line()
+      .await
+      .ok()
+      .and_then(|line| line.parse::<usize>().ok())
+      .unwrap_or_default();
 
     Ok(Some(RootMeta {
       expire_time,
       scopes,
+      size,
       from: RootMetaFrom::File,
     }))
   }
@@ -117,6 +133,10 @@ impl RootStrategy for SplitPackStrategy {
       .write_line(root_meta.scopes.iter().join(",").as_str())
       .await?;
 
+    writer
+      .write_line(root_meta.size.to_string().as_str())
+      .await?;
+
     writer.flush().await?;
 
     Ok(())
@@ -139,6 +159,7 @@ impl RootStrategy for SplitPackStrategy {
     root_meta: &RootMeta,
     scopes: &HashMap<String, PackScope>,
     root_options: &RootOptions,
+    max_size: Option<usize>,
   ) -> Result<()> {
     if !root_options.clean {
       return Ok(());
@@ -147,7 +168,14 @@ impl RootStrategy for SplitPackStrategy {
     let _ = tokio::try_join!(
       remove_unused_scope_files(scopes, self.fs.clone()),
       remove_unused_scopes(&self.root, root_meta, self.fs.clone()),
-      remove_expired_versions(&self.root, root_options, self.fs.clone())
+      remove_expired_versions(&self.root, root_options, self.fs.clone()),
+      remove_oversized_versions(
+        &self.root,
+        root_meta,
+        root_options,
+        max_size,
+        self.fs.clone()
+      )
     );
 
     Ok(())
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use futures::{future::join_all, TryFutureExt};
 use itertools::Itertools;
@@ -14,7 +16,15 @@ impl ScopeValidateStrategy for SplitPackStrategy {
     let meta = scope.meta.expect_value();
 
     if meta.bucket_size != scope.options.bucket_size {
-      return Ok(ValidateResult::invalid("`options.bucketSize` changed"));
+      // the bucket size of scope is chosen by item count when it is adaptive
+      let Some(options) = self.max_bucket_size.and_then(|max_bucket_size| {
+        scope
+          .options
+          .with_bucket_size(meta.bucket_size, max_bucket_size)
+      }) else {
+        return Ok(ValidateResult::invalid("`options.bucketSize` changed"));
+      };
+      scope.options = Arc::new(options);
     }
 
     if meta.pack_size != scope.options.pack_size {
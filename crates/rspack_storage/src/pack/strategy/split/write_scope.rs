@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use futures::future::join_all;
 use itertools::Itertools;
@@ -16,11 +18,62 @@ use crate::{
   error::Result,
   fs::BatchFSError,
   pack::{
-    data::{Pack, PackScope},
-    strategy::{PackWriteStrategy, ScopeUpdate, ScopeWriteStrategy, WriteScopeResult},
+    data::{Pack, PackOptions, PackScope},
+    strategy::{
+      PackWriteStrategy, ScopeReadStrategy, ScopeUpdate, ScopeWriteStrategy, WriteScopeResult,
+    },
   },
 };
 
+/// The item count of scope after applying the updates, the updated items are
+/// counted as new items, so it may be larger than the actual count
+fn get_item_count(scope: &PackScope, updates: &ScopeUpdate) -> usize {
+  let count = scope
+    .packs
+    .expect_value()
+    .iter()
+    .flatten()
+    .map(|pack| pack.keys.expect_value().len())
+    .sum::<usize>();
+  let removed = updates.values().filter(|value| value.is_none()).count();
+  (count + updates.len() - removed).saturating_sub(removed)
+}
+
+impl SplitPackStrategy {
+  /// Move all of items in scope to the buckets of `options`
+  ///
+  /// Return the updates which contain all of items, the packs of old buckets
+  /// will be removed when writing packs.
+  async fn rebucket(
+    &self,
+    scope: &mut PackScope,
+    options: PackOptions,
+    updates: ScopeUpdate,
+  ) -> Result<ScopeUpdate> {
+    self.ensure_contents(scope).await?;
+    let mut items = scope
+      .get_contents()
+      .into_iter()
+      .map(|(key, value)| (Arc::unwrap_or_clone(key), Some(Arc::unwrap_or_clone(value))))
+      .collect::<ScopeUpdate>();
+    items.extend(updates);
+    items.retain(|_, value| value.is_some());
+
+    let old_files = scope
+      .packs
+      .expect_value()
+      .iter()
+      .flatten()
+      .map(|pack| pack.path.clone())
+      .collect_vec();
+    let generation = scope.meta.expect_value().generation;
+    scope.reset_options(Arc::new(options));
+    scope.meta.expect_value_mut().generation = generation;
+    scope.removed.extend(old_files);
+    Ok(items)
+  }
+}
+
 #[async_trait]
 impl ScopeWriteStrategy for SplitPackStrategy {
   async fn before_all(&self, scopes: &mut HashMap<String, PackScope>) -> Result<()> {
@@ -71,10 +124,25 @@ impl ScopeWriteStrategy for SplitPackStrategy {
     Ok(())
   }
 
-  async fn update_scope(&self, scope: &mut PackScope, updates: ScopeUpdate) -> Result<()> {
+  async fn update_scope(&self, scope: &mut PackScope, mut updates: ScopeUpdate) -> Result<()> {
     if !scope.loaded() {
       panic!("scope not loaded, run `load` first");
     }
+    // choose the bucket size by the item count, the items of a saved scope are
+    // redistributed only when the bucket size should be doubled or halved
+    if let Some(options) = self.max_bucket_size.and_then(|max_bucket_size| {
+      scope
+        .options
+        .adapt(get_item_count(scope, &updates), max_bucket_size)
+    }) {
+      if scope.meta.expect_value().generation == 0 {
+        scope.reset_options(Arc::new(options));
+      } else if options.bucket_size >= scope.options.bucket_size * 2
+        || options.bucket_size * 2 <= scope.options.bucket_size
+      {
+        updates = self.rebucket(scope, options, updates).await?;
+      }
+    }
     let mut scope_meta = scope.meta.take_value().expect("should have scope meta");
     let mut scope_packs = scope.packs.take_value().expect("should have scope packs");
     scope_meta.generation += 1;
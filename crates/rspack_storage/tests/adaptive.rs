@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod test_storage_adaptive {
+  use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+  };
+
+  use rspack_fs::MemoryFileSystem;
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageInspector, PackStorageLimits,
+    PackStorageOptions, Result, Storage,
+  };
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_adaptive/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_pack_options(
+    root: &Path,
+    temp_root: &Path,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorageOptions {
+    PackStorageOptions {
+      version: "xxx".to_string(),
+      root: root.to_path_buf(),
+      temp_root: temp_root.to_path_buf(),
+      fs,
+      bucket_size: 20,
+      pack_size: 2000,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    }
+  }
+
+  fn adaptive() -> PackStorageLimits {
+    PackStorageLimits {
+      adaptive_bucket: true,
+      ..Default::default()
+    }
+  }
+
+  const SCOPES: [(&str, usize); 2] = [("large", 2000), ("small", 100)];
+
+  async fn test_build(options: PackStorageOptions) -> Result<()> {
+    let storage = PackStorage::with_limits(options, adaptive());
+    for (scope, count) in SCOPES {
+      assert!(storage.load(scope).await?.is_empty());
+      for i in 0..count {
+        storage.set(
+          scope,
+          format!("key_{:0>5}", i).as_bytes().to_vec(),
+          format!("val_{:0>5}", i).as_bytes().to_vec(),
+        );
+      }
+    }
+    storage.trigger_save()?.await.expect("should save")?;
+    Ok(())
+  }
+
+  async fn test_recovery(options: PackStorageOptions) -> Result<()> {
+    let storage = PackStorage::with_limits(options, adaptive());
+    for (scope, count) in SCOPES {
+      assert_eq!(storage.load(scope).await?.len(), count);
+      // update items after recovery, the bucket size of scope is kept
+      storage.set(
+        scope,
+        "key_new".as_bytes().to_vec(),
+        "val_new".as_bytes().to_vec(),
+      );
+    }
+    storage.trigger_save()?.await.expect("should save")?;
+    Ok(())
+  }
+
+  async fn test_grow(options: PackStorageOptions) -> Result<()> {
+    let storage = PackStorage::with_limits(options, adaptive());
+    assert_eq!(storage.load("large").await?.len(), 2001);
+    assert_eq!(storage.load("small").await?.len(), 101);
+    // the small scope grows a lot, its items are moved to more buckets
+    for i in 0..2000 {
+      storage.set(
+        "small",
+        format!("grow_{:0>5}", i).as_bytes().to_vec(),
+        format!("val_{:0>5}", i).as_bytes().to_vec(),
+      );
+    }
+    storage.trigger_save()?.await.expect("should save")?;
+    Ok(())
+  }
+
+  async fn test_adaptive(root: &Path, temp_root: &Path, fs: Arc<dyn FileSystem>) -> Result<()> {
+    test_build(create_pack_options(root, temp_root, fs.clone())).await?;
+
+    let inspector =
+      PackStorageInspector::new(root.to_path_buf(), temp_root.to_path_buf(), fs.clone());
+    let get_bucket_size = |scope: &'static str| {
+      let inspector = &inspector;
+      async move {
+        inspector
+          .scope("xxx", scope)
+          .await
+          .map(|info| info.expect("should have scope").bucket_size)
+      }
+    };
+    assert_eq!(get_bucket_size("large").await?, 4);
+    assert_eq!(get_bucket_size("small").await?, 1);
+
+    test_recovery(create_pack_options(root, temp_root, fs.clone())).await?;
+    let validated = inspector.validate("xxx").await?;
+    assert_eq!(validated.len(), 2);
+    assert_eq!(*validated[0].1.as_ref().expect("should be valid"), 2001);
+    assert_eq!(*validated[1].1.as_ref().expect("should be valid"), 101);
+    assert_eq!(get_bucket_size("large").await?, 4);
+    assert_eq!(get_bucket_size("small").await?, 1);
+
+    test_grow(create_pack_options(root, temp_root, fs.clone())).await?;
+    let validated = inspector.validate("xxx").await?;
+    assert_eq!(*validated[0].1.as_ref().expect("should be valid"), 2001);
+    assert_eq!(*validated[1].1.as_ref().expect("should be valid"), 2101);
+    assert_eq!(get_bucket_size("large").await?, 4);
+    assert_eq!(get_bucket_size("small").await?, 5);
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_adaptive_bucket() {
+    let (root, temp_root) = get_memory_path("test_adaptive_bucket");
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+
+    let _ = test_adaptive(&root, &temp_root, fs)
+      .await
+      .map_err(|e| panic!("{}", e));
+  }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod test_storage_blob {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_paths::AssertUtf8;
+  use rspack_storage::{
+    BlobFileSystem, BlobStore, FileSystem, LocalBlobStore, PackStorage, PackStorageOptions, Result,
+    Storage,
+  };
+
+  fn create_pack_options(version: &str, fs: Arc<dyn FileSystem>) -> PackStorageOptions {
+    let root = PathBuf::from("/");
+    PackStorageOptions {
+      version: version.to_string(),
+      temp_root: root.join(".temp"),
+      root,
+      fs,
+      bucket_size: 10,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    }
+  }
+
+  async fn test_initial_build(
+    store: Arc<dyn BlobStore>,
+    options: PackStorageOptions,
+  ) -> Result<()> {
+    let storage = PackStorage::new(options);
+    let data = storage.load("test_scope").await?;
+    assert!(data.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{:0>3}", i).as_bytes().to_vec(),
+        format!("val_{:0>3}", i).as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    assert!(store
+      .stat("xxx/test_scope/scope_meta")
+      .await
+      .expect("should stat")
+      .is_some());
+    Ok(())
+  }
+
+  async fn test_recovery(options: PackStorageOptions) -> Result<()> {
+    let storage = PackStorage::new(options);
+    let data = storage.load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_blob() {
+    let root = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_blob")
+      .assert_utf8();
+    let _ = std::fs::remove_dir_all(&root);
+    let store: Arc<dyn BlobStore> = Arc::new(LocalBlobStore::new(root.clone()));
+    let version = "xxx";
+
+    let _ = test_initial_build(
+      store.clone(),
+      create_pack_options(version, Arc::new(BlobFileSystem(store.clone()))),
+    )
+    .await
+    .map_err(|e| panic!("{}", e));
+
+    // the blob store can be moved to another directory
+    let moved_root = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_blob_moved")
+      .assert_utf8();
+    let _ = std::fs::remove_dir_all(&moved_root);
+    std::fs::rename(&root, &moved_root).expect("should move blob store");
+    let store: Arc<dyn BlobStore> = Arc::new(LocalBlobStore::new(moved_root));
+
+    let _ = test_recovery(create_pack_options(
+      version,
+      Arc::new(BlobFileSystem(store)),
+    ))
+    .await
+    .map_err(|e| panic!("{}", e));
+  }
+}
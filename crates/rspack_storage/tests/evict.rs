@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod test_storage_evict {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageInspector, PackStorageLimits,
+    PackStorageOptions, Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_evict")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_evict/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_storage(
+    version: &str,
+    max_size: Option<usize>,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorage {
+    PackStorage::with_limits(
+      PackStorageOptions {
+        version: version.to_string(),
+        root: root.into(),
+        temp_root: temp_root.into(),
+        fs,
+        bucket_size: 2,
+        pack_size: 200,
+        expire: 7 * 24 * 60 * 60 * 1000,
+        clean: true,
+        fresh_generation: Some(1),
+        release_generation: Some(2),
+      },
+      PackStorageLimits {
+        max_size,
+        ..Default::default()
+      },
+    )
+  }
+
+  async fn save_items(storage: &PackStorage, range: std::ops::Range<usize>) -> Result<()> {
+    for i in range {
+      storage.set(
+        "test_scope",
+        format!("key_{:0>3}", i).as_bytes().to_vec(),
+        format!("val_{:0>3}", i).as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")
+  }
+
+  async fn test_build(
+    version: &str,
+    max_size: Option<usize>,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let storage = create_storage(version, max_size, root, temp_root, fs.clone());
+    let data = storage.load("test_scope").await?;
+    assert!(data.is_empty());
+    save_items(&storage, 0..100).await?;
+    assert!(
+      fs.exists(&root.join(version).join("test_scope/scope_meta"))
+        .await?
+    );
+    // make sure the versions are saved in different time
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    Ok(())
+  }
+
+  async fn test_evict(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    test_build("v1", None, root, temp_root, fs.clone()).await?;
+    test_build("v2", None, root, temp_root, fs.clone()).await?;
+
+    let inspector = PackStorageInspector::new(
+      root.clone().into_std_path_buf(),
+      temp_root.clone().into_std_path_buf(),
+      fs.clone(),
+    );
+    let version_size = inspector.versions().await?[0].size();
+    assert!(version_size > 0);
+
+    // keep the current version and the most recently saved version
+    test_build(
+      "v3",
+      Some(version_size * 2 + version_size / 2),
+      root,
+      temp_root,
+      fs.clone(),
+    )
+    .await?;
+    assert!(!(fs.exists(&root.join("v1")).await?));
+    assert!(!(fs.exists(&temp_root.join("v1")).await?));
+    assert!(fs.exists(&root.join("v2/test_scope/scope_meta")).await?);
+    assert!(fs.exists(&root.join("v3/test_scope/scope_meta")).await?);
+
+    // the current version is kept even if it is larger than max size
+    test_build("v4", Some(1), root, temp_root, fs.clone()).await?;
+    assert!(!(fs.exists(&root.join("v2")).await?));
+    assert!(!(fs.exists(&root.join("v3")).await?));
+    assert!(fs.exists(&root.join("v4/test_scope/scope_meta")).await?);
+    Ok(())
+  }
+
+  async fn test_evict_generations(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    test_build("v1", None, root, temp_root, fs.clone()).await?;
+    let inspector = PackStorageInspector::new(
+      root.clone().into_std_path_buf(),
+      temp_root.clone().into_std_path_buf(),
+      fs.clone(),
+    );
+    let version_size = inspector.versions().await?[0].size();
+
+    // the packs of the first save are evicted to keep the version under max size
+    let max_size = version_size + version_size / 2;
+    let storage = create_storage("v1", Some(max_size), root, temp_root, fs.clone());
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    save_items(&storage, 100..200).await?;
+    assert!(inspector.versions().await?[0].size() <= max_size);
+
+    let storage = create_storage("v1", Some(max_size), root, temp_root, fs.clone());
+    let data = storage.load("test_scope").await?;
+    assert!(data.len() < 200);
+    for i in 100..200 {
+      let key = format!("key_{:0>3}", i).as_bytes().to_vec();
+      assert!(data.iter().any(|(k, _)| **k == key));
+    }
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_version_evict() {
+    let cases = [
+      (
+        get_native_path("test_evict_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_evict_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      let _ = test_evict(&root, &temp_root, fs.clone())
+        .await
+        .map_err(|e| panic!("{}", e));
+    }
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_generation_evict() {
+    let (root, temp_root) = get_memory_path("test_evict_generations");
+    let root = root.assert_utf8();
+    let temp_root = temp_root.assert_utf8();
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+
+    let _ = test_evict_generations(&root, &temp_root, fs)
+      .await
+      .map_err(|e| panic!("{}", e));
+  }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod test_storage_inspect {
+  use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+  };
+
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::Utf8PathBuf;
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageInspector, PackStorageOptions, Result,
+    Storage,
+  };
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_inspect/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_pack_options(
+    version: &str,
+    root: &Path,
+    temp_root: &Path,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorageOptions {
+    PackStorageOptions {
+      version: version.to_string(),
+      root: root.to_path_buf(),
+      temp_root: temp_root.to_path_buf(),
+      fs,
+      bucket_size: 10,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: false,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    }
+  }
+
+  async fn build(options: PackStorageOptions, rounds: usize) -> Result<()> {
+    let storage = PackStorage::new(options);
+    storage.load("test_scope").await?;
+    for round in 0..rounds {
+      // update a few items in each round to make fragmented packs
+      for i in (round * 10)..(round * 10 + 10) {
+        storage.set(
+          "test_scope",
+          format!("key_{:0>3}", i).as_bytes().to_vec(),
+          format!("val_{:0>3}", i).as_bytes().to_vec(),
+        );
+      }
+      storage.trigger_save()?.await.expect("should save")?;
+    }
+    Ok(())
+  }
+
+  async fn test_inspect(root: &Path, temp_root: &Path, fs: Arc<dyn FileSystem>) -> Result<()> {
+    build(create_pack_options("v1", root, temp_root, fs.clone()), 10).await?;
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    build(create_pack_options("v2", root, temp_root, fs.clone()), 1).await?;
+
+    let inspector =
+      PackStorageInspector::new(root.to_path_buf(), temp_root.to_path_buf(), fs.clone());
+
+    let versions = inspector.versions().await?;
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0].version, "v1");
+    assert!(!versions[0].is_expired());
+    assert_eq!(versions[0].scopes.len(), 1);
+    assert_eq!(versions[0].scopes[0].name, "test_scope");
+    assert!(!versions[0].scopes[0].packs.is_empty());
+    assert!(versions[0].size() > versions[1].size());
+
+    let validated = inspector.validate("v1").await?;
+    assert_eq!(validated.len(), 1);
+    assert_eq!(*validated[0].1.as_ref().expect("should be valid"), 100);
+
+    let before = inspector
+      .scope("v1", "test_scope")
+      .await?
+      .expect("should have scope");
+    inspector.compact("v1").await?;
+    let after = inspector
+      .scope("v1", "test_scope")
+      .await?
+      .expect("should have scope");
+    assert!(after.packs.len() <= before.packs.len());
+    assert!(after.fragmented_packs() <= before.fragmented_packs());
+    let validated = inspector.validate("v1").await?;
+    assert_eq!(*validated[0].1.as_ref().expect("should be valid"), 100);
+
+    // v1 is saved earlier and will be removed first
+    let v2_size = versions[1].size();
+    let removed = inspector.prune(None, Some(v2_size)).await?;
+    assert_eq!(removed, vec!["v1".to_string()]);
+    assert!(
+      !fs
+        .exists(&Utf8PathBuf::from_path_buf(root.join("v1")).expect("should be utf8"))
+        .await?
+    );
+    let versions = inspector.versions().await?;
+    assert_eq!(versions.len(), 1);
+    assert_eq!(versions[0].version, "v2");
+
+    // the active version is kept even if the size is over the limit
+    let removed = inspector.prune(Some("v2"), Some(0)).await?;
+    assert!(removed.is_empty());
+    assert_eq!(inspector.versions().await?.len(), 1);
+
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_inspect_storage() {
+    let (root, temp_root) = get_memory_path("test_inspect_storage");
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+
+    let _ = test_inspect(&root, &temp_root, fs)
+      .await
+      .map_err(|e| panic!("{}", e));
+  }
+}
@@ -266,11 +266,11 @@ class Compiler {
 	}
 
 	get recordsInputPath() {
-		return unsupported("Compiler.recordsInputPath");
+		return this.options.recordsInputPath;
 	}
 
 	get recordsOutputPath() {
-		return unsupported("Compiler.recordsOutputPath");
+		return this.options.recordsOutputPath;
 	}
 
 	get managedPaths() {
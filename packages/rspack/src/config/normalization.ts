@@ -69,6 +69,7 @@ import type {
 	Plugins,
 	Profile,
 	PublicPath,
+	RecordsPath,
 	Resolve,
 	RspackFutureOptions,
 	RspackOptions,
@@ -332,14 +333,22 @@ export const getNormalizedRspackOptions = (
 						unmanagedPaths: nestedArray(snapshot.unmanagedPaths, p => [...p]),
 						managedPaths: optionalNestedArray(snapshot.managedPaths, p => [
 							...p
-						]) || [/\/node_modules\//]
+						]) || [/\/node_modules\//],
+						fileStrategy: snapshot.fileStrategy
 					},
 					storage: {
 						type: "filesystem",
 						directory: path.resolve(
 							config.context || process.cwd(),
 							cache.storage?.directory || "node_modules/.cache/rspack"
-						)
+						),
+						bucketSize: cache.storage?.bucketSize,
+						adaptiveBucket: cache.storage?.adaptiveBucket,
+						packSize: cache.storage?.packSize,
+						expire: cache.storage?.expire,
+						maxSize: cache.storage?.maxSize,
+						freshGeneration: cache.storage?.freshGeneration,
+						releaseGeneration: cache.storage?.releaseGeneration
 					}
 				};
 			}),
@@ -368,14 +377,18 @@ export const getNormalizedRspackOptions = (
 						} satisfies Incremental)
 					: options
 			),
-			parallelCodeSplitting: experiments.parallelCodeSplitting
+			parallelCodeSplitting: experiments.parallelCodeSplitting,
+			lazyBarrel: experiments.lazyBarrel,
+			inlineConst: experiments.inlineConst
 		})),
 		watch: config.watch,
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
 		profile: config.profile,
 		amd: config.amd,
-		bail: config.bail
+		bail: config.bail,
+		recordsInputPath: config.recordsInputPath ?? config.recordsPath,
+		recordsOutputPath: config.recordsOutputPath ?? config.recordsPath
 	};
 };
 
@@ -589,10 +602,21 @@ export type ExperimentCacheNormalized =
 				immutablePaths: Array<string | RegExp>;
 				unmanagedPaths: Array<string | RegExp>;
 				managedPaths: Array<string | RegExp>;
+				fileStrategy?:
+					| "compile-time"
+					| "content-hash"
+					| "compile-time-and-content-hash";
 			};
 			storage: {
 				type: "filesystem";
 				directory: string;
+				bucketSize?: number;
+				adaptiveBucket?: boolean;
+				packSize?: number;
+				expire?: number;
+				maxSize?: number;
+				freshGeneration?: number;
+				releaseGeneration?: number;
 			};
 	  };
 
@@ -606,6 +630,8 @@ export interface ExperimentsNormalized {
 	layers?: boolean;
 	incremental?: false | Incremental;
 	parallelCodeSplitting?: boolean;
+	lazyBarrel?: boolean;
+	inlineConst?: boolean;
 	futureDefaults?: boolean;
 	rspackFuture?: RspackFutureOptions;
 }
@@ -653,4 +679,6 @@ export interface RspackOptionsNormalized {
 	profile?: Profile;
 	amd?: Amd;
 	bail?: Bail;
+	recordsInputPath?: RecordsPath;
+	recordsOutputPath?: RecordsPath;
 }
@@ -337,8 +337,11 @@ export type HashDigest = string;
 /** The prefix length of the hash digest to use. */
 export type HashDigestLength = number;
 
-/** The hashing algorithm to use. */
-export type HashFunction = "md4" | "xxhash64";
+/**
+ * The hashing algorithm to use.
+ * `xxh128` is the 128-bit variant of XXH3.
+ */
+export type HashFunction = "md4" | "xxhash64" | "sha256" | "sha512" | "xxh128";
 
 /** An optional salt to update the hash. */
 export type HashSalt = string;
@@ -2418,10 +2421,52 @@ export type ExperimentCacheOptions =
 				immutablePaths?: Array<string | RegExp>;
 				unmanagedPaths?: Array<string | RegExp>;
 				managedPaths?: Array<string | RegExp>;
+				/**
+				 * The strategy to check whether the files out of managed paths are modified.
+				 * @default "compile-time"
+				 */
+				fileStrategy?:
+					| "compile-time"
+					| "content-hash"
+					| "compile-time-and-content-hash";
 			};
 			storage?: {
 				type: "filesystem";
 				directory?: string;
+				/**
+				 * The count of buckets in each scope, it is the max count when `adaptiveBucket` is enabled.
+				 * @default 20
+				 */
+				bucketSize?: number;
+				/**
+				 * Choose the bucket count of each scope by its item count.
+				 * @default true
+				 */
+				adaptiveBucket?: boolean;
+				/**
+				 * The max size of a pack in bytes.
+				 * @default 512000
+				 */
+				packSize?: number;
+				/**
+				 * The expire time of a cache version in milliseconds.
+				 * @default 604800000
+				 */
+				expire?: number;
+				/**
+				 * The max total size of all cache versions in bytes, the least recently saved versions are removed first.
+				 */
+				maxSize?: number;
+				/**
+				 * The packs not changed in the latest `freshGeneration` saves will be merged.
+				 * @default 1
+				 */
+				freshGeneration?: number;
+				/**
+				 * The contents of packs not used in the latest `releaseGeneration` saves will be released from memory.
+				 * @default 2
+				 */
+				releaseGeneration?: number;
 			};
 	  };
 
@@ -2605,6 +2650,16 @@ export type Experiments = {
 	 * Enable multi-threaded code splitting algorithm.
 	 */
 	parallelCodeSplitting?: boolean;
+	/**
+	 * Defer building the re-exported modules of side effect free barrel files until their exports are used.
+	 * @default false
+	 */
+	lazyBarrel?: boolean;
+	/**
+	 * Inline constant exports into the modules that import them.
+	 * @default false
+	 */
+	inlineConst?: boolean;
 	/**
 	 * Enable future default options.
 	 * @default false
@@ -2695,6 +2750,13 @@ export type Amd = false | Record<string, any>;
 export type Bail = boolean;
 //#endregion
 
+//#region Records
+/**
+ * The absolute path of the records file, or `false` to disable it.
+ */
+export type RecordsPath = false | string;
+//#endregion
+
 //#region Performance
 /** Options to control how Rspack notifies you of assets and entry points that exceed a specific file limit.   */
 export type Performance =
@@ -2843,6 +2905,19 @@ export type RspackOptions = {
 	 * Whether to fail on the first error.
 	 */
 	bail?: Bail;
+	/**
+	 * The file to store the ids of modules and chunks across builds.
+	 * Used as the default of `recordsInputPath` and `recordsOutputPath`.
+	 */
+	recordsPath?: RecordsPath;
+	/**
+	 * The file to read the records of the last build from.
+	 */
+	recordsInputPath?: RecordsPath;
+	/**
+	 * The file to write the records of this build to.
+	 */
+	recordsOutputPath?: RecordsPath;
 	/**
 	 * Performance optimization options.
 	 */
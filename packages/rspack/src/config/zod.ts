@@ -286,7 +286,10 @@ const hashDigestLength = z.number() satisfies z.ZodType<t.HashDigestLength>;
 
 const hashFunction = z.enum([
 	"md4",
-	"xxhash64"
+	"xxhash64",
+	"sha256",
+	"sha512",
+	"xxh128"
 ]) satisfies z.ZodType<t.HashFunction>;
 
 const hashSalt = z.string() satisfies z.ZodType<t.HashSalt>;
@@ -1336,13 +1339,27 @@ const experimentCacheOptions = z
 						.or(z.instanceof(RegExp))
 						.array()
 						.optional(),
-					managedPaths: z.string().or(z.instanceof(RegExp)).array().optional()
+					managedPaths: z.string().or(z.instanceof(RegExp)).array().optional(),
+					fileStrategy: z
+						.enum([
+							"compile-time",
+							"content-hash",
+							"compile-time-and-content-hash"
+						])
+						.optional()
 				})
 				.optional(),
 			storage: z
 				.object({
 					type: z.enum(["filesystem"]),
-					directory: z.string().optional()
+					directory: z.string().optional(),
+					bucketSize: z.number().int().positive().optional(),
+					adaptiveBucket: z.boolean().optional(),
+					packSize: z.number().int().positive().optional(),
+					expire: z.number().int().nonnegative().optional(),
+					maxSize: z.number().int().nonnegative().optional(),
+					freshGeneration: z.number().int().nonnegative().optional(),
+					releaseGeneration: z.number().int().nonnegative().optional()
 				})
 				.optional()
 		})
@@ -1397,6 +1414,8 @@ const experiments = z.strictObject({
 	layers: z.boolean().optional(),
 	incremental: z.boolean().or(incremental).optional(),
 	parallelCodeSplitting: z.boolean().optional(),
+	lazyBarrel: z.boolean().optional(),
+	inlineConst: z.boolean().optional(),
 	futureDefaults: z.boolean().optional(),
 	rspackFuture: rspackFutureOptions.optional()
 }) satisfies z.ZodType<t.Experiments>;
@@ -1449,6 +1468,17 @@ const amd = z.literal(false).or(z.record(z.any())) satisfies z.ZodType<t.Amd>;
 const bail = z.boolean() satisfies z.ZodType<t.Bail>;
 //#endregion
 
+//#region Records
+const recordsPath = z.literal(false).or(
+	z.string().refine(
+		val => nodePath.isAbsolute(val),
+		val => ({
+			message: `The provided value ${JSON.stringify(val)} must be an absolute path.`
+		})
+	)
+) satisfies z.ZodType<t.RecordsPath>;
+//#endregion
+
 //#region Performance
 const performance = z
 	.strictObject({
@@ -1491,5 +1521,8 @@ export const rspackOptions = z.strictObject({
 	profile: profile.optional(),
 	amd: amd.optional(),
 	bail: bail.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsPath.optional(),
+	recordsOutputPath: recordsPath.optional(),
 	performance: performance.optional()
 }) satisfies z.ZodType<t.RspackOptions>;
@@ -0,0 +1,143 @@
+use std::{path::PathBuf, process::ExitCode, sync::Arc};
+
+use rspack_fs::NativeFileSystem;
+use rspack_storage::{BridgeFileSystem, PackStorageInspector, Result, ScopeInfo};
+
+const USAGE: &str = "Usage: storage-inspect <cache-directory> [command]
+
+Commands:
+  list                       list versions and scopes (default)
+  scope <version> <scope>    print the packs of scope
+  validate <version>         validate all of scopes in version
+  prune [max-size] [--active <version>]
+                             remove expired versions and the oldest versions over max size (in bytes),
+                             the active version is never removed
+  compact <version>          merge fragmented packs of version";
+
+fn print_scope(scope: &ScopeInfo, indent: &str) {
+  println!(
+    "{indent}{}: {} packs, {} bytes, {} fragmented, generation {}",
+    scope.name,
+    scope.packs.len(),
+    scope.size(),
+    scope.fragmented_packs(),
+    scope.generation
+  );
+}
+
+async fn run(inspector: &PackStorageInspector, args: &[String]) -> Result<bool> {
+  match args
+    .iter()
+    .map(|s| s.as_str())
+    .collect::<Vec<_>>()
+    .as_slice()
+  {
+    [] | ["list"] => {
+      for version in inspector.versions().await? {
+        let expire = match version.expire_time {
+          Some(expire_time) if version.is_expired() => format!("expired at {expire_time}"),
+          Some(expire_time) => format!("expire at {expire_time}"),
+          None => "invalid".to_string(),
+        };
+        println!("{} ({expire}, {} bytes)", version.version, version.size());
+        for scope in &version.scopes {
+          print_scope(scope, "  ");
+        }
+      }
+    }
+    ["scope", version, name] => {
+      let Some(scope) = inspector.scope(version, name).await? else {
+        println!("scope {name} not found in version {version}");
+        return Ok(false);
+      };
+      print_scope(&scope, "");
+      println!(
+        "  bucket size {}, pack size {}",
+        scope.bucket_size, scope.pack_size
+      );
+      for pack in &scope.packs {
+        println!(
+          "  {}/{}: {} bytes, hash {}, generation {}",
+          pack.bucket, pack.name, pack.size, pack.hash, pack.generation
+        );
+      }
+    }
+    ["validate", version] => {
+      let mut all_valid = true;
+      for (name, validated) in inspector.validate(version).await? {
+        match validated {
+          Ok(count) => println!("{name}: valid, {count} items"),
+          Err(err) => {
+            all_valid = false;
+            println!("{name}: invalid, {err}");
+          }
+        }
+      }
+      return Ok(all_valid);
+    }
+    ["prune", rest @ ..] => {
+      let (active, rest) = match rest {
+        [rest @ .., "--active", version] => (Some(*version), rest),
+        rest => (None, rest),
+      };
+      let max_size = match rest {
+        [] => None,
+        [size] => match size.parse::<usize>() {
+          Ok(size) => Some(size),
+          Err(_) => {
+            println!("invalid max size {size}");
+            return Ok(false);
+          }
+        },
+        _ => {
+          println!("{USAGE}");
+          return Ok(false);
+        }
+      };
+      for version in inspector.prune(active, max_size).await? {
+        println!("removed {version}");
+      }
+    }
+    ["compact", version] => {
+      inspector.compact(version).await?;
+      for scope in inspector
+        .versions()
+        .await?
+        .iter()
+        .filter(|info| &info.version == version)
+        .flat_map(|info| info.scopes.iter())
+      {
+        print_scope(scope, "");
+      }
+    }
+    _ => {
+      println!("{USAGE}");
+      return Ok(false);
+    }
+  }
+  Ok(true)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let args = std::env::args().skip(1).collect::<Vec<_>>();
+  let Some(directory) = args.first() else {
+    println!("{USAGE}");
+    return ExitCode::FAILURE;
+  };
+  let root = PathBuf::from(directory);
+  // keep the same temp directory as the persistent cache
+  let inspector = PackStorageInspector::new(
+    root.clone(),
+    root.join(".temp"),
+    Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+  );
+  match run(&inspector, &args[1..]).await {
+    Ok(true) => ExitCode::SUCCESS,
+    Ok(false) => ExitCode::FAILURE,
+    Err(err) => {
+      eprintln!("{err}");
+      ExitCode::FAILURE
+    }
+  }
+}
@@ -0,0 +1,43 @@
+use std::{path::Path, process::Command};
+
+fn storage_inspect(root: &Path, args: &[&str]) -> (Option<i32>, String) {
+  let output = Command::new(env!("CARGO_BIN_EXE_storage-inspect"))
+    .arg(root)
+    .args(args)
+    .output()
+    .expect("should run storage-inspect");
+  (
+    output.status.code(),
+    String::from_utf8_lossy(&output.stdout).to_string(),
+  )
+}
+
+#[test]
+fn smoke() {
+  let root = std::env::temp_dir().join("storage_inspect_test");
+  let _ = std::fs::remove_dir_all(&root);
+  // versions without root meta are invalid
+  std::fs::create_dir_all(root.join("old")).expect("should create the version");
+  std::fs::create_dir_all(root.join("current")).expect("should create the version");
+
+  let (code, stdout) = storage_inspect(&root, &[]);
+  assert_eq!(code, Some(0));
+  assert_eq!(
+    stdout,
+    "current (invalid, 0 bytes)\nold (invalid, 0 bytes)\n"
+  );
+
+  let (code, stdout) = storage_inspect(&root, &["prune", "abc"]);
+  assert_eq!(code, Some(1));
+  assert_eq!(stdout, "invalid max size abc\n");
+
+  let (code, stdout) = storage_inspect(&root, &["prune", "0", "--active", "current"]);
+  assert_eq!(code, Some(0));
+  assert_eq!(stdout, "removed old\n");
+  assert!(root.join("current").exists());
+  assert!(!root.join("old").exists());
+
+  let (code, stdout) = storage_inspect(&root, &["unknown"]);
+  assert_eq!(code, Some(1));
+  assert!(stdout.starts_with("Usage: storage-inspect"));
+}